@@ -130,6 +130,24 @@ struct SharedArgs {
     ///
     /// memoize-instance-fn[=<true/false>] - Inline the lookup of an instance function where appropriate.
     ///
+    /// fold-constant-conditions[=<true/false>] - Fold `if` and `while` branches with constant conditions.
+    ///
+    /// unroll-loops[=<true/false>] - Unroll `for` loops over small constant ranges.
+    ///
+    /// propagate-constants[=<true/false>] - Propagate constant `let` bindings to where they're used.
+    ///
+    /// let-mut[=<true/false>] - Require `let mut` for local variables which are reassigned.
+    ///
+    /// warn-mixed-numbers[=<true/false>] - Warn about operations mixing integer and float literals.
+    ///
+    /// warn-redundant-parens[=<true/false>] - Warn about parentheses which can be removed.
+    ///
+    /// preserve-comments[=<true/false>] - Preserve comments in the parsed syntax tree.
+    ///
+    /// nesting-limit=<n> - Set the maximum nesting depth of expressions when parsing (default: 64).
+    ///
+    /// auto-call-unit-tuples[=<true/false>] - Construct tuples and variants without fields when referenced by value.
+    ///
     /// link-checks[=<true/false>] - Perform linker checks which makes sure that called functions exist.
     ///
     /// debug-info[=<true/false>] - Enable or disable debug info.
@@ -21,7 +21,9 @@ impl TestVisitor {
 impl rune::CompileVisitor for TestVisitor {
     fn register_meta(&self, meta: &CompileMeta) {
         let type_hash = match &meta.kind {
-            CompileMetaKind::Function { is_test, type_hash } if *is_test => type_hash,
+            CompileMetaKind::Function {
+                is_test, type_hash, ..
+            } if *is_test => type_hash,
             _ => return,
         };
 
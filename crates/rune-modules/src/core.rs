@@ -20,6 +20,7 @@
 //! # }
 //! ```
 
+use rune::T;
 use rune::ast;
 use rune::macros;
 use rune::{quote, Parser, TokenStream};
@@ -29,6 +30,7 @@ pub fn module(_stdio: bool) -> Result<runestick::Module, runestick::ContextError
     let mut module = runestick::Module::with_crate("std");
     module.macro_(&["stringify"], stringify_macro)?;
     module.macro_(&["panic"], panic_macro)?;
+    module.macro_(&["matches"], matches_macro)?;
     Ok(module)
 }
 
@@ -49,3 +51,30 @@ pub(crate) fn panic_macro(
     let expanded = args.expand()?;
     Ok(quote!(::std::panic(#expanded)).into_token_stream())
 }
+
+/// Implementation for the `matches!` macro, which expands to a `match`
+/// producing `true` if the value matches the pattern and its optional guard.
+pub(crate) fn matches_macro(
+    stream: &TokenStream,
+) -> runestick::Result<TokenStream> {
+    let mut p = Parser::from_token_stream(stream);
+    let expr = p.parse::<ast::Expr>()?;
+    p.parse::<T![,]>()?;
+    let pat = p.parse::<ast::Pat>()?;
+    let guard = p.parse::<Option<(T![if], ast::Expr)>>()?;
+    p.parse::<Option<T![,]>>()?;
+    p.eof()?;
+
+    let output = match &guard {
+        Some((_, condition)) => quote!(match #expr {
+            #pat if #condition => true,
+            _ => false,
+        }),
+        None => quote!(match #expr {
+            #pat => true,
+            _ => false,
+        }),
+    };
+
+    Ok(output.into_token_stream())
+}
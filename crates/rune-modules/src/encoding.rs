@@ -0,0 +1,119 @@
+//! The native `encoding` module for the [Rune Language].
+//!
+//! [Rune Language]: https://rune-rs.github.io
+//!
+//! ## Usage
+//!
+//! Add the following to your `Cargo.toml`:
+//!
+//! ```toml
+//! rune-modules = {version = "0.9.0", features = ["encoding"]}
+//! ```
+//!
+//! Install it into your context:
+//!
+//! ```rust
+//! # fn main() -> runestick::Result<()> {
+//! let mut context = runestick::Context::with_default_modules()?;
+//! context.install(&rune_modules::encoding::module(true)?)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Use it in Rune:
+//!
+//! ```rust,ignore
+//! use encoding;
+//!
+//! fn main() {
+//!     let hex = encoding::to_hex("hello");
+//!     let bytes = encoding::from_hex(hex);
+//!     dbg(bytes);
+//! }
+//! ```
+
+use base64::Engine as _;
+use runestick::{Bytes, ContextError, Module, Value, VmError};
+
+/// Construct the `encoding` module.
+pub fn module(_stdio: bool) -> Result<Module, ContextError> {
+    let mut module = Module::with_crate("encoding");
+    module.function(&["to_hex"], to_hex)?;
+    module.function(&["from_hex"], from_hex)?;
+    module.function(&["to_base64"], to_base64)?;
+    module.function(&["from_base64"], from_base64)?;
+    Ok(module)
+}
+
+/// Encode a string or bytes as a lowercase hex string.
+fn to_hex(value: Value) -> Result<String, VmError> {
+    with_bytes(value, |bytes| {
+        let mut out = String::with_capacity(bytes.len() * 2);
+
+        for b in bytes {
+            out.push(HEX[(b >> 4) as usize] as char);
+            out.push(HEX[(b & 0xf) as usize] as char);
+        }
+
+        out
+    })
+}
+
+/// Decode a hex string into bytes, returning `None` if it isn't valid hex.
+fn from_hex(string: &str) -> Option<Bytes> {
+    let pairs = string.as_bytes().chunks_exact(2);
+
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(pairs.len());
+
+    for pair in pairs {
+        let hi = hex_digit(pair[0])?;
+        let lo = hex_digit(pair[1])?;
+        out.push(hi << 4 | lo);
+    }
+
+    Some(Bytes::from_vec(out))
+}
+
+/// Encode a string or bytes as a base64 string using the standard alphabet.
+fn to_base64(value: Value) -> Result<String, VmError> {
+    with_bytes(value, |bytes| {
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    })
+}
+
+/// Decode a base64 string into bytes, returning `None` if it isn't valid
+/// base64.
+fn from_base64(string: &str) -> Option<Bytes> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(string)
+        .ok()?;
+    Some(Bytes::from_vec(bytes))
+}
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Access the bytes of a string or a byte array.
+fn with_bytes<F, O>(value: Value, f: F) -> Result<O, VmError>
+where
+    F: FnOnce(&[u8]) -> O,
+{
+    Ok(match value {
+        Value::String(string) => f(string.borrow_ref()?.as_bytes()),
+        Value::StaticString(string) => f(string.as_bytes()),
+        Value::Bytes(bytes) => f(&bytes.borrow_ref()?),
+        actual => return Err(VmError::expected::<String>(actual.type_info()?)),
+    })
+}
@@ -1,7 +1,6 @@
 use rune::ast;
 use rune::macros;
-use rune::{quote, Parser, Spanned, TokenStream};
-use runestick::SpannedError;
+use rune::{quote, Parser, TokenStream};
 
 /// Implementation for the `stringy_math!` macro.
 pub(crate) fn stringy_math(stream: &TokenStream) -> runestick::Result<TokenStream> {
@@ -18,7 +17,7 @@ pub(crate) fn stringy_math(stream: &TokenStream) -> runestick::Result<TokenStrea
             "sub" => quote!((#output) - #arg),
             "div" => quote!((#output) / #arg),
             "mul" => quote!((#output) * #arg),
-            _ => return Err(SpannedError::msg(op.span(), "unsupported operation").into()),
+            _ => return Err(macros::error(op, "unsupported operation").into()),
         }
     }
 
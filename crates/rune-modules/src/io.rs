@@ -20,13 +20,16 @@
 //! # }
 //! ```
 
+use rune::ast;
 use rune::macros;
+use rune::T;
 use rune::{quote, Parser, TokenStream};
 
 /// Construct the supplemental `std::io` module.
 pub fn module(_stdio: bool) -> Result<runestick::Module, runestick::ContextError> {
     let mut module = runestick::Module::with_crate_item("std", &["io"]);
     module.macro_(&["println"], println_macro)?;
+    module.macro_(&["dbg"], dbg_macro)?;
     Ok(module)
 }
 
@@ -37,3 +40,53 @@ pub(crate) fn println_macro(stream: &TokenStream) -> runestick::Result<TokenStre
     let expanded = args.expand()?;
     Ok(quote!(std::io::println(#expanded)).into_token_stream())
 }
+
+/// Implementation for the `dbg!` macro.
+///
+/// Prints the location of the call together with the source and value of
+/// each argument. Evaluates to the value of a single argument, a tuple of
+/// values if there are multiple, or unit if there are none.
+pub(crate) fn dbg_macro(stream: &TokenStream) -> runestick::Result<TokenStream> {
+    let mut p = Parser::from_token_stream(stream);
+    let mut exprs = Vec::new();
+
+    while !p.is_eof()? {
+        exprs.push(p.parse::<ast::Expr>()?);
+
+        if p.parse::<Option<T![,]>>()?.is_none() {
+            break;
+        }
+    }
+
+    p.eof()?;
+
+    if exprs.is_empty() {
+        let output = quote!(std::io::println(format!("[{}:{}]", file!(), line!())));
+        return Ok(output.into_token_stream());
+    }
+
+    let mut stmts = Vec::new();
+    let mut values = Vec::new();
+
+    for (n, expr) in exprs.iter().enumerate() {
+        let value = ast::Ident::new(&format!("dbg_value{}", n));
+        let source = ast::Lit::new(macros::stringify(expr));
+
+        stmts.push(quote! {
+            let #value = #expr;
+            std::io::println(format!("[{}:{}] {} = {:?}", file!(), line!(), #source, #value));
+        });
+
+        values.push(value);
+    }
+
+    let output = match values.as_slice() {
+        [value] => quote!({ #stmts #value }),
+        values => {
+            let values = values.iter().map(|v| quote!(#v,)).collect::<Vec<_>>();
+            quote!({ #stmts (#values) })
+        }
+    };
+
+    Ok(output.into_token_stream())
+}
@@ -46,6 +46,7 @@
 //!
 //! See each module for documentation:
 //! * [core]
+//! * [encoding]
 //! * [experiments]
 //! * [fmt]
 //! * [fs]
@@ -63,6 +64,7 @@
 //! ## Features
 //!
 //! * `core` for the [core module][toml]
+//! * `encoding` for the [encoding module][encoding]
 //! * `experiments` for the [experiments module][experiments]
 //! * `fmt` for the [fmt module][fmt]
 //! * `fs` for the [fs module][fs]
@@ -79,6 +81,7 @@
 //! * `toml` for the [toml module][toml]
 //!
 //! [core]: https://docs.rs/rune-modules/0/rune_modules/core/
+//! [encoding]: https://docs.rs/rune-modules/0/rune_modules/encoding/
 //! [experiments]: https://docs.rs/rune-modules/0/rune_modules/experiments/
 //! [fmt]: https://docs.rs/rune-modules/0/rune_modules/fmt/
 //! [fs]: https://docs.rs/rune-modules/0/rune_modules/fs/
@@ -111,17 +114,41 @@ macro_rules! modules {
         /// modules provided based on the [default runestick
         /// context](runestick::Context::with_default_modules).
         pub fn with_config(stdio: bool) -> Result<runestick::Context, runestick::ContextError> {
-            #[allow(unused_mut)]
             let mut context = runestick::Context::with_config(stdio)?;
+            install(&mut context, stdio)?;
+            Ok(context)
+        }
 
+        /// Install all enabled modules into the given context.
+        ///
+        /// Modules which aren't allowed by a context constructed through
+        /// [runestick::Context::with_allowed_modules] are skipped.
+        ///
+        /// ```rust
+        /// use runestick::{Context, Item};
+        ///
+        /// # fn main() -> runestick::Result<()> {
+        /// let mut context = Context::with_allowed_modules(true, vec![
+        ///     Item::with_crate_item("std", &["string"]),
+        /// ])?;
+        ///
+        /// rune_modules::install(&mut context, true)?;
+        /// assert!(!context.contains_prefix(&Item::with_crate_item("std", &["test"])));
+        /// # Ok(()) }
+        /// ```
+        #[allow(unused_variables)]
+        pub fn install(
+            context: &mut runestick::Context,
+            stdio: bool,
+        ) -> Result<(), runestick::ContextError> {
             $(
                 #[cfg(feature = $name)]
                 {
-                    context.install(&self::$ident::module(stdio)?)?;
+                    context.install_if_allowed(&self::$ident::module(stdio)?)?;
                 }
             )*
 
-            Ok(context)
+            Ok(())
         }
 
         /// Construct a a default context runestick context with default config.
@@ -133,6 +160,7 @@ macro_rules! modules {
 
 modules! {
     core, "core",
+    encoding, "encoding",
     fmt, "fmt",
     fs, "fs",
     http, "http",
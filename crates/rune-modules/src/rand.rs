@@ -30,11 +30,15 @@
 //!     println(`Random int: {rand_int}`);
 //!     let rand_int_range = rng.int_range(-100, 100);
 //!     println(`Random int between -100 and 100: {rand_int_range}`);
+//!
+//!     let values = [1, 2, 3, 4];
+//!     values.shuffle(rand::Pcg64::new_seed(42));
+//!     println(`Shuffled: {values:?}`);
 //! }
 //! ```
 
 use nanorand::RNG;
-use runestick::{Any, ContextError, Module, Value};
+use runestick::{Any, ContextError, Module, Value, VmError};
 
 /// Construct the `rand` module.
 pub fn module(_stdio: bool) -> Result<Module, ContextError> {
@@ -55,6 +59,8 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
     module.function(&["int"], int)?;
     module.function(&["int_range"], int_range)?;
 
+    module.inst_fn("shuffle", shuffle)?;
+
     Ok(module)
 }
 
@@ -120,6 +126,32 @@ impl Pcg64 {
     }
 }
 
+/// Shuffle a vector in place with the given generator, which is either a
+/// `WyRand` or a `Pcg64`.
+fn shuffle(vec: &mut runestick::Vec, rng: Value) -> Result<(), VmError> {
+    let rng = rng.into_any()?;
+
+    if let Ok(mut rng) = rng.downcast_borrow_mut::<WyRand>() {
+        fisher_yates(vec, &mut rng.inner);
+        return Ok(());
+    }
+
+    let mut rng = rng.downcast_borrow_mut::<Pcg64>()?;
+    fisher_yates(vec, &mut rng.inner);
+    Ok(())
+}
+
+/// Fisher–Yates shuffle of the given values.
+fn fisher_yates<R>(values: &mut [Value], rng: &mut R)
+where
+    R: RNG,
+{
+    for i in (1..values.len()).rev() {
+        let j = rng.generate_range::<u64>(0, i as u64 + 1) as usize;
+        values.swap(i, j);
+    }
+}
+
 fn int() -> runestick::Result<Value> {
     Ok(Value::Integer(
         nanorand::WyRand::new().generate::<u64>() as i64
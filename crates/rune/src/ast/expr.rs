@@ -96,6 +96,8 @@ pub enum Expr {
     Await(Box<ast::ExprAwait>),
     /// Try expression.
     Try(Box<ast::ExprTry>),
+    /// A `try` block with a `catch` handler.
+    TryCatch(Box<ast::ExprTryCatch>),
     /// A select expression.
     Select(Box<ast::ExprSelect>),
     /// A closure expression.
@@ -127,6 +129,7 @@ impl Expr {
             Self::Match(_) => false,
             Self::Block(_) => false,
             Self::Select(_) => false,
+            Self::TryCatch(_) => false,
             Self::MacroCall(macro_call) => macro_call.needs_semi(),
             Self::ForceSemi(force_semi) => force_semi.needs_semi,
             _ => true,
@@ -143,6 +146,7 @@ impl Expr {
             Self::If(_) => callable,
             Self::Match(_) => callable,
             Self::Select(_) => callable,
+            Self::TryCatch(_) => callable,
             Self::ForceSemi(expr) => expr.expr.is_callable(callable),
             _ => true,
         }
@@ -176,6 +180,7 @@ impl Expr {
             Self::Index(expr) => take(&mut expr.attributes),
             Self::Await(expr) => take(&mut expr.attributes),
             Self::Try(expr) => take(&mut expr.attributes),
+            Self::TryCatch(expr) => take(&mut expr.attributes),
             Self::ForceSemi(expr) => expr.expr.take_attributes(),
             Self::Object(expr) => take(&mut expr.attributes),
             Self::Range(expr) => take(&mut expr.attributes),
@@ -213,6 +218,7 @@ impl Expr {
             Self::Index(expr) => &expr.attributes,
             Self::Await(expr) => &expr.attributes,
             Self::Try(expr) => &expr.attributes,
+            Self::TryCatch(expr) => &expr.attributes,
             Self::ForceSemi(expr) => expr.expr.attributes(),
             Self::MacroCall(expr) => &expr.attributes,
             Self::Object(expr) => &expr.attributes,
@@ -364,6 +370,15 @@ impl Expr {
         p: &mut Parser<'_>,
         attributes: &mut Vec<ast::Attribute>,
         eager_brace: EagerBrace,
+    ) -> Result<Self, ParseError> {
+        p.nested(|p| Self::parse_base_nested(p, attributes, eager_brace))
+    }
+
+    /// Parse a basic expression, after the nesting depth has been increased.
+    fn parse_base_nested(
+        p: &mut Parser<'_>,
+        attributes: &mut Vec<ast::Attribute>,
+        eager_brace: EagerBrace,
     ) -> Result<Self, ParseError> {
         if let Some(path) = p.parse::<Option<ast::Path>>()? {
             return Ok(Self::parse_with_meta_path(
@@ -427,6 +442,10 @@ impl Expr {
                 take(attributes),
                 take(&mut label),
             )?)),
+            K![try] => Self::TryCatch(Box::new(ast::ExprTryCatch::parse_with_meta(
+                p,
+                take(attributes),
+            )?)),
             K![for] => Self::For(Box::new(ast::ExprFor::parse_with_meta(
                 p,
                 take(attributes),
@@ -797,9 +816,11 @@ impl Peek for Expr {
             K![*] => true,
             K![while] => true,
             K![loop] => true,
+            K![try] => true,
             K![for] => true,
             K![let] => true,
             K![if] => true,
+            K![match] => true,
             K![break] => true,
             K![continue] => true,
             K![return] => true,
@@ -132,7 +132,7 @@ impl BinOp {
     }
 
     /// Get the precedence for the current operator.
-    pub(super) fn precedence(self) -> usize {
+    pub(crate) fn precedence(self) -> usize {
         // NB: Rules from: https://doc.rust-lang.org/reference/expressions.html#expression-precedence
         match self {
             Self::Is | Self::IsNot => 12,
@@ -12,6 +12,7 @@ use crate::{ParseError, Parser, Spanned, ToTokens};
 /// testing::roundtrip::<ast::ExprFor>("for (a, _) in x {}");
 /// testing::roundtrip::<ast::ExprFor>("'label: for i in x {}");
 /// testing::roundtrip::<ast::ExprFor>("#[attr] 'label: for i in x {}");
+/// testing::roundtrip::<ast::ExprFor>("for i in x {} else {}");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
 pub struct ExprFor {
@@ -32,6 +33,9 @@ pub struct ExprFor {
     pub iter: ast::Expr,
     /// The body of the loop.
     pub body: Box<ast::Block>,
+    /// The `else` block, which runs if the loop completes without a `break`.
+    #[rune(iter)]
+    pub expr_else: Option<ast::ExprElse>,
 }
 
 impl ExprFor {
@@ -49,6 +53,7 @@ impl ExprFor {
             in_: parser.parse()?,
             iter: ast::Expr::parse_without_eager_brace(parser)?,
             body: parser.parse()?,
+            expr_else: parser.parse()?,
         })
     }
 }
@@ -16,6 +16,8 @@ use std::borrow::Cow;
 /// testing::roundtrip::<ast::ExprObject>("Foo {\"foo\": 42}");
 /// testing::roundtrip::<ast::ExprObject>("#{\"foo\": 42}");
 /// testing::roundtrip::<ast::ExprObject>("#{\"foo\": 42,}");
+/// testing::roundtrip::<ast::ExprObject>("Foo {a: 42, ..base}");
+/// testing::roundtrip::<ast::ExprObject>("Foo {..base}");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned)]
 pub struct ExprObject {
@@ -25,8 +27,36 @@ pub struct ExprObject {
     /// An object identifier.
     #[rune(meta)]
     pub ident: ObjectIdent,
+    /// The open brace.
+    pub open: T!['{'],
     /// Assignments in the object.
-    pub assignments: ast::Braced<FieldAssign, T![,]>,
+    #[rune(iter, parse_with = "parse_assignments")]
+    pub assignments: Vec<(FieldAssign, Option<T![,]>)>,
+    /// The base expression to take the remaining fields from, as in
+    /// `Foo { a: 42, ..base }`.
+    #[rune(iter)]
+    pub base: Option<(T![..], ast::Expr)>,
+    /// The close brace.
+    pub close: T!['}'],
+}
+
+/// Parse field assignments up until the closing brace, or the `..` of a base
+/// expression.
+fn parse_assignments(p: &mut Parser<'_>) -> Result<Vec<(FieldAssign, Option<T![,]>)>, ParseError> {
+    let mut assignments = Vec::new();
+
+    while !p.peek::<T!['}']>()? && !p.peek::<T![..]>()? {
+        let assign = p.parse()?;
+        let comma = p.parse::<Option<T![,]>>()?;
+        let is_end = comma.is_none();
+        assignments.push((assign, comma));
+
+        if is_end {
+            break;
+        }
+    }
+
+    Ok(assignments)
 }
 
 impl Peek for ExprObject {
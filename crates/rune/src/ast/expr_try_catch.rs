@@ -0,0 +1,40 @@
+use crate::ast;
+use crate::{Parse, Spanned, ToTokens};
+
+/// A `try` expression with an error handler: `try { ... } catch (e) { ... }`.
+///
+/// Note that this makes `try` and `catch` reserved keywords, so they can't be
+/// used as identifiers.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{testing, ast};
+///
+/// testing::roundtrip::<ast::ExprTryCatch>("try {} catch (e) {}");
+/// testing::roundtrip::<ast::ExprTryCatch>("try { 1 } catch (_) { 2 }");
+/// testing::roundtrip::<ast::ExprTryCatch>("#[attr] try { foo() } catch (e) { bar(e) }");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned)]
+#[rune(parse = "meta_only")]
+pub struct ExprTryCatch {
+    /// The attributes for the `try` expression.
+    #[rune(iter, meta)]
+    pub attributes: Vec<ast::Attribute>,
+    /// The `try` keyword.
+    pub try_token: T![try],
+    /// The guarded block.
+    pub body: Box<ast::Block>,
+    /// The `catch` keyword.
+    pub catch_token: T![catch],
+    /// The open parenthesis.
+    pub open: T!['('],
+    /// The pattern the caught error is bound to.
+    pub binding: ast::Pat,
+    /// The close parenthesis.
+    pub close: T![')'],
+    /// The block handling the error.
+    pub catch_body: Box<ast::Block>,
+}
+
+expr_parse!(TryCatch, ExprTryCatch, "try expression");
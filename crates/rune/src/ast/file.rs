@@ -1,15 +1,40 @@
 use crate::ast;
-use crate::{OptionSpanned, Parse, ParseError, Parser, ToTokens};
+use crate::{MacroContext, OptionSpanned, Parse, ParseError, Parser, ToTokens, TokenStream};
 
 /// A parsed file.
-#[derive(Debug, Clone, PartialEq, Eq, ToTokens)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct File {
     /// Top level "Outer" `#![...]` attributes for the file
-    #[rune(iter)]
     pub attributes: Vec<ast::Attribute>,
     /// All the declarations in a file.
-    #[rune(iter)]
     pub items: Vec<(ast::Item, Option<T![;]>)>,
+    /// Comments in the file, in the order they appear in the source.
+    ///
+    /// These are only collected if the parser is configured to preserve
+    /// comments through [Parser::set_preserve_comments].
+    pub comments: Vec<ast::Token>,
+}
+
+impl ToTokens for File {
+    fn to_tokens(&self, context: &MacroContext, stream: &mut TokenStream) {
+        let mut tokens = TokenStream::new();
+        self.attributes.to_tokens(context, &mut tokens);
+        self.items.to_tokens(context, &mut tokens);
+
+        // NB: comments are interleaved with the tokens of the file based on
+        // where they were located in the source.
+        let mut comments = self.comments.iter().copied().peekable();
+
+        for token in tokens {
+            while let Some(comment) = comments.next_if(|c| c.span.start < token.span.start) {
+                stream.push(comment);
+            }
+
+            stream.push(token);
+        }
+
+        stream.extend(comments);
+    }
 }
 
 impl OptionSpanned for File {
@@ -85,6 +110,7 @@ impl OptionSpanned for File {
 #[allow(clippy::needless_doctest_main)]
 impl Parse for File {
     fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        let comments_start = p.comments_len();
         let mut attributes = vec![];
 
         // only allow outer attributes at the top of a file
@@ -123,6 +149,12 @@ impl Parse for File {
             return Err(ParseError::unsupported(span, "visibility"));
         }
 
-        Ok(Self { attributes, items })
+        let comments = p.take_comments(comments_start)?;
+
+        Ok(Self {
+            attributes,
+            items,
+            comments,
+        })
     }
 }
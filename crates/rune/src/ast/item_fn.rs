@@ -37,6 +37,9 @@ use runestick::Span;
 /// assert_eq!(item.attributes.len(), 1);
 /// assert!(item.async_token.is_none());
 /// assert!(item.const_token.is_some());
+///
+/// let item = testing::roundtrip::<ast::ItemFn>("fn divmod(a, b) -> (int, int) {}");
+/// assert!(item.output.is_some());
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned)]
 #[rune(parse = "meta_only")]
@@ -62,6 +65,9 @@ pub struct ItemFn {
     pub name: ast::Ident,
     /// The arguments of the function.
     pub args: ast::Parenthesized<ast::FnArg, T![,]>,
+    /// The declared return type of the function.
+    #[rune(iter)]
+    pub output: Option<(T![->], ast::Type)>,
     /// The body of the function.
     pub body: ast::Block,
 }
@@ -77,6 +83,13 @@ impl ItemFn {
         }
     }
 
+    /// Get the number of values returned by this function, if it declares a
+    /// tuple return type.
+    pub fn return_arity(&self) -> Option<usize> {
+        let (_, ty) = self.output.as_ref()?;
+        ty.tuple_arity()
+    }
+
     /// Test if function is an instance fn.
     pub fn is_instance(&self) -> bool {
         matches!(self.args.first(), Some((ast::FnArg::SelfValue(..), _)))
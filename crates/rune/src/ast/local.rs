@@ -9,6 +9,7 @@ use crate::{Parse, ParseError, Parser, Spanned, ToTokens};
 /// use rune::{testing, ast};
 ///
 /// testing::roundtrip::<ast::Local>("let x = 1;");
+/// testing::roundtrip::<ast::Local>("let mut x = 1;");
 /// testing::roundtrip::<ast::Local>("#[attr] let a = f();");
 /// testing::roundtrip::<ast::Local>("let a = b{}().foo[0].await;");
 /// ```
@@ -19,6 +20,9 @@ pub struct Local {
     pub attributes: Vec<ast::Attribute>,
     /// The `let` keyword.
     pub let_token: T![let],
+    /// The optional `mut` keyword, marking the bindings as mutable.
+    #[rune(iter)]
+    pub mut_token: Option<T![mut]>,
     /// The name of the binding.
     pub pat: ast::Pat,
     /// The equality keyword.
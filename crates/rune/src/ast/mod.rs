@@ -63,6 +63,7 @@ mod expr_range;
 mod expr_return;
 mod expr_select;
 mod expr_try;
+mod expr_try_catch;
 mod expr_tuple;
 mod expr_unary;
 mod expr_vec;
@@ -95,6 +96,7 @@ mod pat;
 mod path;
 mod stmt;
 mod token;
+mod ty;
 pub(super) mod utils;
 mod vis;
 
@@ -106,7 +108,7 @@ pub use self::expr_assign::ExprAssign;
 pub use self::expr_await::ExprAwait;
 pub use self::expr_binary::{BinOp, ExprBinary};
 pub use self::expr_block::ExprBlock;
-pub use self::expr_break::{ExprBreak, ExprBreakValue};
+pub use self::expr_break::ExprBreak;
 pub use self::expr_call::ExprCall;
 pub use self::expr_closure::ExprClosure;
 pub use self::expr_continue::ExprContinue;
@@ -124,6 +126,7 @@ pub use self::expr_range::{ExprRange, ExprRangeLimits};
 pub use self::expr_return::ExprReturn;
 pub use self::expr_select::{ExprSelect, ExprSelectBranch};
 pub use self::expr_try::ExprTry;
+pub use self::expr_try_catch::ExprTryCatch;
 pub use self::expr_tuple::ExprTuple;
 pub use self::expr_unary::{ExprUnary, UnOp};
 pub use self::expr_vec::ExprVec;
@@ -153,13 +156,16 @@ pub use self::lit_number::LitNumber;
 pub use self::lit_str::LitStr;
 pub use self::local::Local;
 pub use self::macro_call::MacroCall;
-pub use self::pat::{Pat, PatBinding, PatLit, PatObject, PatPath, PatTuple, PatVec};
+pub use self::pat::{
+    Pat, PatBinding, PatIn, PatLit, PatObject, PatOr, PatPath, PatRange, PatTuple, PatVec,
+};
 pub use self::path::{Path, PathKind, PathSegment};
 pub use self::stmt::{ItemOrExpr, Stmt, StmtSortKey};
 pub use self::token::{
     BuiltIn, CopySource, Delimiter, Number, NumberBase, NumberSource, NumberText, StrSource,
     StrText, StringSource, Token,
 };
+pub use self::ty::Type;
 pub use self::vis::Visibility;
 
 macro_rules! decl_tokens {
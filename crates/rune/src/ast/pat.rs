@@ -20,6 +20,12 @@ pub enum Pat {
     PatBinding(PatBinding),
     /// The rest pattern `..`.
     PatRest(PatRest),
+    /// A set of literals `in [1, 2, 3]`.
+    PatIn(PatIn),
+    /// A set of alternative patterns `1 | 2 | 3`.
+    PatOr(PatOr),
+    /// A range of literals `1..=5`.
+    PatRange(PatRange),
 }
 
 /// Parsing a block expression.
@@ -41,6 +47,12 @@ pub enum Pat {
 /// testing::roundtrip::<ast::Pat>("var");
 /// testing::roundtrip::<ast::Pat>("_");
 /// testing::roundtrip::<ast::Pat>("Foo(n)");
+/// testing::roundtrip::<ast::Pat>("in [1, -2, 3]");
+/// testing::roundtrip::<ast::Pat>("in [\"foo\", \"bar\",]");
+/// testing::roundtrip::<ast::Pat>("1..=5");
+/// testing::roundtrip::<ast::Pat>("-10..-5");
+/// testing::roundtrip::<ast::Pat>("'a'..='z'");
+/// testing::roundtrip::<ast::Pat>("b'0'..=b'9'");
 /// ```
 impl Parse for Pat {
     fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
@@ -48,16 +60,12 @@ impl Parse for Pat {
 
         match p.nth(0)? {
             K![byte] => {
-                return Ok(Self::PatLit(PatLit {
-                    attributes,
-                    expr: ast::Expr::from_lit(ast::Lit::Byte(p.parse()?)),
-                }));
+                let expr = ast::Expr::from_lit(ast::Lit::Byte(p.parse()?));
+                return Self::parse_lit_or_range(p, attributes, expr);
             }
             K![char] => {
-                return Ok(Self::PatLit(PatLit {
-                    attributes,
-                    expr: ast::Expr::from_lit(ast::Lit::Char(p.parse()?)),
-                }));
+                let expr = ast::Expr::from_lit(ast::Lit::Char(p.parse()?));
+                return Self::parse_lit_or_range(p, attributes, expr);
             }
             K![bytestr] => {
                 return Ok(Self::PatLit(PatLit {
@@ -86,10 +94,8 @@ impl Parse for Pat {
                 });
             }
             K![number] => {
-                return Ok(Self::PatLit(PatLit {
-                    attributes,
-                    expr: ast::Expr::from_lit(ast::Lit::Number(p.parse()?)),
-                }));
+                let expr = ast::Expr::from_lit(ast::Lit::Number(p.parse()?));
+                return Self::parse_lit_or_range(p, attributes, expr);
             }
             K![..] => {
                 return Ok(Self::PatRest(PatRest {
@@ -120,12 +126,19 @@ impl Parse for Pat {
                 }))
             }
             K![-] => {
-                let expr: ast::Expr = p.parse()?;
+                let expr = parse_negative(p)?;
 
                 if expr.is_lit() {
-                    return Ok(Self::PatLit(PatLit { attributes, expr }));
+                    return Self::parse_lit_or_range(p, attributes, expr);
                 }
             }
+            K![in] => {
+                return Ok(Self::PatIn(PatIn {
+                    attributes,
+                    in_token: p.parse()?,
+                    items: p.parse()?,
+                }))
+            }
             K![_] => {
                 return Ok(Self::PatIgnore(PatIgnore {
                     attributes,
@@ -162,6 +175,45 @@ impl Parse for Pat {
     }
 }
 
+impl Pat {
+    /// Parse the remainder of a literal pattern, which is a range pattern if
+    /// the literal is followed by range limits like `..=`.
+    fn parse_lit_or_range(
+        p: &mut Parser<'_>,
+        attributes: Vec<ast::Attribute>,
+        expr: ast::Expr,
+    ) -> Result<Self, ParseError> {
+        if !matches!(p.nth(0)?, K![..] | K![..=]) {
+            return Ok(Self::PatLit(PatLit { attributes, expr }));
+        }
+
+        let limits = p.parse()?;
+
+        let end = match p.nth(0)? {
+            K![-] => parse_negative(p)?,
+            K![byte] | K![char] | K![number] => ast::Expr::from_lit(p.parse()?),
+            _ => return Err(ParseError::expected(&p.tok_at(0)?, "end of range pattern")),
+        };
+
+        Ok(Self::PatRange(PatRange {
+            attributes,
+            start: expr,
+            limits,
+            end,
+        }))
+    }
+}
+
+/// Parse a negated expression, like `-42`, without consuming any binary
+/// operators following it.
+fn parse_negative(p: &mut Parser<'_>) -> Result<ast::Expr, ParseError> {
+    Ok(ast::Expr::Unary(Box::new(ast::ExprUnary::parse_with_meta(
+        p,
+        Vec::new(),
+        ast::expr::EagerBrace(false),
+    )?)))
+}
+
 impl Peek for Pat {
     fn peek(p: &mut Peeker<'_>) -> bool {
         match p.nth(0) {
@@ -170,6 +222,7 @@ impl Peek for Pat {
             K![#] => matches!(p.nth(1), K!['{']),
             K![_] => true,
             K![..] => true,
+            K![in] => matches!(p.nth(1), K!['[']),
             K![byte] | K![char] | K![number] | K![str] => true,
             K![true] | K![false] => true,
             K![-] => matches!(p.nth(1), K![number]),
@@ -208,6 +261,52 @@ pub struct PatVec {
     pub items: ast::Bracketed<ast::Pat, T![,]>,
 }
 
+/// A pattern matching any literal in a set, like `in [1, 2, 3]`.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub struct PatIn {
+    /// Attributes associated with the set pattern.
+    #[rune(iter)]
+    pub attributes: Vec<ast::Attribute>,
+    /// The `in` token.
+    pub in_token: T![in],
+    /// The literals in the set.
+    pub items: ast::Bracketed<ast::Pat, T![,]>,
+}
+
+/// A pattern matching a range of literals, like `1..=5` or `'a'..'z'`.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub struct PatRange {
+    /// Attributes associated with the range pattern.
+    #[rune(iter)]
+    pub attributes: Vec<ast::Attribute>,
+    /// The start of the range.
+    pub start: ast::Expr,
+    /// The limits of the range, `..` is exclusive and `..=` is inclusive.
+    pub limits: ast::ExprRangeLimits,
+    /// The end of the range.
+    pub end: ast::Expr,
+}
+
+/// A pattern matching any one of a set of alternatives, like `1 | 2 | 3`.
+///
+/// These are only supported at the top level of a match branch, since the
+/// pipe would otherwise be ambiguous with closure arguments.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub struct PatOr {
+    /// The first alternative.
+    pub first: Box<ast::Pat>,
+    /// The remaining alternatives, each preceded by a pipe `|`.
+    #[rune(iter)]
+    pub rest: Vec<(T![|], ast::Pat)>,
+}
+
+impl PatOr {
+    /// Iterate over all alternatives of the pattern.
+    pub fn alternatives(&self) -> impl Iterator<Item = &ast::Pat> {
+        std::iter::once(&*self.first).chain(self.rest.iter().map(|(_, pat)| pat))
+    }
+}
+
 /// A tuple pattern.
 #[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
 pub struct PatTuple {
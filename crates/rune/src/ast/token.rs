@@ -21,6 +21,10 @@ impl Token {
                 // NB: marker tokens can't be formatted.
                 return Err(fmt::Error);
             }
+            Kind::Comment => {
+                let s = ctx.source().source(self.span).ok_or(fmt::Error)?;
+                write!(f, "{}", s)?;
+            }
             Kind::Ident(s) => match s {
                 StringSource::Text => {
                     let s = ctx.source().source(self.span).ok_or_else(|| fmt::Error)?;
@@ -0,0 +1,54 @@
+use crate::ast;
+use crate::{Parse, ParseError, Parser, Peek, Peeker, Spanned, ToTokens};
+
+/// A type annotation, like the declared return type of a function.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{testing, ast};
+///
+/// testing::roundtrip::<ast::Type>("int");
+/// testing::roundtrip::<ast::Type>("foo::Bar");
+///
+/// let ty = testing::roundtrip::<ast::Type>("()");
+/// assert_eq!(ty.tuple_arity(), Some(0));
+///
+/// let ty = testing::roundtrip::<ast::Type>("(int, int)");
+/// assert_eq!(ty.tuple_arity(), Some(2));
+///
+/// let ty = testing::roundtrip::<ast::Type>("(int, (float, String),)");
+/// assert_eq!(ty.tuple_arity(), Some(2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub enum Type {
+    /// A type referenced by path, like `int` or `foo::Bar`.
+    Path(ast::Path),
+    /// A tuple type, like `(int, int)`.
+    Tuple(ast::Parenthesized<ast::Type, T![,]>),
+}
+
+impl Type {
+    /// Get the number of elements in the type if it's a tuple.
+    pub fn tuple_arity(&self) -> Option<usize> {
+        match self {
+            Self::Tuple(tuple) => Some(tuple.len()),
+            Self::Path(..) => None,
+        }
+    }
+}
+
+impl Parse for Type {
+    fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        Ok(match p.nth(0)? {
+            K!['('] => Self::Tuple(p.parse()?),
+            _ => Self::Path(p.parse()?),
+        })
+    }
+}
+
+impl Peek for Type {
+    fn peek(p: &mut Peeker<'_>) -> bool {
+        matches!(p.nth(0), K!['(']) || ast::Path::peek(p)
+    }
+}
@@ -51,6 +51,60 @@ impl Attribute for BuiltIn {
     const PATH: &'static str = "builtin";
 }
 
+/// A `#[repr]` or `#[repr(C)]` layout hint on a struct, which causes its
+/// fields to be recorded in declaration order.
+#[derive(Parse)]
+pub(crate) struct Repr {
+    /// Arguments to the layout hint.
+    pub args: Option<ast::Parenthesized<ast::Ident, T![,]>>,
+}
+
+impl Repr {
+    /// Validate the arguments to the layout hint.
+    pub(crate) fn validate(&self, storage: &Storage, source: &Source) -> Result<(), ParseError> {
+        if let Some(args) = &self.args {
+            for (ident, _) in args {
+                match ident.resolve(storage, source)?.as_ref() {
+                    "C" => (),
+                    _ => {
+                        return Err(ParseError::msg(ident, "unsupported repr"));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Attribute for Repr {
+    /// Must match the specified name.
+    const PATH: &'static str = "repr";
+}
+
+/// An `#[allow(lint, ..)]` attribute, which suppresses the named lints within
+/// the item it's attached to.
+#[derive(Parse)]
+pub(crate) struct Allow {
+    /// The lints to allow.
+    pub args: ast::Parenthesized<ast::Ident, T![,]>,
+}
+
+impl Attribute for Allow {
+    /// Must match the specified name.
+    const PATH: &'static str = "allow";
+}
+
+/// A `#[track_caller]` attribute on a function, which causes errors raised
+/// inside of it to be reported at the location of its caller.
+#[derive(Parse)]
+pub(crate) struct TrackCaller {}
+
+impl Attribute for TrackCaller {
+    /// Must match the specified name.
+    const PATH: &'static str = "track_caller";
+}
+
 /// NB: at this point we don't support attributes beyond the empty `#[test]`.
 #[derive(Parse)]
 pub(crate) struct Test {}
@@ -13,6 +13,7 @@ pub enum AssemblyInst {
     JumpIfBranch { branch: i64, label: Label },
     PopAndJumpIfNot { count: usize, label: Label },
     IterNext { offset: usize, label: Label },
+    PushTry { label: Label },
     Raw { raw: Inst },
 }
 
@@ -114,6 +115,17 @@ impl Assembly {
             .push((AssemblyInst::IterNext { offset, label }, span));
     }
 
+    /// Add an instruction that pushes an error handler at the given label.
+    pub(crate) fn push_try(&mut self, label: Label, span: Span) {
+        self.instructions
+            .push((AssemblyInst::PushTry { label }, span));
+    }
+
+    /// Swap the two values on the top of the stack.
+    pub(crate) fn swap(&mut self, span: Span) {
+        self.push(Inst::Swap, span);
+    }
+
     /// Push a raw instruction.
     pub(crate) fn push(&mut self, raw: Inst, span: Span) {
         if let Inst::Call { hash, .. } = raw {
@@ -0,0 +1,19 @@
+use crate::ast;
+use crate::CompileError;
+use runestick::Item;
+
+/// A hook which can rewrite the AST of functions after they've been indexed,
+/// but before they're compiled.
+///
+/// The rewriter is called inside of a macro context, so
+/// [quote!][crate::quote] and [Parser::from_token_stream][crate::Parser::from_token_stream]
+/// can be used to construct new AST nodes.
+///
+/// Paths in introduced nodes are resolved in the scope of the function being
+/// rewritten. Since indexing has already happened, new closures, async blocks
+/// or nested items can't be introduced.
+pub trait AstRewriter {
+    /// Rewrite the AST of the function or instance function identified by
+    /// `item`.
+    fn rewrite_fn(&self, item: &Item, ast: &mut ast::ItemFn) -> Result<(), CompileError>;
+}
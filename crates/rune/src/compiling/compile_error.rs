@@ -7,7 +7,7 @@ use crate::{
     ResolveErrorKind, Spanned,
 };
 use runestick::debug::DebugSignature;
-use runestick::{CompileMeta, Hash, Item, Label, Location, SourceId, Span, SpannedError};
+use runestick::{CompileMeta, Hash, Item, Label, Location, SourceId, Span, SpannedError, TypeInfo};
 use std::io;
 use std::path::PathBuf;
 use thiserror::Error;
@@ -130,8 +130,21 @@ pub enum CompileErrorKind {
         item: Item,
         existing: (SourceId, Span),
     },
+    #[error("parameter `{name}` is defined more than once")]
+    DuplicateParameter {
+        name: String,
+        first_span: Span,
+        second_span: Span,
+    },
+    #[error("cannot assign twice to immutable variable")]
+    AssignToImmutable { decl_span: Span },
     #[error("variable `{name}` conflicts")]
     VariableConflict { name: String, existing_span: Span },
+    #[error("variable `{name}` is not bound in all alternatives of the pattern")]
+    InconsistentOrPatternBindings {
+        name: String,
+        alternative_span: Span,
+    },
     #[error("missing macro `{item}`")]
     MissingMacro { item: Item },
     #[error("{error}")]
@@ -162,8 +175,12 @@ pub enum CompileErrorKind {
     LitObjectMissingField { field: Box<str>, item: Item },
     #[error("`{field}` is not a field in `{item}`")]
     LitObjectNotField { field: Box<str>, item: Item },
+    #[error("struct update syntax is only supported when constructing structs")]
+    UnsupportedObjectBase,
     #[error("cannot assign to expression")]
     UnsupportedAssignExpr,
+    #[error("invalid assignment target, only variables, fields and indexes can be assigned to")]
+    InvalidAssignmentTarget,
     #[error("unsupported binary expression")]
     UnsupportedBinaryExpr,
     #[error("cannot take reference of expression")]
@@ -178,6 +195,29 @@ pub enum CompileErrorKind {
         expected: usize,
         actual: usize,
     },
+    #[error("index `{index}` is out of bounds for a vector of length `{len}`")]
+    ConstIndexOutOfBounds { len: usize, index: i64 },
+    #[error("spread arguments are only supported as the last argument of a call")]
+    SpreadNotLast,
+    #[error("spread arguments are not supported when calling {meta}")]
+    UnsupportedSpread { meta: CompileMeta },
+    #[error("{meta} returns a tuple with `{expected}` elements, but the pattern has `{actual}`")]
+    ReturnArityMismatch {
+        meta: CompileMeta,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("expected a return value of type `{expected}`, but got `{actual}`")]
+    ReturnTypeMismatch {
+        expected: Box<str>,
+        actual: TypeInfo,
+    },
+    #[error("{message}")]
+    MacroError { message: Box<str> },
+    #[error("`{item}` is not a function and can't be called")]
+    NotCallable { item: Item },
+    #[error("`{ty}` cannot be iterated over, since it doesn't have an `iter` function")]
+    NotIterable { ty: Box<str> },
     #[error("{meta} is not supported here")]
     UnsupportedPattern { meta: CompileMeta },
     #[error("`..` is not supported in this location")]
@@ -202,6 +242,10 @@ pub enum CompileErrorKind {
     UnsupportedTupleIndex { number: ast::Number },
     #[error("break outside of loop")]
     BreakOutsideOfLoop,
+    #[error(
+        "`break` with a value is only supported in `loop`, and in `for` loops with an `else` block"
+    )]
+    BreakValueUnsupported,
     #[error("continue outside of loop")]
     ContinueOutsideOfLoop,
     #[error("multiple `default` branches in select")]
@@ -278,7 +322,11 @@ pub enum CompileErrorKind {
     #[error("missing label `{label}`")]
     MissingLabel { label: Label },
     #[error("missing loop label `{label}`")]
-    MissingLoopLabel { label: Box<str> },
+    MissingLoopLabel {
+        label: Box<str>,
+        /// Labels of the enclosing loops, innermost first.
+        existing: Vec<(Box<str>, Span)>,
+    },
     #[error("base offset overflow")]
     BaseOverflow,
     #[error("offset overflow")]
@@ -300,6 +348,8 @@ pub enum CompileErrorKind {
     UnsupportedGenerics,
     #[error("#[test] attributes are not supported on nested items")]
     NestedTest { nested_span: Span },
+    #[error("#[test] functions cannot take arguments")]
+    TestWithArguments,
 }
 
 /// A single stap as an import entry.
@@ -13,6 +13,11 @@ pub trait CompileVisitor {
 
     /// Visit something that is a module.
     fn visit_mod(&self, _source_id: SourceId, _span: Span) {}
+
+    /// Visit a macro expansion, where `call_span` is the span of the macro
+    /// call and `expanded_span` is the span covered by the tokens it expanded
+    /// into.
+    fn visit_macro_expansion(&self, _source_id: SourceId, _call_span: Span, _expanded_span: Span) {}
 }
 
 /// A compile visitor that does nothing.
@@ -1,16 +1,20 @@
 use crate::ast;
+use crate::collections::HashMap;
 use crate::load::{FileSourceLoader, SourceLoader, Sources};
-use crate::query::{Build, BuildEntry, Query};
+use crate::macros::MacroContext;
+use crate::query::{Build, BuildEntry, Query, References};
 #[cfg(compiler_v2)]
 use crate::shared::ResultExt as _;
 use crate::shared::{Consts, Gen};
-use crate::worker::{LoadFileKind, Task, Worker};
-use crate::{Diagnostics, Options, Spanned as _, Storage};
-use runestick::{Context, Location, Source, Span};
+use crate::worker::{LoadFileKind, ParseCache, Task, Worker};
+use crate::{Diagnostics, Id, Options, Spanned as _, Storage};
+use runestick::{CompileItem, Context, Item, Location, Source, Span};
 use std::rc::Rc;
 use std::sync::Arc;
+use thiserror::Error;
 
 mod assembly;
+mod ast_rewriter;
 mod compile_error;
 mod compile_visitor;
 mod unit_builder;
@@ -18,6 +22,7 @@ mod v1;
 #[cfg(compiler_v2)]
 mod v2;
 
+pub use self::ast_rewriter::AstRewriter;
 pub use self::compile_error::{CompileError, CompileErrorKind, CompileResult, ImportEntryStep};
 pub use self::compile_visitor::{CompileVisitor, NoopCompileVisitor};
 pub use self::unit_builder::{BuildError, InsertMetaError, LinkerError, UnitBuilder};
@@ -25,6 +30,13 @@ use crate::parsing::Resolve as _;
 
 pub(crate) use self::assembly::{Assembly, AssemblyInst};
 
+/// Error raised when we failed to compile sources.
+///
+/// Look at the passed in [Diagnostics] instance for details.
+#[derive(Debug, Error)]
+#[error("failed to compile sources (see diagnostics for details)")]
+pub struct CompileSourcesError;
+
 /// Compile the given source with default options.
 pub fn compile(
     context: &Context,
@@ -32,39 +44,108 @@ pub fn compile(
     unit: &UnitBuilder,
     diagnostics: &mut Diagnostics,
 ) -> Result<(), ()> {
-    let visitor = Rc::new(NoopCompileVisitor::new());
-    let source_loader = Rc::new(FileSourceLoader::new());
-
     compile_with_options(
         context,
         sources,
         unit,
         diagnostics,
         &Default::default(),
-        visitor,
-        source_loader,
-    )?;
+        Default::default(),
+    )
+    .map_err(|CompileSourcesError| ())
+}
 
-    Ok(())
+/// Optional hooks into, and outputs from, [compile_with_options].
+///
+/// Unset fields fall back to the behavior of [compile].
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{CompileOptions, Diagnostics, Options, References, Sources, UnitBuilder};
+/// use runestick::{Context, Item, Source};
+///
+/// # fn main() -> runestick::Result<()> {
+/// let context = Context::with_default_modules()?;
+///
+/// let mut sources = Sources::new();
+/// sources.insert(Source::new("entry", "fn foo() { 42 } pub fn main() { foo() }"));
+///
+/// let unit = UnitBuilder::with_default_prelude();
+/// let mut diagnostics = Diagnostics::new();
+/// let mut references = References::default();
+///
+/// rune::compile_with_options(
+///     &context,
+///     &mut sources,
+///     &unit,
+///     &mut diagnostics,
+///     &Options::default(),
+///     CompileOptions {
+///         references: Some(&mut references),
+///         ..Default::default()
+///     },
+/// ).expect("compilation to succeed");
+///
+/// assert_eq!(references.find(&Item::with_item(&["foo"])).len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct CompileOptions<'a> {
+    /// The visitor to notify of compiled items, defaults to a
+    /// [NoopCompileVisitor].
+    pub visitor: Option<Rc<dyn CompileVisitor>>,
+    /// The loader used to resolve `mod` items, defaults to a
+    /// [FileSourceLoader].
+    pub source_loader: Option<Rc<dyn SourceLoader + 'a>>,
+    /// Rewriter called on the AST of every function before it's compiled.
+    pub rewriter: Option<Rc<dyn AstRewriter>>,
+    /// Cache used to reuse the syntax trees of modules which are unchanged
+    /// since they were last parsed.
+    pub cache: Option<&'a ParseCache>,
+    /// Populated with a reverse index of every location where an item was
+    /// referenced if compilation succeeds.
+    ///
+    /// This is useful for editor tooling, like finding all references to an
+    /// item.
+    pub references: Option<&'a mut References>,
 }
 
 /// Encode the given object into a collection of asm.
-pub fn compile_with_options<'a>(
+///
+/// Like [compile], errors are reported through the provided [Diagnostics].
+pub fn compile_with_options(
     context: &Context,
     sources: &mut Sources,
     unit: &UnitBuilder,
     diagnostics: &mut Diagnostics,
     options: &Options,
-    visitor: Rc<dyn CompileVisitor>,
-    source_loader: Rc<dyn SourceLoader + 'a>,
-) -> Result<(), ()> {
+    compile_options: CompileOptions<'_>,
+) -> Result<(), CompileSourcesError> {
+    let CompileOptions {
+        visitor,
+        source_loader,
+        rewriter,
+        cache,
+        references,
+    } = compile_options;
+
+    let visitor = visitor.unwrap_or_else(|| Rc::new(NoopCompileVisitor::new()));
+    let source_loader = source_loader.unwrap_or_else(|| Rc::new(FileSourceLoader::new()));
+
     // Global storage.
     let storage = Storage::new();
     // Shared id generator.
     let gen = Gen::new();
-    // Constants storage.
+    // Constants storage, seeded with the constants provided by the context
+    // so that they can be used in constant evaluation.
     let consts = Consts::default();
 
+    for (item, value) in context.iter_constants() {
+        consts.insert(item.clone(), value.clone());
+    }
+
     // The worker queue.
     let mut worker = Worker::new(
         context,
@@ -77,6 +158,7 @@ pub fn compile_with_options<'a>(
         source_loader,
         storage.clone(),
         gen,
+        cache,
     );
 
     // Queue up the initial sources to be loaded.
@@ -85,7 +167,7 @@ pub fn compile_with_options<'a>(
             Ok(result) => result,
             Err(error) => {
                 worker.diagnostics.error(source_id, error);
-                return Err(());
+                return Err(CompileSourcesError);
             }
         };
 
@@ -99,7 +181,7 @@ pub fn compile_with_options<'a>(
     worker.run();
 
     if worker.diagnostics.has_error() {
-        return Err(());
+        return Err(CompileSourcesError);
     }
 
     loop {
@@ -108,6 +190,7 @@ pub fn compile_with_options<'a>(
 
             let task = CompileBuildEntry {
                 visitor: &visitor,
+                rewriter: rewriter.as_ref(),
                 context,
                 options,
                 storage: &storage,
@@ -132,14 +215,111 @@ pub fn compile_with_options<'a>(
     }
 
     if worker.diagnostics.has_error() {
-        return Err(());
+        return Err(CompileSourcesError);
+    }
+
+    if let Some(references) = references {
+        *references = worker.query.references();
     }
 
     Ok(())
 }
 
+/// Encode the given object into a collection of asm, and return a reverse
+/// index of every location where an item was referenced.
+///
+/// This is useful for editor tooling, like finding all references to an item.
+/// Use [compile_with_options] to combine this with other [CompileOptions].
+pub fn compile_with_references<'a>(
+    context: &Context,
+    sources: &mut Sources,
+    unit: &UnitBuilder,
+    diagnostics: &mut Diagnostics,
+    options: &Options,
+    visitor: Rc<dyn CompileVisitor>,
+    source_loader: Rc<dyn SourceLoader + 'a>,
+) -> Result<References, CompileSourcesError> {
+    let mut references = References::default();
+
+    compile_with_options(
+        context,
+        sources,
+        unit,
+        diagnostics,
+        options,
+        CompileOptions {
+            visitor: Some(visitor),
+            source_loader: Some(source_loader),
+            references: Some(&mut references),
+            ..Default::default()
+        },
+    )?;
+
+    Ok(references)
+}
+
+/// Encode the given object into a collection of asm, reusing the syntax trees
+/// of modules which are unchanged since they were last parsed with the given
+/// `cache`.
+///
+/// Use [compile_with_options] to combine this with other [CompileOptions].
+pub fn compile_with_cache<'a>(
+    context: &Context,
+    sources: &mut Sources,
+    unit: &UnitBuilder,
+    diagnostics: &mut Diagnostics,
+    options: &Options,
+    visitor: Rc<dyn CompileVisitor>,
+    source_loader: Rc<dyn SourceLoader + 'a>,
+    cache: &ParseCache,
+) -> Result<(), CompileSourcesError> {
+    compile_with_options(
+        context,
+        sources,
+        unit,
+        diagnostics,
+        options,
+        CompileOptions {
+            visitor: Some(visitor),
+            source_loader: Some(source_loader),
+            cache: Some(cache),
+            ..Default::default()
+        },
+    )
+}
+
+/// Encode the given object into a collection of asm, calling the given
+/// [AstRewriter] on the AST of every function before it's compiled.
+///
+/// Use [compile_with_options] to combine this with other [CompileOptions].
+pub fn compile_with_rewriter<'a>(
+    context: &Context,
+    sources: &mut Sources,
+    unit: &UnitBuilder,
+    diagnostics: &mut Diagnostics,
+    options: &Options,
+    visitor: Rc<dyn CompileVisitor>,
+    source_loader: Rc<dyn SourceLoader + 'a>,
+    rewriter: Rc<dyn AstRewriter>,
+) -> Result<(), CompileSourcesError> {
+    compile_with_options(
+        context,
+        sources,
+        unit,
+        diagnostics,
+        options,
+        CompileOptions {
+            visitor: Some(visitor),
+            source_loader: Some(source_loader),
+            rewriter: Some(rewriter),
+            ..Default::default()
+        },
+    )
+}
+
 struct CompileBuildEntry<'a> {
     visitor: &'a Rc<dyn CompileVisitor>,
+    rewriter: Option<&'a Rc<dyn AstRewriter>>,
     context: &'a Context,
     options: &'a Options,
     storage: &'a Storage,
@@ -153,12 +333,15 @@ impl CompileBuildEntry<'_> {
     fn compiler1<'a>(
         &'a mut self,
         location: Location,
+        item: &Arc<CompileItem>,
         source: &Arc<Source>,
         span: Span,
         asm: &'a mut Assembly,
+        default_path: Option<Id>,
     ) -> self::v1::Compiler<'a> {
         self::v1::Compiler {
             visitor: self.visitor.clone(),
+            item: item.clone(),
             storage: self.storage,
             source_id: location.source_id,
             source: source.clone(),
@@ -172,6 +355,9 @@ impl CompileBuildEntry<'_> {
             loops: self::v1::Loops::new(),
             options: self.options,
             diagnostics: self.diagnostics,
+            default_path,
+            return_type: None,
+            condition_head: false,
         }
     }
 
@@ -201,6 +387,44 @@ impl CompileBuildEntry<'_> {
         }
     }
 
+    /// Call the rewriter, if present, on the given function.
+    ///
+    /// Paths introduced by the rewriter haven't been indexed, so if the
+    /// function is rewritten this returns a path id to resolve them in the
+    /// scope of the function.
+    fn rewrite_fn(
+        &self,
+        item: &Arc<CompileItem>,
+        impl_item: Option<&Arc<Item>>,
+        source: &Arc<Source>,
+        ast: &mut ast::ItemFn,
+    ) -> Result<Option<Id>, CompileError> {
+        let rewriter = match self.rewriter {
+            Some(rewriter) => rewriter,
+            None => return Ok(None),
+        };
+
+        let span = ast.span();
+
+        let macro_context = MacroContext {
+            macro_span: span,
+            stream_span: span,
+            source: source.clone(),
+            storage: self.storage.clone(),
+            query: self.query.clone(),
+            item: item.clone(),
+            consts: self.consts.clone(),
+        };
+
+        crate::macros::with_context(macro_context, || rewriter.rewrite_fn(&item.item, ast))?;
+
+        Ok(Some(self.query.insert_path(
+            &item.module,
+            impl_item,
+            &item.item,
+        )))
+    }
+
     fn compile(mut self, entry: BuildEntry) -> Result<(), CompileError> {
         let BuildEntry {
             item,
@@ -213,15 +437,17 @@ impl CompileBuildEntry<'_> {
         let mut asm = self.unit.new_assembly(location);
 
         match build {
-            Build::Function(f) => {
+            Build::Function(mut f) => {
                 use self::v1::AssembleFn as _;
 
+                let default_path = self.rewrite_fn(&item, None, &source, &mut f.ast)?;
+
                 let args = format_fn_args(&*source, f.ast.args.iter().map(|(a, _)| a))?;
 
                 let span = f.ast.span();
                 let count = f.ast.args.len();
 
-                let mut c = self.compiler1(location, &source, span, &mut asm);
+                let mut c = self.compiler1(location, &item, &source, span, &mut asm, default_path);
                 f.ast.assemble_fn(&mut c, false)?;
 
                 // NB: experimental compiler that is work-in-progress
@@ -245,18 +471,29 @@ impl CompileBuildEntry<'_> {
                         f.call,
                         args,
                     )?;
+
+                    if f.track_caller {
+                        self.unit.set_track_caller(&item.item);
+                    }
+
+                    if f.is_test {
+                        self.unit.add_test(&item.item);
+                    }
                 }
             }
-            Build::InstanceFunction(f) => {
+            Build::InstanceFunction(mut f) => {
                 use self::v1::AssembleFn as _;
 
+                let default_path =
+                    self.rewrite_fn(&item, Some(&f.impl_item), &source, &mut f.ast)?;
+
                 let args = format_fn_args(&*source, f.ast.args.iter().map(|(a, _)| a))?;
 
                 let span = f.ast.span();
                 let count = f.ast.args.len();
                 let name = f.ast.name.resolve(self.storage, &*source)?;
 
-                let mut c = self.compiler1(location, &source, span, &mut asm);
+                let mut c = self.compiler1(location, &item, &source, span, &mut asm, default_path);
                 let meta = c.lookup_meta(f.instance_span, &f.impl_item)?;
 
                 let type_hash = meta
@@ -278,6 +515,10 @@ impl CompileBuildEntry<'_> {
                         f.call,
                         args,
                     )?;
+
+                    if f.track_caller {
+                        self.unit.set_track_caller(&item.item);
+                    }
                 }
             }
             Build::Closure(closure) => {
@@ -287,7 +528,7 @@ impl CompileBuildEntry<'_> {
                 let args =
                     format_fn_args(&*source, closure.ast.args.as_slice().iter().map(|(a, _)| a))?;
 
-                let mut c = self.compiler1(location, &source, span, &mut asm);
+                let mut c = self.compiler1(location, &item, &source, span, &mut asm, None);
                 closure.ast.assemble_closure(&mut c, &closure.captures)?;
 
                 if used.is_unused() {
@@ -310,7 +551,7 @@ impl CompileBuildEntry<'_> {
                 let args = b.captures.len();
                 let span = b.ast.span();
 
-                let mut c = self.compiler1(location, &source, span, &mut asm);
+                let mut c = self.compiler1(location, &item, &source, span, &mut asm, None);
                 b.ast.assemble_closure(&mut c, &b.captures)?;
 
                 if used.is_unused() {
@@ -391,6 +632,7 @@ where
     I: IntoIterator<Item = &'a ast::FnArg>,
 {
     let mut args = Vec::new();
+    let mut names = HashMap::<&str, Span>::new();
 
     for arg in arguments {
         match arg {
@@ -400,6 +642,25 @@ where
             ast::FnArg::Pat(pat) => {
                 let span = pat.span();
 
+                if let ast::Pat::PatPath(path) = pat {
+                    if let Some(name) = path
+                        .path
+                        .try_as_ident()
+                        .and_then(|i| source.source(i.span()))
+                    {
+                        if let Some(first_span) = names.insert(name, span) {
+                            return Err(CompileError::new(
+                                span,
+                                CompileErrorKind::DuplicateParameter {
+                                    name: name.to_owned(),
+                                    first_span,
+                                    second_span: span,
+                                },
+                            ));
+                        }
+                    }
+                }
+
                 if let Some(s) = source.source(span) {
                     args.push(s.to_owned());
                 } else {
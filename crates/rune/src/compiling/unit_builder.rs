@@ -9,8 +9,8 @@ use crate::{CompileError, CompileErrorKind, Diagnostics};
 use runestick::debug::{DebugArgs, DebugSignature};
 use runestick::{
     Call, CompileMeta, CompileMetaKind, ConstValue, Context, DebugInfo, DebugInst, Hash, Inst,
-    IntoComponent, Item, Label, Location, Protocol, Rtti, Span, StaticString, Unit, UnitFn,
-    VariantRtti,
+    IntoComponent, Item, Label, Location, Protocol, Rtti, Span, StaticSet, StaticString, Unit,
+    UnitFn, VariantRtti,
 };
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -54,6 +54,7 @@ impl UnitBuilder {
         this.prelude("is_readable", &["is_readable"]);
         this.prelude("is_writable", &["is_writable"]);
         this.prelude("line", &["macros", "builtin", "line"]);
+        this.prelude("matches", &["matches"]);
         this.prelude("None", &["option", "Option", "None"]);
         this.prelude("Object", &["object", "Object"]);
         this.prelude("Ok", &["result", "Result", "Ok"]);
@@ -73,6 +74,38 @@ impl UnitBuilder {
         }
     }
 
+    /// Construct a new unit with a custom prelude, replacing the default one.
+    ///
+    /// Each entry maps a local name, which is visible unqualified in all
+    /// sources, to the item it refers to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rune::UnitBuilder;
+    /// use runestick::Item;
+    ///
+    /// let unit = UnitBuilder::with_prelude(vec![
+    ///     ("Some", Item::with_crate_item("std", &["option", "Option", "Some"])),
+    ///     ("println", Item::with_crate_item("std", &["io", "println"])),
+    /// ]);
+    /// ```
+    pub fn with_prelude<I, S>(prelude: I) -> Self
+    where
+        I: IntoIterator<Item = (S, Item)>,
+        S: AsRef<str>,
+    {
+        let mut this = Inner::default();
+
+        for (local, item) in prelude {
+            this.prelude.insert(local.as_ref().into(), item);
+        }
+
+        Self {
+            inner: Rc::new(RefCell::new(this)),
+        }
+    }
+
     /// Clone the prelude.
     pub(crate) fn prelude(&self) -> HashMap<Box<str>, Item> {
         self.inner.borrow().prelude.clone()
@@ -110,10 +143,12 @@ impl UnitBuilder {
             inner.static_strings,
             inner.static_bytes,
             inner.static_object_keys,
+            inner.static_sets,
             inner.rtti,
             inner.variant_rtti,
             inner.debug,
             inner.constants,
+            inner.tests,
         ))
     }
 
@@ -267,6 +302,20 @@ impl UnitBuilder {
         Ok(new_slot)
     }
 
+    /// Insert a static set of literals and return its associated slot that can
+    /// later be looked up through [lookup_set][Unit::lookup_set].
+    pub(crate) fn new_static_set(&self, set: StaticSet) -> usize {
+        let mut inner = self.inner.borrow_mut();
+
+        if let Some(existing_slot) = inner.static_sets.iter().position(|s| *s == set) {
+            return existing_slot;
+        }
+
+        let new_slot = inner.static_sets.len();
+        inner.static_sets.push(set);
+        new_slot
+    }
+
     /// Declare a new struct.
     pub(crate) fn insert_meta(&self, meta: &CompileMeta) -> Result<(), InsertMetaError> {
         let mut inner = self.inner.borrow_mut();
@@ -278,11 +327,13 @@ impl UnitBuilder {
                 let signature = DebugSignature {
                     path: meta.item.item.clone(),
                     args: DebugArgs::EmptyArgs,
+                    track_caller: false,
                 };
 
                 let rtti = Arc::new(Rtti {
                     hash: empty.hash,
                     item: meta.item.item.clone(),
+                    ordered_fields: None,
                 });
 
                 if inner.rtti.insert(empty.hash, rtti).is_some() {
@@ -314,11 +365,13 @@ impl UnitBuilder {
                 let signature = DebugSignature {
                     path: meta.item.item.clone(),
                     args: DebugArgs::TupleArgs(tuple.args),
+                    track_caller: false,
                 };
 
                 let rtti = Arc::new(Rtti {
                     hash: tuple.hash,
                     item: meta.item.item.clone(),
+                    ordered_fields: None,
                 });
 
                 if inner.rtti.insert(tuple.hash, rtti).is_some() {
@@ -341,12 +394,13 @@ impl UnitBuilder {
                     .functions
                     .insert(tuple.hash, signature);
             }
-            CompileMetaKind::Struct { .. } => {
+            CompileMetaKind::Struct { object, .. } => {
                 let hash = Hash::type_hash(&meta.item.item);
 
                 let rtti = Arc::new(Rtti {
                     hash,
                     item: meta.item.item.clone(),
+                    ordered_fields: object.ordered_fields.clone(),
                 });
 
                 inner.constants.insert(
@@ -378,6 +432,7 @@ impl UnitBuilder {
                 let signature = DebugSignature {
                     path: meta.item.item.clone(),
                     args: DebugArgs::EmptyArgs,
+                    track_caller: false,
                 };
 
                 if inner.functions.insert(empty.hash, info).is_some() {
@@ -414,6 +469,7 @@ impl UnitBuilder {
                 let signature = DebugSignature {
                     path: meta.item.item.clone(),
                     args: DebugArgs::TupleArgs(tuple.args),
+                    track_caller: false,
                 };
 
                 if inner.functions.insert(tuple.hash, info).is_some() {
@@ -503,6 +559,22 @@ impl UnitBuilder {
         Ok(())
     }
 
+    /// Mark the function at the given path as tracking its caller, so that
+    /// errors raised inside of it are reported at the call site.
+    pub(crate) fn set_track_caller(&self, path: &Item) {
+        let mut inner = self.inner.borrow_mut();
+        let hash = Hash::type_hash(path);
+
+        if let Some(signature) = inner.debug_info_mut().functions.get_mut(&hash) {
+            signature.track_caller = true;
+        }
+    }
+
+    /// Mark the function at the given path as a test.
+    pub(crate) fn add_test(&self, path: &Item) {
+        self.inner.borrow_mut().tests.push(path.clone());
+    }
+
     /// Register a new function re-export.
     pub(crate) fn new_function_reexport(
         &self,
@@ -565,6 +637,26 @@ impl UnitBuilder {
             ));
         }
 
+        // NB: instance functions named after an operator, like `add`, also
+        // implement the corresponding protocol so that `a + b` calls
+        // `a.add(b)`. Similarly `iter` and `next` are used to drive `for`
+        // loops.
+        let protocol =
+            Protocol::from_operator_fn(name).or_else(|| Protocol::from_iteration_fn(name));
+
+        if let Some(protocol) = protocol {
+            let protocol_fn = Hash::instance_function(type_hash, protocol);
+
+            if inner.functions.insert(protocol_fn, info).is_some() {
+                return Err(CompileError::new(
+                    location.span,
+                    CompileErrorKind::FunctionConflict {
+                        existing: signature,
+                    },
+                ));
+            }
+        }
+
         inner.constants.insert(
             Hash::instance_function(hash, Protocol::INTO_TYPE_NAME),
             ConstValue::String(signature.path.to_string()),
@@ -642,6 +734,8 @@ struct Inner {
     static_object_keys: Vec<Box<[String]>>,
     /// Used to detect duplicates in the collection of static object keys.
     static_object_keys_rev: HashMap<Hash, usize>,
+    /// Static sets of literals used in `in [..]` patterns.
+    static_sets: Vec<StaticSet>,
     /// Runtime type information for types.
     rtti: HashMap<Hash, Arc<Rtti>>,
     /// Runtime type information for variants.
@@ -655,6 +749,8 @@ struct Inner {
 
     /// Constant values
     constants: HashMap<Hash, ConstValue>,
+    /// Functions marked with `#[test]`.
+    tests: Vec<Item>,
 }
 
 impl Inner {
@@ -721,6 +817,11 @@ impl Inner {
                     let jump = translate_offset(span, pos, label, &assembly.labels)?;
                     self.instructions.push(Inst::IterNext { offset, jump });
                 }
+                AssemblyInst::PushTry { label } => {
+                    comment = Some(format!("label:{}", label));
+                    let offset = translate_offset(span, pos, label, &assembly.labels)?;
+                    self.instructions.push(Inst::PushTry { offset });
+                }
                 AssemblyInst::Raw { raw } => {
                     self.instructions.push(raw);
                 }
@@ -13,7 +13,10 @@ impl Assemble for ast::Expr {
             ast::Expr::For(expr_for) => expr_for.assemble(c, needs)?,
             ast::Expr::Loop(expr_loop) => expr_loop.assemble(c, needs)?,
             ast::Expr::Let(expr_let) => expr_let.assemble(c, needs)?,
-            ast::Expr::Group(expr) => expr.expr.assemble(c, needs)?,
+            ast::Expr::Group(expr) => {
+                c.check_redundant_parens(&expr.expr, None);
+                expr.expr.assemble(c, needs)?
+            }
             ast::Expr::Unary(expr_unary) => expr_unary.assemble(c, needs)?,
             ast::Expr::Assign(expr_assign) => expr_assign.assemble(c, needs)?,
             ast::Expr::Binary(expr_binary) => expr_binary.assemble(c, needs)?,
@@ -27,6 +30,7 @@ impl Assemble for ast::Expr {
             ast::Expr::Match(expr_match) => expr_match.assemble(c, needs)?,
             ast::Expr::Await(expr_await) => expr_await.assemble(c, needs)?,
             ast::Expr::Try(expr_try) => expr_try.assemble(c, needs)?,
+            ast::Expr::TryCatch(expr_try_catch) => expr_try_catch.assemble(c, needs)?,
             ast::Expr::Select(expr_select) => expr_select.assemble(c, needs)?,
             ast::Expr::Call(expr_call) => expr_call.assemble(c, needs)?,
             ast::Expr::FieldAccess(expr_field_access) => expr_field_access.assemble(c, needs)?,
@@ -6,6 +6,8 @@ impl Assemble for ast::ExprAssign {
         let span = self.span();
         log::trace!("ExprAssign => {:?}", c.source.source(span));
 
+        c.check_redundant_parens(&self.rhs, None);
+
         let supported = match &self.lhs {
             // <var> = <value>
             ast::Expr::Path(path) if path.rest.is_empty() => {
@@ -17,6 +19,11 @@ impl Assemble for ast::ExprAssign {
                     .ok_or_else(|| CompileError::msg(path, "unsupported path"))?;
                 let ident = segment.resolve(c.storage, &*c.source)?;
                 let var = c.scopes.get_var(&*ident, c.source_id, span)?;
+
+                if c.options.let_mut {
+                    var.check_mutable(span)?;
+                }
+
                 c.asm.push(Inst::Replace { offset: var.offset }, span);
                 true
             }
@@ -80,7 +87,12 @@ impl Assemble for ast::ExprAssign {
                 c.scopes.undecl_anon(span, 3)?;
                 true
             }
-            _ => false,
+            lhs => {
+                return Err(CompileError::new(
+                    lhs,
+                    CompileErrorKind::InvalidAssignmentTarget,
+                ));
+            }
         };
 
         if !supported {
@@ -15,6 +15,14 @@ impl Assemble for ast::ExprBinary {
             c.source.source(self.rhs.span())
         );
 
+        if self.op.is_assign() {
+            c.check_redundant_parens(&self.rhs, None);
+        } else {
+            let precedence = self.op.precedence();
+            c.check_redundant_parens(&self.lhs, Some(precedence));
+            c.check_redundant_parens(&self.rhs, Some(precedence));
+        }
+
         // Special expressions which operates on the stack in special ways.
         if self.op.is_assign() {
             compile_assign_binop(c, &self.lhs, &self.rhs, self.op, needs)?;
@@ -26,6 +34,10 @@ impl Assemble for ast::ExprBinary {
             return Ok(Asm::top(span));
         }
 
+        if c.options.warn_mixed_numbers {
+            check_mixed_number_literals(c, span, &self.lhs, &self.rhs)?;
+        }
+
         let guard = c.scopes.push_child(span)?;
 
         // NB: need to declare these as anonymous local variables so that they
@@ -97,6 +109,28 @@ fn compile_conditional_binop(
 ) -> CompileResult<()> {
     let span = lhs.span().join(rhs.span());
 
+    // NB: a constant left hand side either decides the outcome on its own, in
+    // which case the right hand side is never evaluated, or the outcome is
+    // entirely decided by the right hand side.
+    if c.options.fold_constant_conditions {
+        if let Some(value) = c.const_bool(lhs) {
+            match (bin_op, value) {
+                (ast::BinOp::And, true) | (ast::BinOp::Or, false) => {
+                    rhs.assemble(c, needs)?.apply(c)?;
+                    return Ok(());
+                }
+                (ast::BinOp::And, false) | (ast::BinOp::Or, true) => {
+                    if needs.value() {
+                        c.asm.push(Inst::bool(value), span);
+                    }
+
+                    return Ok(());
+                }
+                _ => (),
+            }
+        }
+    }
+
     let end_label = c.asm.new_label("conditional_end");
 
     lhs.assemble(c, Needs::Value)?.apply(c)?;
@@ -149,6 +183,10 @@ fn compile_assign_binop(
             let ident = segment.resolve(c.storage, &*c.source)?;
             let var = c.scopes.get_var(&*ident, c.source_id, span)?;
 
+            if c.options.let_mut {
+                var.check_mutable(span)?;
+            }
+
             Some(InstTarget::Offset(var.offset))
         }
         // <expr>.<field> <op> <value>
@@ -220,3 +258,32 @@ fn compile_assign_binop(
 
     Ok(())
 }
+
+/// Warn if the operands are an integer literal and a float literal.
+fn check_mixed_number_literals(
+    c: &mut Compiler<'_>,
+    span: Span,
+    lhs: &ast::Expr,
+    rhs: &ast::Expr,
+) -> CompileResult<()> {
+    let integer = match (number_literal(c, lhs)?, number_literal(c, rhs)?) {
+        (Some(ast::Number::Integer(..)), Some(ast::Number::Float(..))) => lhs.span(),
+        (Some(ast::Number::Float(..)), Some(ast::Number::Integer(..))) => rhs.span(),
+        _ => return Ok(()),
+    };
+
+    c.diagnostics
+        .mixed_number_literals(c.source_id, span, integer, c.context());
+    Ok(())
+}
+
+/// Resolve the given expression if it's a number literal.
+fn number_literal(c: &Compiler<'_>, expr: &ast::Expr) -> CompileResult<Option<ast::Number>> {
+    match expr {
+        ast::Expr::Lit(expr_lit) => match &expr_lit.lit {
+            ast::Lit::Number(lit_number) => Ok(Some(lit_number.resolve(c.storage, &c.source)?)),
+            _ => Ok(None),
+        },
+        _ => Ok(None),
+    }
+}
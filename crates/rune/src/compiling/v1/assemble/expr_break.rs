@@ -18,20 +18,24 @@ impl Assemble for ast::ExprBreak {
             }
         };
 
-        let (last_loop, to_drop, has_value) = if let Some(expr) = &self.expr {
-            match expr {
-                ast::ExprBreakValue::Expr(expr) => {
-                    expr.assemble(c, current_loop.needs)?.apply(c)?;
-                    (current_loop, current_loop.drop.into_iter().collect(), true)
-                }
-                ast::ExprBreakValue::Label(label) => {
-                    let (last_loop, to_drop) =
-                        c.loops.walk_until_label(c.storage, &*c.source, *label)?;
-                    (last_loop, to_drop, false)
+        let (last_loop, to_drop) = match &self.label {
+            Some(label) => c.loops.walk_until_label(c.storage, &*c.source, *label)?,
+            None => (current_loop, current_loop.drop.into_iter().collect()),
+        };
+
+        let has_value = match &self.expr {
+            Some(expr) => {
+                if !last_loop.break_value {
+                    return Err(CompileError::new(
+                        expr.span(),
+                        CompileErrorKind::BreakValueUnsupported,
+                    ));
                 }
+
+                expr.assemble(c, last_loop.needs)?.apply(c)?;
+                true
             }
-        } else {
-            (current_loop, current_loop.drop.into_iter().collect(), false)
+            None => false,
         };
 
         // Drop loop temporary. Typically an iterator.
@@ -6,6 +6,25 @@ impl Assemble for ast::ExprCall {
         let span = self.span();
         log::trace!("ExprCall => {:?}", c.source.source(span));
 
+        for (arg, _) in &self.args {
+            // NB: parenthesizing a range is the only way to pass it as a
+            // single argument instead of spreading it.
+            if !matches!(arg, ast::Expr::Group(group) if as_spread(&group.expr).is_some()) {
+                c.check_redundant_parens(arg, None);
+            }
+        }
+
+        let spread = match self.args.as_slice().split_last() {
+            Some(((last, _), rest)) => {
+                if let Some((arg, _)) = rest.iter().find(|(arg, _)| as_spread(arg).is_some()) {
+                    return Err(CompileError::new(arg, CompileErrorKind::SpreadNotLast));
+                }
+
+                as_spread(last).is_some()
+            }
+            None => false,
+        };
+
         let guard = c.scopes.push_child(span)?;
         let args = self.args.len();
 
@@ -13,7 +32,13 @@ impl Assemble for ast::ExprCall {
         // or expand the expression.
         #[allow(clippy::never_loop)]
         let path = loop {
-            let expr = &self.expr;
+            let mut expr = &self.expr;
+
+            // NB: a parenthesized callee like `(foo)(1, 2)` is called directly
+            // instead of constructing a function pointer which is then called.
+            while let ast::Expr::Group(expr_group) = expr {
+                expr = &expr_group.expr;
+            }
 
             let use_expr = match expr {
                 ast::Expr::Path(path) => {
@@ -33,14 +58,16 @@ impl Assemble for ast::ExprCall {
                             expr.assemble(c, Needs::Value)?.apply(c)?;
                             c.scopes.decl_anon(span)?;
 
-                            for (expr, _) in &self.args {
-                                expr.assemble(c, Needs::Value)?.apply(c)?;
-                                c.scopes.decl_anon(span)?;
-                            }
+                            assemble_args(c, &self.args, span)?;
 
                             let ident = ident.resolve(&c.storage, &*c.source)?;
                             let hash = Hash::instance_fn_name(ident.as_ref());
-                            c.asm.push(Inst::CallInstance { hash, args }, span);
+
+                            if spread {
+                                c.asm.push(Inst::CallInstanceSpread { hash, args }, span);
+                            } else {
+                                c.asm.push(Inst::CallInstance { hash, args }, span);
+                            }
                             false
                         } else {
                             true
@@ -55,13 +82,15 @@ impl Assemble for ast::ExprCall {
             if use_expr {
                 log::trace!("ExprCall(Other) => {:?}", c.source.source(span));
 
-                for (expr, _) in &self.args {
-                    expr.assemble(c, Needs::Value)?.apply(c)?;
-                    c.scopes.decl_anon(span)?;
-                }
+                assemble_args(c, &self.args, span)?;
 
                 expr.assemble(c, Needs::Value)?.apply(c)?;
-                c.asm.push(Inst::CallFn { args }, span);
+
+                if spread {
+                    c.asm.push(Inst::CallFnSpread { args }, span);
+                } else {
+                    c.asm.push(Inst::CallFn { args }, span);
+                }
             }
 
             if !needs.value() {
@@ -81,13 +110,15 @@ impl Assemble for ast::ExprCall {
                 .copied();
 
             if let Some(var) = local {
-                for (expr, _) in &self.args {
-                    expr.assemble(c, Needs::Value)?.apply(c)?;
-                    c.scopes.decl_anon(span)?;
-                }
+                assemble_args(c, &self.args, span)?;
 
                 var.copy(&mut c.asm, span, format!("var `{}`", name));
-                c.asm.push(Inst::CallFn { args }, span);
+
+                if spread {
+                    c.asm.push(Inst::CallFnSpread { args }, span);
+                } else {
+                    c.asm.push(Inst::CallFn { args }, span);
+                }
 
                 if !needs.value() {
                     c.asm.push(Inst::Pop, span);
@@ -101,21 +132,9 @@ impl Assemble for ast::ExprCall {
         let meta = c.lookup_meta(path.span(), &named.item)?;
 
         match &meta.kind {
-            CompileMetaKind::UnitStruct { .. } | CompileMetaKind::UnitVariant { .. } => {
-                if 0 != self.args.len() {
-                    return Err(CompileError::new(
-                        span,
-                        CompileErrorKind::UnsupportedArgumentCount {
-                            meta: meta.clone(),
-                            expected: 0,
-                            actual: self.args.len(),
-                        },
-                    ));
-                }
-            }
             CompileMetaKind::TupleStruct { tuple, .. }
             | CompileMetaKind::TupleVariant { tuple, .. } => {
-                if tuple.args != self.args.len() {
+                if tuple.args != self.args.len() && !spread {
                     return Err(CompileError::new(
                         span,
                         CompileErrorKind::UnsupportedArgumentCount {
@@ -132,8 +151,20 @@ impl Assemble for ast::ExprCall {
                         .remove_tuple_call_parens(c.source_id, span, tuple, c.context());
                 }
             }
-            CompileMetaKind::Function { .. } => (),
+            CompileMetaKind::Function { .. } => {
+                if needs.value() && c.query.is_diverging(c.context, &meta.item.item) {
+                    c.diagnostics
+                        .diverging_call_value(c.source_id, span, c.context());
+                }
+            }
             CompileMetaKind::ConstFn { id, .. } => {
+                if spread {
+                    return Err(CompileError::new(
+                        span,
+                        CompileErrorKind::UnsupportedSpread { meta: meta.clone() },
+                    ));
+                }
+
                 let from = c.query.item_for(self)?;
                 let const_fn = c.query.const_fn_for((self.span(), *id))?;
 
@@ -145,22 +176,26 @@ impl Assemble for ast::ExprCall {
                 return Ok(Asm::top(span));
             }
             _ => {
-                return Err(CompileError::expected_meta(
+                return Err(CompileError::new(
                     span,
-                    meta,
-                    "something that can be called as a function",
+                    CompileErrorKind::NotCallable {
+                        item: meta.item.item.clone(),
+                    },
                 ));
             }
         };
 
-        for (expr, _) in &self.args {
-            expr.assemble(c, Needs::Value)?.apply(c)?;
-            c.scopes.decl_anon(span)?;
-        }
+        assemble_args(c, &self.args, span)?;
 
         let hash = Hash::type_hash(&meta.item.item);
-        c.asm
-            .push_with_comment(Inst::Call { hash, args }, span, meta.to_string());
+
+        let inst = if spread {
+            Inst::CallSpread { hash, args }
+        } else {
+            Inst::Call { hash, args }
+        };
+
+        c.asm.push_with_comment(inst, span, meta.to_string());
 
         // NB: we put it here to preserve the call in case it has side effects.
         // But if we don't need the value, then pop it from the stack.
@@ -172,3 +207,36 @@ impl Assemble for ast::ExprCall {
         Ok(Asm::top(span))
     }
 }
+
+/// Assemble the arguments of a call, where a spread argument pushes the vector
+/// to spread.
+fn assemble_args(
+    c: &mut Compiler<'_>,
+    args: &ast::Parenthesized<ast::Expr, T![,]>,
+    span: Span,
+) -> CompileResult<()> {
+    for (expr, _) in args {
+        let expr = as_spread(expr).unwrap_or(expr);
+        expr.assemble(c, Needs::Value)?.apply(c)?;
+        c.scopes.decl_anon(span)?;
+    }
+
+    Ok(())
+}
+
+/// Test if the given argument is a spread argument like `..args`, returning
+/// the expression being spread.
+fn as_spread(expr: &ast::Expr) -> Option<&ast::Expr> {
+    match expr {
+        ast::Expr::Range(range) => match &**range {
+            ast::ExprRange {
+                from: None,
+                limits: ast::ExprRangeLimits::HalfOpen(..),
+                to: Some(to),
+                ..
+            } => Some(to),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -1,11 +1,27 @@
 use crate::compiling::v1::assemble::prelude::*;
 
+/// The largest number of iterations a `for` loop over a constant range can have
+/// for it to be unrolled.
+const UNROLL_LIMIT: i64 = 16;
+
 /// Compile a for loop.
 impl Assemble for ast::ExprFor {
     fn assemble(&self, c: &mut Compiler<'_>, needs: Needs) -> CompileResult<Asm> {
         let span = self.span();
         log::trace!("ExprFor => {:?}", c.source.source(span));
 
+        // NB: loops which are the target of a `break` or `continue` are
+        // assembled as normal loops.
+        if c.options.unroll_loops && !c.query.has_loop_control(c.source_id, span) {
+            if let Some(range) = c.const_range(&self.iter) {
+                if range.end.saturating_sub(range.start) <= UNROLL_LIMIT {
+                    return assemble_unrolled(self, c, needs, range);
+                }
+            }
+        }
+
+        check_iterable(c, &self.iter)?;
+
         let continue_label = c.asm.new_label("for_continue");
         let end_label = c.asm.new_label("for_end");
         let break_label = c.asm.new_label("for_break");
@@ -14,7 +30,8 @@ impl Assemble for ast::ExprFor {
 
         let (iter_offset, loop_scope_expected) = {
             let loop_scope_expected = c.scopes.push_child(span)?;
-            self.iter.assemble(c, Needs::Value)?.apply(c)?;
+            c.assemble_condition_head(&self.iter, Needs::Value)?
+                .apply(c)?;
 
             let iter_offset = c.scopes.decl_anon(span)?;
             c.asm.push_with_comment(
@@ -68,13 +85,14 @@ impl Assemble for ast::ExprFor {
         let continue_var_count = c.scopes.total_var_count(span)?;
         c.asm.label(continue_label)?;
 
-        let _guard = c.loops.push(Loop {
+        let loop_guard = c.loops.push(Loop {
             label: self.label.map(|(label, _)| label),
             continue_label,
             continue_var_count,
             break_label,
             break_var_count,
             needs,
+            break_value: self.expr_else.is_some(),
             drop: Some(iter_offset),
         });
 
@@ -154,13 +172,139 @@ impl Assemble for ast::ExprFor {
 
         c.clean_last_scope(span, loop_scope_expected, Needs::None)?;
 
-        // NB: If a value is needed from a for loop, encode it as a unit.
-        if needs.value() {
-            c.asm.push(Inst::unit(), span);
-        }
+        // NB: the else block is not part of the loop, so a `break` in it
+        // refers to an enclosing loop.
+        drop(loop_guard);
+        assemble_else(self, c, needs)?;
 
         // NB: breaks produce their own value.
         c.asm.label(break_label)?;
         Ok(Asm::top(span))
     }
 }
+
+/// Check that the expression being iterated over can be iterated over, in case
+/// its type is statically known.
+///
+/// Literals are checked against the context, and script structs need an `iter`
+/// instance function.
+fn check_iterable(c: &mut Compiler<'_>, expr: &ast::Expr) -> CompileResult<()> {
+    let span = expr.span();
+
+    let ty = match expr {
+        ast::Expr::Group(expr_group) => return check_iterable(c, &expr_group.expr),
+        ast::Expr::Lit(..) => {
+            let ty = match c.literal_type(expr)? {
+                Some(ty) => ty,
+                None => return Ok(()),
+            };
+
+            let into_iter = Hash::instance_function(ty.hash, runestick::Protocol::INTO_ITER);
+
+            if c.context.lookup(into_iter).is_some() {
+                return Ok(());
+            }
+
+            runestick::TypeInfo::StaticType(ty).to_string()
+        }
+        ast::Expr::Object(expr_object) => {
+            let path = match &expr_object.ident {
+                ast::ObjectIdent::Named(path) => path,
+                ast::ObjectIdent::Anonymous(..) => return Ok(()),
+            };
+
+            let named = c.convert_path_to_named(path)?;
+
+            let meta = match c.try_lookup_meta(span, &named.item)? {
+                Some(meta) => meta,
+                None => return Ok(()),
+            };
+
+            if !matches!(meta.kind, CompileMetaKind::Struct { .. }) || meta.source.is_none() {
+                return Ok(());
+            }
+
+            let iter = meta.item.item.extended("iter");
+
+            if c.query
+                .query_meta(span, &iter, Default::default())?
+                .is_some()
+            {
+                return Ok(());
+            }
+
+            meta.item.item.to_string()
+        }
+        _ => return Ok(()),
+    };
+
+    Err(CompileError::new(
+        span,
+        CompileErrorKind::NotIterable { ty: ty.into() },
+    ))
+}
+
+/// Compile a for loop over a constant range by emitting the body once for each
+/// value in the range.
+///
+/// Each unrolled iteration gets its own loop context, so that `continue` jumps
+/// to the next iteration and `break` jumps past the last one.
+fn assemble_unrolled(
+    this: &ast::ExprFor,
+    c: &mut Compiler<'_>,
+    needs: Needs,
+    range: std::ops::Range<i64>,
+) -> CompileResult<Asm> {
+    let span = this.span();
+    log::trace!("ExprFor(Unrolled) => {:?}", c.source.source(span));
+
+    let break_label = c.asm.new_label("unroll_break");
+    let break_var_count = c.scopes.total_var_count(span)?;
+    let binding_span = this.binding.span();
+
+    for n in range {
+        let continue_label = c.asm.new_label("unroll_continue");
+
+        let _guard = c.loops.push(Loop {
+            label: this.label.map(|(label, _)| label),
+            continue_label,
+            continue_var_count: break_var_count,
+            break_label,
+            break_var_count,
+            needs,
+            break_value: this.expr_else.is_some(),
+            drop: None,
+        });
+
+        let guard = c.scopes.push_child(span)?;
+
+        c.asm.push(Inst::integer(n), binding_span);
+        let binding_offset = c.scopes.decl_anon(binding_span)?;
+        c.compile_pat_offset(&this.binding, binding_offset)?;
+
+        this.body.assemble(c, Needs::None)?.apply(c)?;
+        c.clean_last_scope(span, guard, Needs::None)?;
+
+        c.asm.label(continue_label)?;
+    }
+
+    assemble_else(this, c, needs)?;
+
+    // NB: breaks produce their own value.
+    c.asm.label(break_label)?;
+    Ok(Asm::top(span))
+}
+
+/// Assemble the value produced when the loop completes without a `break`.
+///
+/// This is the `else` block if present, since breaks jump past it.
+fn assemble_else(this: &ast::ExprFor, c: &mut Compiler<'_>, needs: Needs) -> CompileResult<()> {
+    if let Some(expr_else) = &this.expr_else {
+        expr_else.block.assemble(c, needs)?.apply(c)?;
+    } else if needs.value() {
+        // NB: If a value is needed from a for loop, encode it as a unit.
+        c.asm.push(Inst::unit(), this.span());
+    }
+
+    Ok(())
+}
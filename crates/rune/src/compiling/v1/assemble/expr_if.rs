@@ -6,6 +6,30 @@ impl Assemble for ast::ExprIf {
         let span = self.span();
         log::trace!("ExprIf => {:?}", c.source.source(span));
 
+        let constant = c.const_condition(&self.condition);
+
+        if c.options.fold_constant_conditions {
+            match constant {
+                Some(true) => {
+                    let scope = c.scopes.child(span)?;
+                    let expected = c.scopes.push(scope);
+                    self.block.assemble(c, needs)?.apply(c)?;
+                    c.clean_last_scope(span, expected, needs)?;
+                    return Ok(Asm::top(span));
+                }
+                Some(false) if self.expr_else_ifs.is_empty() => {
+                    if let Some(fallback) = &self.expr_else {
+                        fallback.block.assemble(c, needs)?.apply(c)?;
+                    } else if needs.value() {
+                        c.asm.push(Inst::unit(), span);
+                    }
+
+                    return Ok(Asm::top(span));
+                }
+                _ => (),
+            }
+        }
+
         let then_label = c.asm.new_label("if_then");
         let end_label = c.asm.new_label("if_end");
 
@@ -13,6 +37,8 @@ impl Assemble for ast::ExprIf {
         let then_scope = c.compile_condition(&self.condition, then_label)?;
 
         for branch in &self.expr_else_ifs {
+            c.const_condition(&branch.condition);
+
             let label = c.asm.new_label("if_branch");
             let scope = c.compile_condition(&branch.condition, label)?;
             branches.push((branch, label, scope));
@@ -1,4 +1,5 @@
 use crate::compiling::v1::assemble::prelude::*;
+use crate::IrValue;
 
 /// Compile an expression.
 impl Assemble for ast::ExprIndex {
@@ -6,6 +7,8 @@ impl Assemble for ast::ExprIndex {
         let span = self.span();
         log::trace!("ExprIndex => {:?}", c.source.source(span));
 
+        check_const_index(c, self)?;
+
         let guard = c.scopes.push_child(span)?;
 
         let target = self.target.assemble(c, Needs::Value)?.apply_targeted(c)?;
@@ -23,3 +26,42 @@ impl Assemble for ast::ExprIndex {
         Ok(Asm::top(span))
     }
 }
+
+/// Check that a constant index into a vector literal is in bounds.
+fn check_const_index(c: &mut Compiler<'_>, expr_index: &ast::ExprIndex) -> CompileResult<()> {
+    use num::ToPrimitive as _;
+
+    let mut target = &expr_index.target;
+
+    while let ast::Expr::Group(expr_group) = target {
+        target = &expr_group.expr;
+    }
+
+    let len = match target {
+        ast::Expr::Vec(expr_vec) => expr_vec.items.len(),
+        _ => return Ok(()),
+    };
+
+    let index = match c.eval_literal_expr(&expr_index.index) {
+        Some(IrValue::Integer(index)) => index,
+        _ => return Ok(()),
+    };
+
+    if index
+        .to_usize()
+        .map(|index| index < len)
+        .unwrap_or_default()
+    {
+        return Ok(());
+    }
+
+    let index = match index.to_i64() {
+        Some(index) => index,
+        None => return Ok(()),
+    };
+
+    Err(CompileError::new(
+        expr_index,
+        CompileErrorKind::ConstIndexOutOfBounds { len, index },
+    ))
+}
@@ -6,6 +6,8 @@ impl Assemble for ast::ExprLet {
         let span = self.span();
         log::trace!("ExprLet => {:?}", c.source.source(span));
 
+        c.check_return_arity(&self.pat, &self.expr)?;
+
         let load = |c: &mut Compiler, needs: Needs| {
             // NB: assignments "move" the value being assigned.
             self.expr.assemble(c, needs)?.apply(c)?;
@@ -18,6 +18,7 @@ impl Assemble for ast::ExprLoop {
             break_label,
             break_var_count: var_count,
             needs,
+            break_value: true,
             drop: None,
         });
 
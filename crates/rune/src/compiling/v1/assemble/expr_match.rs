@@ -7,7 +7,8 @@ impl Assemble for ast::ExprMatch {
 
         let expected_scopes = c.scopes.push_child(span)?;
 
-        self.expr.assemble(c, Needs::Value)?.apply(c)?;
+        c.assemble_condition_head(&self.expr, Needs::Value)?
+            .apply(c)?;
         // Offset of the expression.
         let offset = c.scopes.decl_anon(span)?;
 
@@ -1,5 +1,6 @@
 use crate::collections::{HashMap, HashSet};
 use crate::compiling::v1::assemble::prelude::*;
+use runestick::{PanicReason, TypeCheck};
 
 /// Compile a literal object.
 impl Assemble for ast::ExprObject {
@@ -30,6 +31,68 @@ impl Assemble for ast::ExprObject {
             }
         }
 
+        // NB: the base is resolved and evaluated before any of the fields, so
+        // that the remaining fields can be copied out of it by offset.
+        let base = match &self.base {
+            Some((dot_dot, expr)) => {
+                let base_span = dot_dot.span().join(expr.span());
+
+                let meta = match &self.ident {
+                    ast::ObjectIdent::Named(path) => {
+                        let named = c.convert_path_to_named(path)?;
+                        Some(c.lookup_meta(path.span(), &named.item)?)
+                    }
+                    ast::ObjectIdent::Anonymous(..) => None,
+                };
+
+                let (type_check, fields) = match meta.as_ref().map(|meta| &meta.kind) {
+                    Some(CompileMetaKind::Struct { object, .. }) => {
+                        let hash = Hash::type_hash(&meta.as_ref().unwrap().item.item);
+                        (TypeCheck::Type(hash), object.fields.clone())
+                    }
+                    Some(CompileMetaKind::StructVariant { object, .. }) => {
+                        let hash = Hash::type_hash(&meta.as_ref().unwrap().item.item);
+                        (TypeCheck::Variant(hash), object.fields.clone())
+                    }
+                    _ => {
+                        return Err(CompileError::new(
+                            base_span,
+                            CompileErrorKind::UnsupportedObjectBase,
+                        ));
+                    }
+                };
+
+                expr.assemble(c, Needs::Value)?.apply(c)?;
+                let offset = c.scopes.decl_anon(base_span)?;
+
+                let ok = c.asm.new_label("struct_update_ok");
+                let slot = c
+                    .unit
+                    .new_static_object_keys_iter(base_span, &[] as &[&str])?;
+
+                c.asm.push(Inst::Copy { offset }, base_span);
+                c.asm.push(
+                    Inst::MatchObject {
+                        type_check,
+                        slot,
+                        exact: false,
+                    },
+                    base_span,
+                );
+                c.asm.jump_if(ok, base_span);
+                c.asm.push(
+                    Inst::Panic {
+                        reason: PanicReason::StructUpdateTypeMismatch,
+                    },
+                    base_span,
+                );
+                c.asm.label(ok)?;
+
+                Some((offset, base_span, fields))
+            }
+            None => None,
+        };
+
         for (assign, _) in &self.assignments {
             let span = assign.span();
 
@@ -37,12 +100,42 @@ impl Assemble for ast::ExprObject {
                 expr.assemble(c, Needs::Value)?.apply(c)?;
             } else {
                 let key = assign.key.resolve(&c.storage, &*c.source)?;
-                let var = c.scopes.get_var(&*key, c.source_id, span)?;
-                var.copy(&mut c.asm, span, format!("name `{}`", key));
+
+                if let Some(value) = c.scopes.try_get_const(&key) {
+                    let value = value.clone();
+                    value.assemble_const(c, Needs::Value, span)?;
+                } else {
+                    let var = c.scopes.get_var(&*key, c.source_id, span)?;
+                    var.copy(&mut c.asm, span, format!("name `{}`", key));
+                }
             }
             c.scopes.decl_anon(span)?;
         }
 
+        // Copy the fields which weren't explicitly assigned out of the base.
+        if let Some((offset, base_span, fields)) = &base {
+            let mut remaining = fields
+                .iter()
+                .filter(|field| !keys_dup.contains_key(field.as_ref()))
+                .cloned()
+                .collect::<Vec<Box<str>>>();
+
+            remaining.sort();
+
+            for field in remaining {
+                let slot = c.unit.new_static_string(*base_span, &field)?;
+                c.asm.push(
+                    Inst::ObjectIndexGetAt {
+                        offset: *offset,
+                        slot,
+                    },
+                    *base_span,
+                );
+                c.scopes.decl_anon(*base_span)?;
+                keys.push(field);
+            }
+        }
+
         let slot = c.unit.new_static_object_keys_iter(span, &keys)?;
 
         match &self.ident {
@@ -58,13 +151,21 @@ impl Assemble for ast::ExprObject {
                         c.asm.push(Inst::UnitStruct { hash }, span);
                     }
                     CompileMetaKind::Struct { object, .. } => {
-                        check_object_fields(&object.fields, check_keys, span, &meta.item.item)?;
+                        if base.is_none() {
+                            check_object_fields(&object.fields, check_keys, span, &meta.item.item)?;
+                        } else {
+                            check_object_known_fields(&object.fields, check_keys, &meta.item.item)?;
+                        }
 
                         let hash = Hash::type_hash(&meta.item.item);
                         c.asm.push(Inst::Struct { hash, slot }, span);
                     }
                     CompileMetaKind::StructVariant { object, .. } => {
-                        check_object_fields(&object.fields, check_keys, span, &meta.item.item)?;
+                        if base.is_none() {
+                            check_object_fields(&object.fields, check_keys, span, &meta.item.item)?;
+                        } else {
+                            check_object_known_fields(&object.fields, check_keys, &meta.item.item)?;
+                        }
 
                         let hash = Hash::type_hash(&meta.item.item);
                         c.asm.push(Inst::StructVariant { hash, slot }, span);
@@ -82,6 +183,11 @@ impl Assemble for ast::ExprObject {
             }
         }
 
+        // Drop the base which sits beneath the constructed value.
+        if base.is_some() {
+            c.asm.push(Inst::Clean { count: 1 }, span);
+        }
+
         // No need to encode an object since the value is not needed.
         if !needs.value() {
             c.diagnostics.not_used(c.source_id, span, c.context());
@@ -125,3 +231,25 @@ fn check_object_fields(
 
     Ok(())
 }
+
+/// Check that all explicitly assigned keys are fields of the struct, without
+/// requiring that every field is assigned.
+fn check_object_known_fields(
+    fields: &HashSet<Box<str>>,
+    check_keys: Vec<(Box<str>, Span)>,
+    item: &Item,
+) -> CompileResult<()> {
+    for (field, span) in check_keys {
+        if !fields.contains(&field) {
+            return Err(CompileError::new(
+                span,
+                CompileErrorKind::LitObjectNotField {
+                    field,
+                    item: item.clone(),
+                },
+            ));
+        }
+    }
+
+    Ok(())
+}
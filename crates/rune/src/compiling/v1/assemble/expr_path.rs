@@ -20,6 +20,12 @@ impl Assemble for ast::Path {
 
         if let Needs::Value = needs {
             if let Some(local) = named.as_local() {
+                if let Some(value) = c.scopes.try_get_const(local) {
+                    let value = value.clone();
+                    value.assemble_const(c, needs, span)?;
+                    return Ok(Asm::top(span));
+                }
+
                 if let Some(var) = c.scopes.try_get_var(local, c.source_id, span)? {
                     return Ok(Asm::var(span, *var, local.into()));
                 }
@@ -18,10 +18,14 @@ impl Assemble for ast::ExprReturn {
         let total_var_count = c.scopes.total_var_count(span)?;
 
         if let Some(expr) = &self.expr {
+            c.check_redundant_parens(expr, None);
+
             expr.assemble(c, Needs::Value)?.apply(c)?;
+            c.assert_return_type(Some(expr), span)?;
             c.locals_clean(total_var_count, span);
             c.asm.push(Inst::Return, span);
         } else {
+            c.assert_return_type(None, span)?;
             c.locals_pop(total_var_count, span);
             c.asm.push(Inst::ReturnUnit, span);
         }
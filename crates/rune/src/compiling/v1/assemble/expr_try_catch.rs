@@ -0,0 +1,54 @@
+use crate::compiling::v1::assemble::prelude::*;
+
+/// Compile a `try` block with a `catch` handler.
+impl Assemble for ast::ExprTryCatch {
+    fn assemble(&self, c: &mut Compiler<'_>, needs: Needs) -> CompileResult<Asm> {
+        let span = self.span();
+        log::trace!("ExprTryCatch => {:?}", c.source.source(span));
+
+        let catch_label = c.asm.new_label("try_catch");
+        let end_label = c.asm.new_label("try_end");
+
+        c.asm.push_try(catch_label, span);
+        self.body.assemble(c, needs)?.apply(c)?;
+        c.asm.push(Inst::PopTry, span);
+        c.asm.jump(end_label, span);
+
+        c.asm.label(catch_label)?;
+
+        // NB: the virtual machine unwinds the stack to what it was before the
+        // `try` block and pushes the caught error onto it.
+        let expected = c.scopes.push_child(span)?;
+        let offset = c.scopes.decl_anon(span)?;
+
+        let load = move |c: &mut Compiler, needs: Needs| {
+            if needs.value() {
+                c.asm.push(Inst::Copy { offset }, span);
+            }
+
+            Ok(())
+        };
+
+        let false_label = c.asm.new_label("catch_panic");
+
+        if c.compile_pat(&self.binding, false_label, &load)? {
+            let ok_label = c.asm.new_label("catch_ok");
+            c.asm.jump(ok_label, span);
+            c.asm.label(false_label)?;
+            c.asm.push(
+                Inst::Panic {
+                    reason: runestick::PanicReason::UnmatchedPattern,
+                },
+                span,
+            );
+
+            c.asm.label(ok_label)?;
+        }
+
+        self.catch_body.assemble(c, needs)?.apply(c)?;
+        c.clean_last_scope(span, expected, needs)?;
+
+        c.asm.label(end_label)?;
+        Ok(Asm::top(span))
+    }
+}
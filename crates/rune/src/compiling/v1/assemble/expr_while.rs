@@ -6,6 +6,16 @@ impl Assemble for ast::ExprWhile {
         let span = self.span();
         log::trace!("ExprWhile => {:?}", c.source.source(span));
 
+        let constant = c.const_condition(&self.condition);
+
+        if let (true, Some(false)) = (c.options.fold_constant_conditions, constant) {
+            if needs.value() {
+                c.asm.push(Inst::unit(), span);
+            }
+
+            return Ok(Asm::top(span));
+        }
+
         let continue_label = c.asm.new_label("while_continue");
         let then_label = c.asm.new_label("whiel_then");
         let end_label = c.asm.new_label("while_end");
@@ -20,6 +30,7 @@ impl Assemble for ast::ExprWhile {
             break_label,
             break_var_count: var_count,
             needs,
+            break_value: false,
             drop: None,
         });
 
@@ -33,7 +33,12 @@ impl AssembleFn for ast::ItemFn {
             c.compile_pat_offset(pat, offset)?;
         }
 
+        if let (Some(..), Some((_, ty))) = (&self.async_token, &self.output) {
+            c.resolve_return_type(ty)?;
+        }
+
         if self.body.statements.is_empty() {
+            c.assert_unit_return_type();
             let total_var_count = c.scopes.total_var_count(span)?;
             c.locals_pop(total_var_count, span);
             c.asm.push(Inst::ReturnUnit, span);
@@ -43,11 +48,19 @@ impl AssembleFn for ast::ItemFn {
         if !self.body.produces_nothing() {
             self.body.assemble(c, Needs::Value)?.apply(c)?;
 
+            let last = match self.body.statements.last() {
+                Some(ast::Stmt::Expr(expr, None)) => Some(expr),
+                _ => None,
+            };
+
+            c.assert_return_type(last, span)?;
+
             let total_var_count = c.scopes.total_var_count(span)?;
             c.locals_clean(total_var_count, span);
             c.asm.push(Inst::Return, span);
         } else {
             self.body.assemble(c, Needs::None)?.apply(c)?;
+            c.assert_unit_return_type();
 
             let total_var_count = c.scopes.total_var_count(span)?;
             c.locals_pop(total_var_count, span);
@@ -6,6 +6,21 @@ impl Assemble for ast::Local {
         let span = self.span();
         log::trace!("Local => {:?}", c.source.source(span));
 
+        c.check_return_arity(&self.pat, &self.expr)?;
+        c.check_redundant_parens(&self.expr, None);
+
+        if c.options.propagate_constants && self.mut_token.is_none() {
+            if let Some((name, value)) = c.const_local(self)? {
+                c.scopes.decl_const(&name, value, span)?;
+
+                if needs.value() {
+                    c.asm.push(Inst::unit(), span);
+                }
+
+                return Ok(Asm::top(span));
+            }
+        }
+
         let load = |c: &mut Compiler, needs: Needs| {
             // NB: assignments "move" the value being assigned.
             self.expr.assemble(c, needs)?.apply(c)?;
@@ -13,6 +28,7 @@ impl Assemble for ast::Local {
         };
 
         let false_label = c.asm.new_label("let_panic");
+        let first_offset = c.scopes.total_var_count(span)?;
 
         if c.compile_pat(&self.pat, false_label, &load)? {
             c.diagnostics
@@ -31,6 +47,10 @@ impl Assemble for ast::Local {
             c.asm.label(ok_label)?;
         }
 
+        if self.mut_token.is_none() {
+            c.scopes.mark_immutable(first_offset, span)?;
+        }
+
         // If a value is needed for a let expression, it is evaluated as a unit.
         if needs.value() {
             c.asm.push(Inst::unit(), span);
@@ -24,6 +24,7 @@ mod expr_range;
 mod expr_return;
 mod expr_select;
 mod expr_try;
+mod expr_try_catch;
 mod expr_tuple;
 mod expr_unary;
 mod expr_vec;
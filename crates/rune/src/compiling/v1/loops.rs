@@ -31,6 +31,8 @@ pub(crate) struct Loop {
     pub(crate) break_var_count: usize,
     /// If the loop needs a value.
     pub(crate) needs: Needs,
+    /// If the loop can be broken out of with a value.
+    pub(crate) break_value: bool,
     /// Locals to drop when breaking.
     pub(crate) drop: Option<usize>,
 }
@@ -73,6 +75,7 @@ impl Loops {
         let span = expected.span();
         let expected = expected.resolve(storage, source)?;
         let mut to_drop = Vec::new();
+        let mut existing = Vec::new();
 
         for l in self.loops.borrow().iter().rev() {
             to_drop.extend(l.drop);
@@ -84,17 +87,21 @@ impl Loops {
                 }
             };
 
+            let label_span = label.span();
             let label = label.resolve(storage, source)?;
 
             if expected == label {
                 return Ok((*l, to_drop));
             }
+
+            existing.push((label.as_ref().into(), label_span));
         }
 
         Err(CompileError::new(
             span,
             CompileErrorKind::MissingLoopLabel {
                 label: expected.as_ref().into(),
+                existing,
             },
         ))
     }
@@ -1,17 +1,18 @@
 use crate::ast;
 use crate::collections::HashMap;
 use crate::compiling::{Assembly, CompileVisitor};
-use crate::ir::{IrBudget, IrCompiler, IrInterpreter};
+use crate::ir::{IrBudget, IrCompiler, IrInterpreter, IrValue};
 use crate::query::{Named, Query, QueryConstFn, Used};
 use crate::shared::Consts;
 use crate::CompileResult;
 use crate::{
-    CompileError, CompileErrorKind, Diagnostics, Options, Resolve as _, Spanned, Storage,
+    CompileError, CompileErrorKind, Diagnostics, Id, Options, Resolve as _, Spanned, Storage,
     UnitBuilder,
 };
 use runestick::{
-    CompileItem, CompileMeta, CompileMetaKind, ConstValue, Context, Inst, InstValue, Item, Label,
-    Source, Span, TypeCheck,
+    CompileItem, CompileMeta, CompileMetaKind, ConstValue, Context, Hash, Inst, InstAddress,
+    InstOp, InstValue, Item, Label, Location, PanicReason, Source, Span, StaticSet, TypeCheck,
+    TypeInfo,
 };
 use std::rc::Rc;
 use std::sync::Arc;
@@ -44,6 +45,8 @@ impl Needs {
 pub(crate) struct Compiler<'a> {
     /// Compiler visitor.
     pub(crate) visitor: Rc<dyn CompileVisitor>,
+    /// The item being compiled.
+    pub(crate) item: Arc<CompileItem>,
     /// The source id of the source.
     pub(crate) source_id: usize,
     /// The source we are compiling for.
@@ -70,6 +73,24 @@ pub(crate) struct Compiler<'a> {
     pub(crate) options: &'a Options,
     /// Compilation warnings.
     pub(crate) diagnostics: &'a mut Diagnostics,
+    /// Path id used to resolve paths which haven't been indexed, like those
+    /// introduced by an [AstRewriter][crate::AstRewriter].
+    pub(crate) default_path: Option<Id>,
+    /// The declared return type of the async function being compiled, which
+    /// is asserted on the value the function resolves to.
+    pub(crate) return_type: Option<ReturnType>,
+    /// If we're compiling the head of an `if`, `while`, `for` or `match`
+    /// expression, in which struct literals have to be parenthesized.
+    pub(crate) condition_head: bool,
+}
+
+/// A declared return type which is asserted when a function returns.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReturnType {
+    /// The span of the declared type.
+    span: Span,
+    /// The hash of the declared type.
+    hash: Hash,
 }
 
 impl<'a> Compiler<'a> {
@@ -84,12 +105,16 @@ impl<'a> Compiler<'a> {
         if let Some(meta) = self.query.query_meta(spanned, &item, Default::default())? {
             log::trace!("found in query: {:?}", meta);
             self.visitor.visit_meta(self.source_id, &meta, spanned);
+            self.query
+                .insert_reference(&meta.item.item, Location::new(self.source_id, spanned));
             return Ok(Some(meta));
         }
 
         if let Some(meta) = self.context.lookup_meta(&item) {
             log::trace!("found in context: {:?}", meta);
             self.visitor.visit_meta(self.source_id, &meta, spanned);
+            self.query
+                .insert_reference(&meta.item.item, Location::new(self.source_id, spanned));
             return Ok(Some(meta));
         }
 
@@ -156,7 +181,9 @@ impl<'a> Compiler<'a> {
                         meta.to_string(),
                     );
                 }
-                CompileMetaKind::TupleStruct { tuple, .. } if tuple.args == 0 => {
+                CompileMetaKind::TupleStruct { tuple, .. }
+                    if tuple.args == 0 && self.options.auto_call_unit_tuples =>
+                {
                     self.asm.push_with_comment(
                         Inst::Call {
                             hash: tuple.hash,
@@ -176,7 +203,9 @@ impl<'a> Compiler<'a> {
                         meta.to_string(),
                     );
                 }
-                CompileMetaKind::TupleVariant { tuple, .. } if tuple.args == 0 => {
+                CompileMetaKind::TupleVariant { tuple, .. }
+                    if tuple.args == 0 && self.options.auto_call_unit_tuples =>
+                {
                     self.asm.push_with_comment(
                         Inst::Call {
                             hash: tuple.hash,
@@ -240,13 +269,212 @@ impl<'a> Compiler<'a> {
 
     /// Convert a path to an item.
     pub(crate) fn convert_path_to_named(&mut self, path: &ast::Path) -> CompileResult<Named> {
-        let named = self
-            .query
-            .convert_path(self.context, &self.storage, &*self.source, path)?;
+        let named = if let (None, Some(id)) = (path.id, self.default_path) {
+            let path = ast::Path {
+                id: Some(id),
+                ..path.clone()
+            };
+
+            self.query
+                .convert_path(self.context, self.storage, &self.source, &path)?
+        } else {
+            self.query
+                .convert_path(self.context, self.storage, &self.source, path)?
+        };
 
         Ok(named)
     }
 
+    /// Resolve the declared return type of an async function, so that the
+    /// value it resolves to can be asserted once it returns.
+    pub(crate) fn resolve_return_type(&mut self, ty: &ast::Type) -> CompileResult<()> {
+        let hash = match ty {
+            ast::Type::Tuple(tuple) if tuple.is_empty() => runestick::UNIT_TYPE.hash,
+            ast::Type::Tuple(..) => runestick::TUPLE_TYPE.hash,
+            ast::Type::Path(path) => {
+                let named = self.convert_path_to_named(path)?;
+                let meta = self.lookup_meta(path.span(), &named.item)?;
+
+                match meta.type_hash_of() {
+                    Some(hash) => hash,
+                    None => return Err(CompileError::expected_meta(path, meta, "a type")),
+                }
+            }
+        };
+
+        self.return_type = Some(ReturnType {
+            span: ty.span(),
+            hash,
+        });
+
+        Ok(())
+    }
+
+    /// Assert that the value on top of the stack matches the declared return
+    /// type, if there is one.
+    ///
+    /// If `expr` is a literal, a mismatch is reported as a compile error.
+    pub(crate) fn assert_return_type(
+        &mut self,
+        expr: Option<&ast::Expr>,
+        span: Span,
+    ) -> CompileResult<()> {
+        let return_type = match self.return_type {
+            Some(return_type) => return_type,
+            None => return Ok(()),
+        };
+
+        let actual = match expr {
+            Some(expr) => self.literal_type(expr)?,
+            None => Some(runestick::UNIT_TYPE),
+        };
+
+        if let Some(actual) = actual {
+            if actual.hash == return_type.hash {
+                return Ok(());
+            }
+
+            return Err(CompileError::new(
+                span,
+                CompileErrorKind::ReturnTypeMismatch {
+                    expected: self.source.source(return_type.span).unwrap_or("?").into(),
+                    actual: TypeInfo::StaticType(actual),
+                },
+            ));
+        }
+
+        let end = self.asm.new_label("return_type_ok");
+        self.asm.push(Inst::Dup, span);
+        self.asm.push(
+            Inst::Push {
+                value: InstValue::Type(return_type.hash),
+            },
+            span,
+        );
+        self.asm.push(
+            Inst::Op {
+                op: InstOp::Is,
+                a: InstAddress::Top,
+                b: InstAddress::Top,
+            },
+            span,
+        );
+        self.asm.jump_if(end, span);
+        self.asm.push(
+            Inst::Panic {
+                reason: PanicReason::ReturnTypeMismatch,
+            },
+            return_type.span,
+        );
+        self.asm.label(end)?;
+        Ok(())
+    }
+
+    /// Assert that the declared return type, if any, is unit where the
+    /// function implicitly returns unit.
+    pub(crate) fn assert_unit_return_type(&mut self) {
+        if let Some(return_type) = self.return_type {
+            if return_type.hash != runestick::UNIT_TYPE.hash {
+                self.asm.push(
+                    Inst::Panic {
+                        reason: PanicReason::ReturnTypeMismatch,
+                    },
+                    return_type.span,
+                );
+            }
+        }
+    }
+
+    /// Get the type of the expression if it's a literal.
+    pub(crate) fn literal_type(
+        &self,
+        expr: &ast::Expr,
+    ) -> CompileResult<Option<&'static runestick::StaticType>> {
+        let lit = match expr {
+            ast::Expr::Lit(expr_lit) => &expr_lit.lit,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(match lit {
+            ast::Lit::Bool(..) => runestick::BOOL_TYPE,
+            ast::Lit::Byte(..) => runestick::BYTE_TYPE,
+            ast::Lit::Str(..) => runestick::STRING_TYPE,
+            ast::Lit::ByteStr(..) => runestick::BYTES_TYPE,
+            ast::Lit::Char(..) => runestick::CHAR_TYPE,
+            ast::Lit::Number(lit_number) => {
+                match lit_number.resolve(self.storage, &self.source)? {
+                    ast::Number::Integer(..) => runestick::INTEGER_TYPE,
+                    ast::Number::Float(..) => runestick::FLOAT_TYPE,
+                }
+            }
+        }))
+    }
+
+    /// Check that a tuple pattern which destructures a call to a function
+    /// with a declared tuple return type, like `-> (int, int)`, has a matching
+    /// number of elements.
+    pub(crate) fn check_return_arity(
+        &mut self,
+        pat: &ast::Pat,
+        expr: &ast::Expr,
+    ) -> CompileResult<()> {
+        let pat_tuple = match pat {
+            ast::Pat::PatTuple(pat_tuple) if pat_tuple.path.is_none() => pat_tuple,
+            _ => return Ok(()),
+        };
+
+        let path = match expr {
+            ast::Expr::Call(expr_call) => match &expr_call.expr {
+                ast::Expr::Path(path) => path,
+                _ => return Ok(()),
+            },
+            _ => return Ok(()),
+        };
+
+        let named = self.convert_path_to_named(path)?;
+
+        if let Some(name) = named.as_local() {
+            if self
+                .scopes
+                .try_get_var(name, self.source_id, path.span())?
+                .is_some()
+            {
+                return Ok(());
+            }
+        }
+
+        let meta = match self
+            .query
+            .query_meta(path.span(), &named.item, Default::default())?
+        {
+            Some(meta) => meta,
+            None => return Ok(()),
+        };
+
+        let expected = match &meta.kind {
+            CompileMetaKind::Function {
+                return_arity: Some(return_arity),
+                ..
+            } => *return_arity,
+            _ => return Ok(()),
+        };
+
+        let (is_open, actual) = pat_items_count(pat_tuple.items.as_slice())?;
+
+        if actual == expected || is_open && actual <= expected {
+            return Ok(());
+        }
+
+        Err(CompileError::new(
+            pat_tuple,
+            CompileErrorKind::ReturnArityMismatch {
+                meta,
+                expected,
+                actual,
+            },
+        ))
+    }
+
     pub(crate) fn compile_condition(
         &mut self,
         condition: &ast::Condition,
@@ -259,7 +487,8 @@ impl<'a> Compiler<'a> {
             ast::Condition::Expr(expr) => {
                 let span = expr.span();
 
-                expr.assemble(self, Needs::Value)?.apply(self)?;
+                self.assemble_condition_head(expr, Needs::Value)?
+                    .apply(self)?;
                 self.asm.jump_if(then_label, span);
 
                 Ok(self.scopes.child(span)?)
@@ -273,7 +502,7 @@ impl<'a> Compiler<'a> {
                 let expected = self.scopes.push(scope);
 
                 let load = |c: &mut Self, needs: Needs| {
-                    expr_let.expr.assemble(c, needs)?.apply(c)?;
+                    c.assemble_condition_head(&expr_let.expr, needs)?.apply(c)?;
                     Ok(())
                 };
 
@@ -290,6 +519,199 @@ impl<'a> Compiler<'a> {
         }
     }
 
+    /// Evaluate the given condition through the IR interpreter, and warn if it
+    /// always evaluates to the same value.
+    ///
+    /// Only conditions built out of literals are considered, since conditions
+    /// referencing constants are usually intentional.
+    pub(crate) fn const_condition(&mut self, condition: &ast::Condition) -> Option<bool> {
+        let expr = match condition {
+            ast::Condition::Expr(expr) => expr,
+            _ => return None,
+        };
+
+        let value = match self.eval_literal_expr(expr)? {
+            IrValue::Bool(value) => value,
+            _ => return None,
+        };
+
+        self.diagnostics
+            .constant_condition(self.source_id, expr.span(), value, self.context());
+
+        Some(value)
+    }
+
+    /// Evaluate the given expression through the IR interpreter if it's a
+    /// boolean built entirely out of literals, like `true` or `1 < 2`.
+    pub(crate) fn const_bool(&mut self, expr: &ast::Expr) -> Option<bool> {
+        match self.eval_literal_expr(expr)? {
+            IrValue::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Evaluate the range of a `for` loop if its bounds are integers built
+    /// entirely out of literals, like `0..4`.
+    pub(crate) fn const_range(&mut self, expr: &ast::Expr) -> Option<std::ops::Range<i64>> {
+        use num::ToPrimitive as _;
+
+        let expr_range = match expr {
+            ast::Expr::Range(expr_range) => expr_range,
+            _ => return None,
+        };
+
+        let from = match self.eval_literal_expr(expr_range.from.as_ref()?)? {
+            IrValue::Integer(from) => from.to_i64()?,
+            _ => return None,
+        };
+
+        let to = match self.eval_literal_expr(expr_range.to.as_ref()?)? {
+            IrValue::Integer(to) => to.to_i64()?,
+            _ => return None,
+        };
+
+        let to = match expr_range.limits {
+            ast::ExprRangeLimits::HalfOpen(..) => to,
+            ast::ExprRangeLimits::Closed(..) => to.checked_add(1)?,
+        };
+
+        Some(from..to)
+    }
+
+    /// Assemble the head expression of an `if`, `while`, `for` or `match`.
+    pub(crate) fn assemble_condition_head(
+        &mut self,
+        expr: &ast::Expr,
+        needs: Needs,
+    ) -> CompileResult<Asm> {
+        let condition_head = std::mem::replace(&mut self.condition_head, true);
+        let result = expr.assemble(self, needs);
+        self.condition_head = condition_head;
+        result
+    }
+
+    /// Warn if `expr` is wrapped in parentheses which can be removed without
+    /// changing how it's parsed.
+    ///
+    /// `precedence` is the precedence of the binary operator `expr` is an
+    /// operand of, or `None` if `expr` is in a position where any expression
+    /// is accepted as-is, like the initializer of a `let`.
+    pub(crate) fn check_redundant_parens(&mut self, expr: &ast::Expr, precedence: Option<usize>) {
+        if !self.options.warn_redundant_parens {
+            return;
+        }
+
+        let expr_group = match expr {
+            ast::Expr::Group(expr_group) => expr_group,
+            _ => return,
+        };
+
+        // NB: parentheses around a struct literal can't be removed in the
+        // head of an `if`, `while`, `for` or `match` since the opening brace
+        // would be parsed as the start of its body.
+        if self.condition_head && has_bare_struct_literal(&expr_group.expr) {
+            return;
+        }
+
+        let redundant = match (&expr_group.expr, precedence) {
+            (_, None) => true,
+            (ast::Expr::Binary(inner), Some(outer)) => inner.op.precedence() > outer,
+            (inner, Some(..)) => is_atomic_expr(inner),
+        };
+
+        if redundant {
+            self.diagnostics
+                .redundant_parens(self.source_id, expr_group.span(), self.context());
+        }
+    }
+
+    /// Evaluate a `let` binding of a single variable to an expression built
+    /// entirely out of literals, returning the name of the variable and the
+    /// constant it can be replaced with.
+    ///
+    /// Only immutable values are propagated, since mutating a propagated
+    /// string or vector would only affect one use of it. Variables which are
+    /// reassigned or captured by a closure are never propagated.
+    pub(crate) fn const_local(
+        &mut self,
+        local: &ast::Local,
+    ) -> CompileResult<Option<(Box<str>, ConstValue)>> {
+        use num::ToPrimitive as _;
+
+        let path = match &local.pat {
+            ast::Pat::PatPath(pat_path) => &pat_path.path,
+            _ => return Ok(None),
+        };
+
+        let ident = match path.try_as_ident() {
+            Some(ident) => ident,
+            None => return Ok(None),
+        };
+
+        let span = path.span();
+
+        if self.query.is_non_constant(self.source_id, span) {
+            return Ok(None);
+        }
+
+        // NB: a path which refers to an item, like a unit struct, is a pattern
+        // rather than a binding.
+        let named = self.convert_path_to_named(path)?;
+
+        if self.try_lookup_meta(span, &named.item)?.is_some() {
+            return Ok(None);
+        }
+
+        let value = match self.eval_literal_expr(&local.expr) {
+            Some(IrValue::Unit) => ConstValue::Unit,
+            Some(IrValue::Byte(b)) => ConstValue::Byte(b),
+            Some(IrValue::Char(c)) => ConstValue::Char(c),
+            Some(IrValue::Bool(b)) => ConstValue::Bool(b),
+            Some(IrValue::Float(n)) => ConstValue::Float(n),
+            Some(IrValue::Integer(n)) => match n.to_i64() {
+                Some(n) => ConstValue::Integer(n),
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+
+        let name = ident.resolve(self.storage, &self.source)?;
+        Ok(Some((name.as_ref().into(), value)))
+    }
+
+    /// Evaluate an expression built entirely out of literals using the IR
+    /// interpreter.
+    ///
+    /// Expressions which refer to anything else are not evaluated, since they
+    /// might refer to variables which are only known at runtime.
+    pub(crate) fn eval_literal_expr(&mut self, expr: &ast::Expr) -> Option<IrValue> {
+        if !is_literal_expr(expr) {
+            return None;
+        }
+
+        let mut ir_query = self.query.as_ir_query();
+
+        let mut compiler = IrCompiler {
+            storage: self.storage.clone(),
+            source: self.source.clone(),
+            query: &mut *ir_query,
+        };
+
+        let ir = compiler.compile(expr).ok()?;
+
+        let mut interpreter = IrInterpreter {
+            budget: IrBudget::new(1_000_000),
+            call_depth: 0,
+            scopes: Default::default(),
+            module: self.item.module.clone(),
+            item: self.item.item.clone(),
+            consts: self.consts.clone(),
+            query: &mut *ir_query,
+        };
+
+        interpreter.eval_value(&ir, Used::Used).ok()
+    }
+
     /// Encode a vector pattern match.
     pub(crate) fn compile_pat_vec(
         &mut self,
@@ -572,6 +994,15 @@ impl<'a> Compiler<'a> {
                     }
                 }
 
+                if !has_rest && bindings.len() < fields.len() {
+                    self.diagnostics.pattern_missing_fields(
+                        self.source_id,
+                        pat_object.span(),
+                        fields.len() - bindings.len(),
+                        self.context(),
+                    );
+                }
+
                 type_check
             }
             ast::ObjectIdent::Anonymous(..) => TypeCheck::Object,
@@ -774,6 +1205,18 @@ impl<'a> Compiler<'a> {
                 self.compile_pat_object(object, false_label, &load)?;
                 Ok(true)
             }
+            ast::Pat::PatIn(pat_in) => {
+                self.compile_pat_in(pat_in, false_label, &load)?;
+                Ok(true)
+            }
+            ast::Pat::PatOr(pat_or) => {
+                self.compile_pat_or(pat_or, false_label, &load)?;
+                Ok(true)
+            }
+            ast::Pat::PatRange(pat_range) => {
+                self.compile_pat_range(pat_range, false_label, &load)?;
+                Ok(true)
+            }
             pat => Err(CompileError::new(
                 pat,
                 CompileErrorKind::UnsupportedPatternExpr,
@@ -861,6 +1304,284 @@ impl<'a> Compiler<'a> {
         Ok(true)
     }
 
+    /// Compile a pattern matching against a set of literals, like
+    /// `in [1, 2, 3]`.
+    ///
+    /// The set is built at compile time and tested with a single instruction.
+    pub(crate) fn compile_pat_in(
+        &mut self,
+        pat_in: &ast::PatIn,
+        false_label: Label,
+        load: &dyn Fn(&mut Self, Needs) -> CompileResult<()>,
+    ) -> CompileResult<()> {
+        let span = pat_in.span();
+        log::trace!("PatIn => {:?}", self.source.source(span));
+
+        let mut set = StaticSet::new();
+
+        for (pat, _) in &pat_in.items {
+            match pat {
+                ast::Pat::PatLit(pat_lit) => self.insert_pat_lit_into_set(&mut set, pat_lit)?,
+                pat => {
+                    return Err(CompileError::new(
+                        pat,
+                        CompileErrorKind::UnsupportedPatternExpr,
+                    ))
+                }
+            }
+        }
+
+        let slot = self.unit.new_static_set(set);
+        load(self, Needs::Value)?;
+        self.asm.push(Inst::InStaticSet { slot }, span);
+        self.asm
+            .pop_and_jump_if_not(self.scopes.local_var_count(span)?, false_label, span);
+        Ok(())
+    }
+
+    /// Compile a pattern matching a range of literals, like `1..=5`.
+    ///
+    /// The value is tested against each bound of the range in turn.
+    pub(crate) fn compile_pat_range(
+        &mut self,
+        pat_range: &ast::PatRange,
+        false_label: Label,
+        load: &dyn Fn(&mut Self, Needs) -> CompileResult<()>,
+    ) -> CompileResult<()> {
+        let span = pat_range.span();
+        log::trace!("PatRange => {:?}", self.source.source(span));
+
+        let start = self.pat_range_bound(&pat_range.start)?;
+        let end = self.pat_range_bound(&pat_range.end)?;
+
+        if std::mem::discriminant(&start) != std::mem::discriminant(&end) {
+            return Err(CompileError::msg(
+                span,
+                "the bounds of a range pattern must be of the same type",
+            ));
+        }
+
+        load(self, Needs::Value)?;
+        self.asm
+            .push(Inst::GteValue { value: start }, pat_range.start.span());
+        self.asm
+            .pop_and_jump_if_not(self.scopes.local_var_count(span)?, false_label, span);
+
+        let inst = match &pat_range.limits {
+            ast::ExprRangeLimits::HalfOpen(..) => Inst::LtValue { value: end },
+            ast::ExprRangeLimits::Closed(..) => Inst::LteValue { value: end },
+        };
+
+        load(self, Needs::Value)?;
+        self.asm.push(inst, pat_range.end.span());
+        self.asm
+            .pop_and_jump_if_not(self.scopes.local_var_count(span)?, false_label, span);
+        Ok(())
+    }
+
+    /// Get the value of a literal bound of a range pattern.
+    fn pat_range_bound(&mut self, expr: &ast::Expr) -> CompileResult<InstValue> {
+        let (lit, neg) = match expr {
+            ast::Expr::Unary(expr_unary) => match &expr_unary.expr {
+                ast::Expr::Lit(expr_lit) => (&expr_lit.lit, true),
+                _ => {
+                    return Err(CompileError::new(
+                        expr,
+                        CompileErrorKind::UnsupportedPatternExpr,
+                    ))
+                }
+            },
+            ast::Expr::Lit(expr_lit) => (&expr_lit.lit, false),
+            _ => {
+                return Err(CompileError::new(
+                    expr,
+                    CompileErrorKind::UnsupportedPatternExpr,
+                ))
+            }
+        };
+
+        let span = expr.span();
+
+        Ok(match lit {
+            ast::Lit::Number(lit_number) => {
+                let number = lit_number.resolve(self.storage, &self.source)?;
+
+                if let ast::Number::Float(..) = number {
+                    return Err(CompileError::new(
+                        span,
+                        CompileErrorKind::MatchFloatInPattern,
+                    ));
+                }
+
+                InstValue::Integer(number.as_i64(span, neg)?)
+            }
+            ast::Lit::Byte(lit_byte) if !neg => {
+                InstValue::Byte(lit_byte.resolve(self.storage, &self.source)?)
+            }
+            ast::Lit::Char(lit_char) if !neg => {
+                InstValue::Char(lit_char.resolve(self.storage, &self.source)?)
+            }
+            _ => {
+                return Err(CompileError::new(
+                    span,
+                    CompileErrorKind::UnsupportedPatternExpr,
+                ))
+            }
+        })
+    }
+
+    /// Compile a pattern matching any one of a set of alternatives, like
+    /// `1 | 2 | 3`.
+    ///
+    /// Each alternative is compiled in its own scope, and tested in order
+    /// against the same loaded value. The variables bound by the alternative
+    /// which matched are compacted into the same stack slots, in order of
+    /// their names, so that they're declared identically regardless of which
+    /// alternative matched.
+    pub(crate) fn compile_pat_or(
+        &mut self,
+        pat_or: &ast::PatOr,
+        false_label: Label,
+        load: &dyn Fn(&mut Self, Needs) -> CompileResult<()>,
+    ) -> CompileResult<()> {
+        let span = pat_or.span();
+        log::trace!("PatOr => {:?}", self.source.source(span));
+
+        let ok_label = self.asm.new_label("pat_or_ok");
+        let base = self.scopes.total_var_count(span)?;
+        let mut expected = None::<(Span, Vec<(String, Span)>)>;
+
+        for pat in pat_or.alternatives() {
+            let span = pat.span();
+            let alt_false = self.asm.new_label("pat_or_false");
+
+            let guard = self.scopes.push_child(span)?;
+            self.compile_pat(pat, alt_false, load)?;
+            let scope = self.scopes.pop(guard, span)?;
+
+            let mut bindings = scope.iter_locals().collect::<Vec<_>>();
+            bindings.sort_by(|a, b| a.0.cmp(b.0));
+
+            let names = bindings
+                .iter()
+                .map(|(name, _, span)| ((*name).to_owned(), *span))
+                .collect::<Vec<_>>();
+
+            match &expected {
+                Some((first_span, expected)) => {
+                    check_or_pattern_bindings(&names, expected, *first_span)?;
+                    check_or_pattern_bindings(expected, &names, span)?;
+                }
+                None => expected = Some((span, names)),
+            }
+
+            let offsets = bindings
+                .iter()
+                .map(|(_, offset, _)| *offset)
+                .collect::<Vec<_>>();
+
+            if scope.local_var_count == 3 && offsets == [base + 1, base + 2] {
+                // NB: the two bindings are already in order on top of the
+                // loaded value, so the last binding can be swapped below the
+                // first and moved into the slot of the loaded value.
+                self.asm.swap(span);
+                self.asm.push(Inst::Replace { offset: base }, span);
+            } else {
+                // Copy all bindings to the top of the stack before moving them
+                // into place, since the slots they're moved into might be
+                // occupied by bindings which haven't been copied yet.
+                for (_, offset, span) in &bindings {
+                    self.asm.push(Inst::Copy { offset: *offset }, *span);
+                }
+
+                for (n, (_, _, span)) in bindings.iter().enumerate().rev() {
+                    self.asm.push(Inst::Replace { offset: base + n }, *span);
+                }
+
+                let count = scope.local_var_count - bindings.len();
+
+                if count > 0 {
+                    self.asm.push(Inst::PopN { count }, span);
+                }
+            }
+
+            self.asm.jump(ok_label, span);
+            self.asm.label(alt_false)?;
+        }
+
+        // NB: none of the alternatives matched, so clean up the current scope
+        // the same way a failed pattern would.
+        let count = self.scopes.local_var_count(span)?;
+
+        if count > 0 {
+            self.asm.push(Inst::PopN { count }, span);
+        }
+
+        self.asm.jump(false_label, span);
+        self.asm.label(ok_label)?;
+
+        if let Some((_, names)) = expected {
+            for (name, _) in names {
+                self.scopes.decl_var(&name, span)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Insert the literal in the given pattern into a static set.
+    fn insert_pat_lit_into_set(
+        &mut self,
+        set: &mut StaticSet,
+        pat_lit: &ast::PatLit,
+    ) -> CompileResult<()> {
+        match &pat_lit.expr {
+            ast::Expr::Unary(expr_unary) => {
+                if let ast::Expr::Lit(expr_lit) = &expr_unary.expr {
+                    if let ast::Lit::Number(lit_number) = &expr_lit.lit {
+                        let integer = lit_number
+                            .resolve(self.storage, &self.source)?
+                            .as_i64(pat_lit.span(), true)?;
+                        set.insert_integer(integer);
+                        return Ok(());
+                    }
+                }
+            }
+            ast::Expr::Lit(expr_lit) => match &expr_lit.lit {
+                ast::Lit::Byte(lit_byte) => {
+                    set.insert_byte(lit_byte.resolve(self.storage, &self.source)?);
+                    return Ok(());
+                }
+                ast::Lit::Char(lit_char) => {
+                    set.insert_char(lit_char.resolve(self.storage, &self.source)?);
+                    return Ok(());
+                }
+                ast::Lit::Str(lit_str) => {
+                    set.insert_string(&lit_str.resolve(self.storage, &self.source)?);
+                    return Ok(());
+                }
+                ast::Lit::Number(lit_number) => {
+                    let integer = lit_number
+                        .resolve(self.storage, &self.source)?
+                        .as_i64(pat_lit.span(), false)?;
+                    set.insert_integer(integer);
+                    return Ok(());
+                }
+                ast::Lit::Bool(lit_bool) => {
+                    set.insert_bool(lit_bool.value);
+                    return Ok(());
+                }
+                ast::Lit::ByteStr(_) => (),
+            },
+            _ => (),
+        }
+
+        Err(CompileError::new(
+            pat_lit,
+            CompileErrorKind::UnsupportedPatternExpr,
+        ))
+    }
+
     /// Clean the last scope.
     pub(crate) fn clean_last_scope(
         &mut self,
@@ -924,6 +1645,7 @@ impl<'a> Compiler<'a> {
 
         let mut interpreter = IrInterpreter {
             budget: IrBudget::new(1_000_000),
+            call_depth: 0,
             scopes: Default::default(),
             module: from.module.clone(),
             item: from.item.clone(),
@@ -943,6 +1665,40 @@ impl<'a> Compiler<'a> {
     }
 }
 
+/// Test if the given expression is built entirely out of literals.
+fn is_literal_expr(expr: &ast::Expr) -> bool {
+    match expr {
+        ast::Expr::Lit(..) => true,
+        ast::Expr::Group(expr_group) => is_literal_expr(&expr_group.expr),
+        ast::Expr::Binary(expr_binary) => {
+            is_literal_expr(&expr_binary.lhs) && is_literal_expr(&expr_binary.rhs)
+        }
+        _ => false,
+    }
+}
+
+/// Check that every variable in `bindings` is also bound in `other`, which
+/// are the bindings of the alternative at `other_span`.
+fn check_or_pattern_bindings(
+    bindings: &[(String, Span)],
+    other: &[(String, Span)],
+    other_span: Span,
+) -> CompileResult<()> {
+    for (name, span) in bindings {
+        if !other.iter().any(|(other, _)| other == name) {
+            return Err(CompileError::new(
+                *span,
+                CompileErrorKind::InconsistentOrPatternBindings {
+                    name: name.clone(),
+                    alternative_span: other_span,
+                },
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 /// Test if the given pattern is open or not.
 fn pat_items_count<'a, I: 'a, U: 'a>(items: I) -> Result<(bool, usize), CompileError>
 where
@@ -975,3 +1731,51 @@ where
 
     Ok((is_open, count))
 }
+
+/// Test if the expression binds tighter than any operator, so that
+/// parentheses around it never matter.
+/// Test if the expression contains a struct literal which isn't enclosed in
+/// any delimiters, like `Foo { a: 1 }.a == 1`.
+fn has_bare_struct_literal(expr: &ast::Expr) -> bool {
+    match expr {
+        ast::Expr::Object(expr_object) => {
+            matches!(expr_object.ident, ast::ObjectIdent::Named(..))
+        }
+        ast::Expr::Binary(expr_binary) => {
+            has_bare_struct_literal(&expr_binary.lhs) || has_bare_struct_literal(&expr_binary.rhs)
+        }
+        ast::Expr::Assign(expr_assign) => {
+            has_bare_struct_literal(&expr_assign.lhs) || has_bare_struct_literal(&expr_assign.rhs)
+        }
+        ast::Expr::Range(expr_range) => {
+            matches!(&expr_range.from, Some(from) if has_bare_struct_literal(from))
+                || matches!(&expr_range.to, Some(to) if has_bare_struct_literal(to))
+        }
+        ast::Expr::Unary(expr_unary) => has_bare_struct_literal(&expr_unary.expr),
+        ast::Expr::FieldAccess(expr_field_access) => {
+            has_bare_struct_literal(&expr_field_access.expr)
+        }
+        ast::Expr::Index(expr_index) => has_bare_struct_literal(&expr_index.target),
+        ast::Expr::Call(expr_call) => has_bare_struct_literal(&expr_call.expr),
+        ast::Expr::Try(expr_try) => has_bare_struct_literal(&expr_try.expr),
+        ast::Expr::Await(expr_await) => has_bare_struct_literal(&expr_await.expr),
+        _ => false,
+    }
+}
+
+fn is_atomic_expr(expr: &ast::Expr) -> bool {
+    matches!(
+        expr,
+        ast::Expr::Path(..)
+            | ast::Expr::Lit(..)
+            | ast::Expr::Call(..)
+            | ast::Expr::FieldAccess(..)
+            | ast::Expr::Index(..)
+            | ast::Expr::Tuple(..)
+            | ast::Expr::Vec(..)
+            | ast::Expr::Object(..)
+            | ast::Expr::MacroCall(..)
+            | ast::Expr::Try(..)
+            | ast::Expr::Await(..)
+    )
+}
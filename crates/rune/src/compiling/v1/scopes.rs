@@ -1,7 +1,7 @@
 use crate::collections::HashMap;
 use crate::compiling::Assembly;
 use crate::{CompileError, CompileErrorKind, CompileResult, CompileVisitor};
-use runestick::{Inst, SourceId, Span};
+use runestick::{ConstValue, Inst, SourceId, Span};
 use std::rc::Rc;
 
 /// A locally declared variable, its calculated stack offset and where it was
@@ -14,9 +14,25 @@ pub struct Var {
     span: Span,
     /// Variable has been taken at the given position.
     moved_at: Option<Span>,
+    /// If the variable can be reassigned.
+    mutable: bool,
 }
 
 impl Var {
+    /// Check that the variable can be reassigned.
+    pub(crate) fn check_mutable(&self, span: Span) -> CompileResult<()> {
+        if !self.mutable {
+            return Err(CompileError::new(
+                span,
+                CompileErrorKind::AssignToImmutable {
+                    decl_span: self.span,
+                },
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Copy the declared variable.
     pub(crate) fn copy<C>(&self, asm: &mut Assembly, span: Span, comment: C)
     where
@@ -59,6 +75,9 @@ pub(crate) struct AnonVar {
 pub(crate) struct Scope {
     /// Named variables.
     locals: HashMap<String, Var>,
+    /// Named variables which have been propagated as constants, and therefore
+    /// don't occupy a stack slot.
+    consts: HashMap<String, ConstValue>,
     /// Anonymous variables.
     anon: Vec<AnonVar>,
     /// The number of variables.
@@ -72,6 +91,7 @@ impl Scope {
     fn new() -> Scope {
         Self {
             locals: HashMap::new(),
+            consts: HashMap::new(),
             anon: Vec::new(),
             total_var_count: 0,
             local_var_count: 0,
@@ -82,6 +102,7 @@ impl Scope {
     fn child(&self) -> Self {
         Self {
             locals: HashMap::new(),
+            consts: HashMap::new(),
             anon: Vec::new(),
             total_var_count: self.total_var_count,
             local_var_count: 0,
@@ -96,10 +117,12 @@ impl Scope {
             offset,
             span,
             moved_at: None,
+            mutable: true,
         };
 
         self.total_var_count += 1;
         self.local_var_count += 1;
+        self.consts.remove(name);
 
         if let Some(old) = self.locals.insert(name.to_owned(), local) {
             return Err(CompileError::new(
@@ -120,12 +143,14 @@ impl Scope {
 
         log::trace!("decl {} => {}", name, offset);
 
+        self.consts.remove(name);
         self.locals.insert(
             name.to_owned(),
             Var {
                 offset,
                 span,
                 moved_at: None,
+                mutable: true,
             },
         );
 
@@ -134,6 +159,23 @@ impl Scope {
         offset
     }
 
+    /// Declare a variable whose value is a propagated constant.
+    fn decl_const(&mut self, name: &str, value: ConstValue) {
+        log::trace!("decl const {} => {:?}", name, value);
+
+        self.locals.remove(name);
+        self.consts.insert(name.to_owned(), value);
+    }
+
+    /// Mark all variables declared at or after the given offset as immutable.
+    fn mark_immutable(&mut self, offset: usize) {
+        for var in self.locals.values_mut() {
+            if var.offset >= offset {
+                var.mutable = false;
+            }
+        }
+    }
+
     /// Declare an anonymous variable.
     ///
     /// This is used if cleanup is required in the middle of an expression.
@@ -166,6 +208,14 @@ impl Scope {
         Ok(())
     }
 
+    /// Iterate over the named variables declared in this scope, with their
+    /// offsets and the spans they were declared at.
+    pub(crate) fn iter_locals(&self) -> impl Iterator<Item = (&str, usize, Span)> + '_ {
+        self.locals
+            .iter()
+            .map(|(name, var)| (name.as_str(), var.offset, var.span))
+    }
+
     /// Access the variable with the given name.
     fn get(&self, name: &str, span: Span) -> CompileResult<Option<&Var>> {
         if let Some(var) = self.locals.get(name) {
@@ -232,6 +282,11 @@ impl Scopes {
         log::trace!("get var: {}", name);
 
         for scope in self.scopes.iter().rev() {
+            // NB: shadowed by a propagated constant.
+            if scope.consts.contains_key(name) {
+                return Ok(None);
+            }
+
             if let Some(var) = scope.get(name, span)? {
                 log::trace!("found var: {} => {:?}", name, var);
                 self.visitor.visit_variable_use(source_id, var.span, span);
@@ -253,6 +308,11 @@ impl Scopes {
         log::trace!("get var: {}", name);
 
         for scope in self.scopes.iter_mut().rev() {
+            // NB: shadowed by a propagated constant.
+            if scope.consts.contains_key(name) {
+                return Ok(None);
+            }
+
             if let Some(var) = scope.take(name, span)? {
                 log::trace!("found var: {} => {:?}", name, var);
                 self.visitor.visit_variable_use(source_id, var.span, span);
@@ -263,6 +323,23 @@ impl Scopes {
         Ok(None)
     }
 
+    /// Try to get the value of a variable which has been propagated as a
+    /// constant. Returns `None` if there's no such constant, or if it's
+    /// shadowed by a regular variable.
+    pub(crate) fn try_get_const(&self, name: &str) -> Option<&ConstValue> {
+        for scope in self.scopes.iter().rev() {
+            if let Some(value) = scope.consts.get(name) {
+                return Some(value);
+            }
+
+            if scope.locals.contains_key(name) {
+                return None;
+            }
+        }
+
+        None
+    }
+
     /// Get the local with the given name.
     pub(crate) fn get_var(
         &self,
@@ -309,6 +386,24 @@ impl Scopes {
         Ok(self.last_mut(span)?.decl_var(name, span))
     }
 
+    /// Declare a variable whose value is a propagated constant.
+    pub(crate) fn decl_const(
+        &mut self,
+        name: &str,
+        value: ConstValue,
+        span: Span,
+    ) -> CompileResult<()> {
+        self.last_mut(span)?.decl_const(name, value);
+        Ok(())
+    }
+
+    /// Mark all variables in the last scope declared at or after the given
+    /// offset as immutable.
+    pub(crate) fn mark_immutable(&mut self, offset: usize, span: Span) -> CompileResult<()> {
+        self.last_mut(span)?.mark_immutable(offset);
+        Ok(())
+    }
+
     /// Declare an anonymous variable.
     pub(crate) fn decl_anon(&mut self, span: Span) -> CompileResult<usize> {
         Ok(self.last_mut(span)?.decl_anon(span))
@@ -63,6 +63,8 @@ pub struct Diagnostics {
     last_error: Option<usize>,
     /// First warning in chain.
     last_warning: Option<usize>,
+    /// Lints which are allowed within a given span of a source.
+    allowed: Vec<(SourceId, Span, &'static str)>,
 }
 
 impl Diagnostics {
@@ -72,6 +74,7 @@ impl Diagnostics {
             mode,
             last_error: None,
             last_warning: None,
+            allowed: Vec::new(),
         }
     }
 
@@ -211,6 +214,88 @@ impl Diagnostics {
         self.warning(source_id, WarningKind::UnecessarySemiColon { span });
     }
 
+    /// Add a warning about a condition that always evaluates to the same
+    /// value.
+    ///
+    /// Like `if true { .. }` or `while false { .. }`.
+    pub fn constant_condition(
+        &mut self,
+        source_id: usize,
+        span: Span,
+        value: bool,
+        context: Option<Span>,
+    ) {
+        self.warning(
+            source_id,
+            WarningKind::ConstantCondition {
+                span,
+                value,
+                context,
+            },
+        );
+    }
+
+    /// Add a warning about a binary operation which mixes an integer literal
+    /// and a float literal.
+    ///
+    /// Like `1 + 2.0`.
+    pub fn mixed_number_literals(
+        &mut self,
+        source_id: usize,
+        span: Span,
+        integer: Span,
+        context: Option<Span>,
+    ) {
+        self.warning(
+            source_id,
+            WarningKind::MixedNumberLiterals {
+                span,
+                integer,
+                context,
+            },
+        );
+    }
+
+    /// Add a warning about parentheses which can be removed.
+    pub fn redundant_parens(&mut self, source_id: SourceId, span: Span, context: Option<Span>) {
+        self.warning(source_id, WarningKind::RedundantParens { span, context });
+    }
+
+    /// Add a warning about using the value of a call to a function which never
+    /// returns.
+    pub fn diverging_call_value(&mut self, source_id: SourceId, span: Span, context: Option<Span>) {
+        self.warning(source_id, WarningKind::DivergingCallValue { span, context });
+    }
+
+    /// Add a warning about a closed object pattern which doesn't list all
+    /// fields of the struct it matches.
+    pub fn pattern_missing_fields(
+        &mut self,
+        source_id: SourceId,
+        span: Span,
+        missing: usize,
+        context: Option<Span>,
+    ) {
+        self.warning(
+            source_id,
+            WarningKind::PatternMissingFields {
+                span,
+                missing,
+                context,
+            },
+        );
+    }
+
+    /// Add a warning about an unknown lint in an `#[allow(..)]` attribute.
+    pub fn unknown_lint(&mut self, source_id: SourceId, span: Span) {
+        self.warning(source_id, WarningKind::UnknownLint { span });
+    }
+
+    /// Suppress warnings of the given lint which originate within `span`.
+    pub(crate) fn allow(&mut self, source_id: SourceId, span: Span, lint: &'static str) {
+        self.allowed.push((source_id, span, lint));
+    }
+
     /// Push a warning to the collection of diagnostics.
     pub fn warning<T>(&mut self, source_id: SourceId, kind: T)
     where
@@ -220,14 +305,26 @@ impl Diagnostics {
             return;
         }
 
-        let current = Some(self.diagnostics.len());
-
-        self.diagnostics.push(Diagnostic::Warning(Warning {
+        let warning = Warning {
             last: self.last_warning,
             source_id,
             kind: kind.into(),
-        }));
+        };
+
+        let span = warning.span();
+        let lint = warning.kind.lint();
 
+        let allowed = self
+            .allowed
+            .iter()
+            .any(|&(id, s, l)| id == source_id && l == lint && s.overlaps(span));
+
+        if allowed {
+            return;
+        }
+
+        let current = Some(self.diagnostics.len());
+        self.diagnostics.push(Diagnostic::Warning(warning));
         self.last_warning = current;
     }
 
@@ -38,6 +38,12 @@ impl Warning {
             WarningKind::TemplateWithoutExpansions { span, .. } => *span,
             WarningKind::RemoveTupleCallParams { span, .. } => *span,
             WarningKind::UnecessarySemiColon { span, .. } => *span,
+            WarningKind::ConstantCondition { span, .. } => *span,
+            WarningKind::MixedNumberLiterals { span, .. } => *span,
+            WarningKind::UnknownLint { span, .. } => *span,
+            WarningKind::RedundantParens { span, .. } => *span,
+            WarningKind::DivergingCallValue { span, .. } => *span,
+            WarningKind::PatternMissingFields { span, .. } => *span,
         }
     }
 }
@@ -98,4 +104,100 @@ pub enum WarningKind {
         /// Span where the semi-colon is.
         span: Span,
     },
+    /// A condition always evaluates to the same value.
+    #[error("condition is always `{value}`")]
+    ConstantCondition {
+        /// Span of the condition.
+        span: Span,
+        /// The value the condition always evaluates to.
+        value: bool,
+        /// The context in which it is used.
+        context: Option<Span>,
+    },
+    /// A binary operation mixes an integer literal and a float literal.
+    #[error("operation mixes integer and float literals")]
+    MixedNumberLiterals {
+        /// Span of the operation.
+        span: Span,
+        /// Span of the integer literal.
+        integer: Span,
+        /// The context in which it is used.
+        context: Option<Span>,
+    },
+    /// Parentheses which can be removed without changing the meaning of the
+    /// expression.
+    #[error("unnecessary parentheses")]
+    RedundantParens {
+        /// Span of the parenthesized expression.
+        span: Span,
+        /// The context in which it is used.
+        context: Option<Span>,
+    },
+    /// The value of a call to a function which never returns is used.
+    #[error("function never returns, so the value of this call is never produced")]
+    DivergingCallValue {
+        /// Span of the call.
+        span: Span,
+        /// The context in which it is used.
+        context: Option<Span>,
+    },
+    /// A closed object pattern doesn't list all fields of the struct it
+    /// matches, so it can never match.
+    #[error("pattern is missing {missing} field(s), consider adding `..` to ignore them")]
+    PatternMissingFields {
+        /// Span of the pattern.
+        span: Span,
+        /// The number of fields which are missing from the pattern.
+        missing: usize,
+        /// The context in which it is used.
+        context: Option<Span>,
+    },
+    /// An `#[allow(..)]` attribute names a lint which doesn't exist.
+    #[error("unknown lint")]
+    UnknownLint {
+        /// Span of the lint name.
+        span: Span,
+    },
+}
+
+/// Declare the lint that each [WarningKind] belongs to, generating both
+/// [WarningKind::lint] and [WarningKind::resolve_lint] from the same table.
+macro_rules! lints {
+    ($($kind:ident => $lint:literal $(| $alias:literal)*,)*) => {
+        impl WarningKind {
+            /// The name of the lint this warning belongs to, as used in
+            /// `#[allow(..)]` attributes.
+            pub fn lint(&self) -> &'static str {
+                match self {
+                    $(WarningKind::$kind { .. } => $lint,)*
+                }
+            }
+
+            /// Resolve a lint name as it appears in an `#[allow(..)]`
+            /// attribute.
+            ///
+            /// `dead_code` and `unused_variables` are accepted as aliases for
+            /// `unused`.
+            pub(crate) fn resolve_lint(name: &str) -> Option<&'static str> {
+                match name {
+                    $($lint $(| $alias)* => Some($lint),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+lints! {
+    NotUsed => "unused" | "dead_code" | "unused_variables",
+    LetPatternMightPanic => "pattern_might_panic",
+    TemplateWithoutExpansions => "template_without_expansions",
+    RemoveTupleCallParams => "unused_call_parens",
+    UnecessarySemiColon => "redundant_semicolons",
+    ConstantCondition => "constant_condition",
+    MixedNumberLiterals => "mixed_number_literals",
+    UnknownLint => "unknown_lints",
+    RedundantParens => "unused_parens",
+    DivergingCallValue => "unreachable_code",
+    PatternMissingFields => "pattern_missing_fields",
 }
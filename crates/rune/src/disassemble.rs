@@ -0,0 +1,159 @@
+//! Disassembly of compiled units.
+
+use crate::Sources;
+use runestick::{Hash, Inst, Source, Span, Unit};
+use std::fmt;
+
+/// Trait to disassemble a unit into a listing of its instructions.
+///
+/// This is implemented for [Unit].
+pub trait Disassemble {
+    /// Disassemble the current unit, annotating each instruction with the
+    /// source line it originates from where debug information is available.
+    fn disassemble(&self, sources: &Sources) -> Disassembly;
+}
+
+impl Disassemble for Unit {
+    fn disassemble(&self, sources: &Sources) -> Disassembly {
+        let debug_info = self.debug_info();
+        let mut functions = Vec::<DisassembledFn>::new();
+
+        for (offset, inst) in self.iter_instructions().enumerate() {
+            if let Some((hash, signature)) = debug_info.and_then(|d| d.function_at(offset)) {
+                functions.push(DisassembledFn {
+                    hash: Some(hash),
+                    signature: Some(signature.to_string()),
+                    instructions: Vec::new(),
+                });
+            }
+
+            let debug = debug_info.and_then(|d| d.instruction_at(offset));
+
+            let source = debug.and_then(|d| {
+                let source = sources.get(d.source_id)?;
+                let (line, text) = source_line(source, d.span)?;
+                Some((line, text.trim_end().to_owned()))
+            });
+
+            let instruction = DisassembledInst {
+                offset,
+                inst,
+                label: debug.and_then(|d| Some(d.label.as_ref()?.to_string())),
+                comment: debug.and_then(|d| d.comment.clone()),
+                source,
+            };
+
+            match functions.last_mut() {
+                Some(function) => function.instructions.push(instruction),
+                None => functions.push(DisassembledFn {
+                    hash: None,
+                    signature: None,
+                    instructions: vec![instruction],
+                }),
+            }
+        }
+
+        Disassembly { functions }
+    }
+}
+
+/// The disassembled listing of a unit.
+///
+/// The [Display][fmt::Display] implementation renders it as plain text.
+#[derive(Debug, Clone)]
+pub struct Disassembly {
+    /// The functions in the unit, in the order they appear.
+    ///
+    /// Instructions which can't be attributed to a function because the unit
+    /// lacks debug information are grouped under an anonymous function.
+    pub functions: Vec<DisassembledFn>,
+}
+
+/// A single disassembled function.
+#[derive(Debug, Clone)]
+pub struct DisassembledFn {
+    /// The hash of the function.
+    pub hash: Option<Hash>,
+    /// The signature of the function.
+    pub signature: Option<String>,
+    /// The instructions of the function.
+    pub instructions: Vec<DisassembledInst>,
+}
+
+/// A single disassembled instruction.
+#[derive(Debug, Clone)]
+pub struct DisassembledInst {
+    /// The offset of the instruction in the unit.
+    pub offset: usize,
+    /// The instruction.
+    pub inst: Inst,
+    /// The label which points to the instruction.
+    pub label: Option<String>,
+    /// The comment attached to the instruction.
+    pub comment: Option<String>,
+    /// The line number and text of the source line the instruction originates
+    /// from.
+    pub source: Option<(usize, String)>,
+}
+
+impl fmt::Display for Disassembly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+
+        for function in &self.functions {
+            if !std::mem::take(&mut first) {
+                writeln!(f)?;
+            }
+
+            match (&function.signature, &function.hash) {
+                (Some(signature), Some(hash)) => writeln!(f, "fn {} ({}):", signature, hash)?,
+                _ => writeln!(f, "<unknown>:")?,
+            }
+
+            let mut last_line = None;
+
+            for inst in &function.instructions {
+                if let Some(label) = &inst.label {
+                    writeln!(f, "{}:", label)?;
+                }
+
+                if let Some((line, text)) = &inst.source {
+                    // NB: only print the source line when it changes.
+                    if last_line != Some(*line) {
+                        writeln!(f, "  {}: {}", line + 1, text.trim())?;
+                        last_line = Some(*line);
+                    }
+                }
+
+                write!(f, "    {:04} = {}", inst.offset, inst.inst)?;
+
+                if let Some(comment) = &inst.comment {
+                    write!(f, " // {}", comment)?;
+                }
+
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Get the zero-based line number and text of the line the span starts on.
+fn source_line(source: &Source, span: Span) -> Option<(usize, &str)> {
+    let line_starts = source.line_starts();
+
+    let line = match line_starts.binary_search(&span.start.into_usize()) {
+        Ok(n) => n,
+        Err(n) => n.saturating_sub(1),
+    };
+
+    let start = *line_starts.get(line)?;
+
+    let text = match line_starts.get(line + 1) {
+        Some(end) => source.get(start..*end)?,
+        None => source.get(start..)?,
+    };
+
+    Some((source.base_line() + line, text))
+}
@@ -9,10 +9,11 @@ use std::error::Error as _;
 use std::fmt;
 use std::fmt::Write as _;
 use std::io;
+use std::ops::Range;
 use thiserror::Error;
 
 use codespan_reporting::diagnostic::{Diagnostic, Label};
-use codespan_reporting::files::{Files, SimpleFiles};
+use codespan_reporting::files::{self, Files};
 use codespan_reporting::term;
 use codespan_reporting::term::termcolor::WriteColor;
 
@@ -60,19 +61,13 @@ impl EmitDiagnostics for Diagnostics {
         }
 
         let config = codespan_reporting::term::Config::default();
-        let mut files = SimpleFiles::new();
-
-        for source in sources.iter() {
-            files.add(source.name(), source.as_str());
-        }
-
         for diagnostic in self.diagnostics() {
             match diagnostic {
                 crate::Diagnostic::Error(e) => {
-                    error_emit_diagnostics_with(e, out, sources, &files, &config)?;
+                    error_emit_diagnostics_with(e, out, sources, &config)?;
                 }
                 crate::Diagnostic::Warning(w) => {
-                    warning_emit_diagnostics_with(w, out, sources, &files, &config)?;
+                    warning_emit_diagnostics_with(w, out, sources, &config)?;
                 }
             }
         }
@@ -86,12 +81,6 @@ impl EmitDiagnostics for VmError {
     where
         O: WriteColor,
     {
-        let mut files = SimpleFiles::new();
-
-        for source in sources.iter() {
-            files.add(source.name(), source.as_str());
-        }
-
         let (error, unwound) = self.as_unwound();
 
         let (unit, ip, frames) = match unwound {
@@ -114,7 +103,9 @@ impl EmitDiagnostics for VmError {
                 return Ok(());
             }
         };
-        let debug_inst = match debug_info.instruction_at(ip) {
+
+        let callers = frames.iter().map(|v| v.ip()).collect::<Vec<_>>();
+        let debug_inst = match debug_info.location_at(ip, &callers) {
             Some(debug_inst) => debug_inst,
             None => {
                 writeln!(
@@ -201,18 +192,15 @@ impl EmitDiagnostics for VmError {
             .with_labels(labels)
             .with_notes(notes);
 
-        term::emit(out, &config, &files, &diagnostic)?;
+        term::emit(out, &config, sources, &diagnostic)?;
 
         writeln!(out, "Callstack:")?;
         for frame in &backtrace {
-            let line = files
-                .line_index(frame.source_id, frame.span.start.into_usize())
-                .unwrap();
-            let line = files.line_number(frame.source_id, line).unwrap() - 1;
-            let line_range = files.line_range(frame.source_id, line).expect("a range");
-            let source = files.get(frame.source_id)?;
-            let name = source.name();
-            let slice = &source.source()[line_range];
+            let line_index = sources.line_index(frame.source_id, frame.span.start.into_usize())?;
+            let line = sources.line_number(frame.source_id, line_index)? - 1;
+            let line_range = sources.line_range(frame.source_id, line_index)?;
+            let name = sources.name(frame.source_id)?;
+            let slice = &sources.source(frame.source_id)?[line_range];
 
             write!(out, "\t{}:{}\n\t\t{}", name, line, slice)?;
         }
@@ -221,11 +209,10 @@ impl EmitDiagnostics for VmError {
 }
 
 /// Helper to emit diagnostics for a warning.
-fn warning_emit_diagnostics_with<'a, O>(
+fn warning_emit_diagnostics_with<O>(
     this: &Warning,
     out: &mut O,
-    sources: &'a Sources,
-    files: &'a impl Files<'a, FileId = SourceId>,
+    sources: &Sources,
     config: &codespan_reporting::term::Config,
 ) -> Result<(), DiagnosticsError>
 where
@@ -299,6 +286,71 @@ where
 
             None
         }
+        WarningKind::ConstantCondition {
+            span,
+            value,
+            context,
+        } => {
+            labels.push(
+                Label::primary(this.source_id(), span.range())
+                    .with_message(format!("condition is always `{}`", value)),
+            );
+
+            *context
+        }
+        WarningKind::MixedNumberLiterals {
+            span,
+            integer,
+            context,
+        } => {
+            labels.push(
+                Label::primary(this.source_id(), span.range())
+                    .with_message("operation mixes integer and float literals"),
+            );
+
+            labels.push(
+                Label::secondary(this.source_id(), integer.range())
+                    .with_message("this is an integer"),
+            );
+
+            let mut note = String::new();
+            writeln!(
+                note,
+                "Hint: Use an explicit float literal, like `1.0` instead of `1`"
+            )?;
+            notes.push(note);
+
+            *context
+        }
+        WarningKind::RedundantParens { span, context } => {
+            labels.push(
+                Label::primary(this.source_id(), span.range())
+                    .with_message("remove these parentheses"),
+            );
+
+            *context
+        }
+        WarningKind::DivergingCallValue { span, context } => {
+            labels.push(
+                Label::primary(this.source_id(), span.range())
+                    .with_message("this call never returns"),
+            );
+
+            *context
+        }
+        WarningKind::PatternMissingFields { span, context, .. } => {
+            labels.push(
+                Label::primary(this.source_id(), span.range())
+                    .with_message("add `..` to ignore the remaining fields"),
+            );
+
+            *context
+        }
+        WarningKind::UnknownLint { span } => {
+            labels
+                .push(Label::primary(this.source_id(), span.range()).with_message("unknown lint"));
+            None
+        }
     };
 
     if let Some(context) = context {
@@ -312,7 +364,7 @@ where
         .with_labels(labels)
         .with_notes(notes);
 
-    term::emit(out, &config, files, &diagnostic)?;
+    term::emit(out, config, sources, &diagnostic)?;
     Ok(())
 }
 
@@ -321,7 +373,6 @@ fn error_emit_diagnostics_with<O>(
     this: &Error,
     out: &mut O,
     sources: &Sources,
-    files: &SimpleFiles<&str, &str>,
     config: &codespan_reporting::term::Config,
 ) -> Result<(), DiagnosticsError>
 where
@@ -357,7 +408,7 @@ where
                         ))
                         .with_labels(labels);
 
-                    term::emit(out, config, files, &diagnostic)?;
+                    term::emit(out, config, sources, &diagnostic)?;
                 }
             }
 
@@ -399,7 +450,7 @@ where
         .with_labels(labels)
         .with_notes(notes);
 
-    term::emit(out, config, files, &diagnostic)?;
+    term::emit(out, config, sources, &diagnostic)?;
     return Ok(());
 
     fn format_compile_error(
@@ -425,6 +476,18 @@ where
                         .with_message("object being defined here"),
                 );
             }
+            CompileErrorKind::DuplicateParameter { first_span, .. } => {
+                labels.push(
+                    Label::secondary(this.source_id(), first_span.range())
+                        .with_message("first defined here"),
+                );
+            }
+            CompileErrorKind::AssignToImmutable { decl_span } => {
+                labels.push(
+                    Label::secondary(this.source_id(), decl_span.range())
+                        .with_message("declared here, consider using `let mut`"),
+                );
+            }
             CompileErrorKind::ModAlreadyLoaded { existing, .. } => {
                 let (existing_source_id, existing_span) = *existing;
 
@@ -449,6 +512,14 @@ where
                     notes.push(note);
                 }
             }
+            CompileErrorKind::InconsistentOrPatternBindings {
+                alternative_span, ..
+            } => {
+                labels.push(
+                    Label::secondary(this.source_id(), alternative_span.range())
+                        .with_message("not bound in this alternative"),
+                );
+            }
             CompileErrorKind::VariableMoved { moved_at, .. } => {
                 labels.push(
                     Label::secondary(this.source_id(), moved_at.range()).with_message("moved here"),
@@ -457,6 +528,18 @@ where
             CompileErrorKind::CallMacroError { item, .. } => {
                 notes.push(format!("Error originated in the `{}` macro", item).into());
             }
+            CompileErrorKind::MissingLoopLabel { existing, .. } => {
+                for (label, span) in existing {
+                    labels.push(
+                        Label::secondary(this.source_id(), span.range())
+                            .with_message(format!("loop label `'{}` is in scope", label)),
+                    );
+                }
+
+                if existing.is_empty() {
+                    notes.push("Hint: There are no labeled loops in scope".into());
+                }
+            }
             CompileErrorKind::NestedTest { nested_span } => {
                 labels.push(
                     Label::secondary(this.source_id(), nested_span.range())
@@ -588,13 +671,72 @@ impl EmitDiagnostics for Error {
     {
         let config = codespan_reporting::term::Config::default();
 
-        let mut files = SimpleFiles::new();
+        error_emit_diagnostics_with(self, out, sources, &config)
+    }
+}
+
+/// Source files used when rendering diagnostics.
+///
+/// Line and column numbers are adjusted by the base of each source, so that
+/// diagnostics for embedded fragments refer to the host document.
+impl<'a> Files<'a> for Sources {
+    type FileId = SourceId;
+    type Name = &'a str;
+    type Source = &'a str;
+
+    fn name(&'a self, id: SourceId) -> Result<Self::Name, files::Error> {
+        Ok(self.get(id).ok_or(files::Error::FileMissing)?.name())
+    }
+
+    fn source(&'a self, id: SourceId) -> Result<Self::Source, files::Error> {
+        Ok(self.get(id).ok_or(files::Error::FileMissing)?.as_str())
+    }
+
+    fn line_index(&'a self, id: SourceId, byte_index: usize) -> Result<usize, files::Error> {
+        let source = self.get(id).ok_or(files::Error::FileMissing)?;
+
+        Ok(source
+            .line_starts()
+            .binary_search(&byte_index)
+            .unwrap_or_else(|next_line| next_line - 1))
+    }
+
+    fn line_number(&'a self, id: SourceId, line_index: usize) -> Result<usize, files::Error> {
+        let source = self.get(id).ok_or(files::Error::FileMissing)?;
+        Ok(source.base_line() + line_index + 1)
+    }
 
-        for source in sources.iter() {
-            files.add(source.name(), source.as_str());
+    fn column_number(
+        &'a self,
+        id: SourceId,
+        line_index: usize,
+        byte_index: usize,
+    ) -> Result<usize, files::Error> {
+        let source = self.get(id).ok_or(files::Error::FileMissing)?;
+        let line_range = self.line_range(id, line_index)?;
+        let column = files::column_index(source.as_str(), line_range, byte_index) + 1;
+
+        if line_index == 0 {
+            Ok(source.base_column() + column)
+        } else {
+            Ok(column)
         }
+    }
+
+    fn line_range(&'a self, id: SourceId, line_index: usize) -> Result<Range<usize>, files::Error> {
+        let source = self.get(id).ok_or(files::Error::FileMissing)?;
+        let line_starts = source.line_starts();
+
+        let line_start = |line_index: usize| match line_index.cmp(&line_starts.len()) {
+            std::cmp::Ordering::Less => Ok(line_starts[line_index]),
+            std::cmp::Ordering::Equal => Ok(source.len()),
+            std::cmp::Ordering::Greater => Err(files::Error::LineTooLarge {
+                given: line_index,
+                max: line_starts.len() - 1,
+            }),
+        };
 
-        error_emit_diagnostics_with(self, out, sources, &files, &config)
+        Ok(line_start(line_index)?..line_start(line_index + 1)?)
     }
 }
 
@@ -617,7 +759,7 @@ pub fn line_for(source: &Source, span: Span) -> Option<(usize, &str, Span)> {
     };
 
     Some((
-        line,
+        source.base_line() + line,
         s,
         Span::new(
             span.start.into_usize() - start,
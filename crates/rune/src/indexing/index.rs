@@ -1,19 +1,19 @@
 use crate::ast;
 use crate::attrs;
-use crate::collections::HashMap;
+use crate::collections::{HashMap, HashSet};
 use crate::indexing::{IndexFnKind, IndexLocal as _, IndexScopes};
 use crate::load::{SourceLoader, Sources};
 use crate::macros::MacroCompiler;
 use crate::parsing::{Parse, Parser};
 use crate::query::{
     Build, BuildEntry, BuiltInFile, BuiltInFormat, BuiltInLine, BuiltInMacro, BuiltInTemplate,
-    Function, Indexed, IndexedEntry, InstanceFunction, Query, Used,
+    Diverges, Function, Indexed, IndexedEntry, InstanceFunction, Query, Used,
 };
 use crate::shared::{Consts, Items};
 use crate::worker::{Import, ImportKind, LoadFileKind, Task};
 use crate::{
     CompileError, CompileErrorKind, CompileResult, CompileVisitor, Diagnostics, OptionSpanned as _,
-    Options, ParseError, Resolve as _, Spanned as _, Storage,
+    Options, ParseError, Resolve as _, Spanned as _, Storage, WarningKind,
 };
 use runestick::format;
 use runestick::{
@@ -70,9 +70,173 @@ pub(crate) struct Indexer<'a> {
     ///
     /// Then, `nested_item` would point to the span of `pub fn public`.
     pub(crate) nested_item: Option<Span>,
+    /// Set if a `break` has been encountered in the loop currently being
+    /// indexed.
+    pub(crate) has_break: bool,
+    /// Spans of `loop` expressions which contain no `break`, and therefore
+    /// never complete.
+    pub(crate) infinite_loops: HashSet<Span>,
+    /// The labels and spans of the loops currently being indexed, innermost
+    /// last.
+    pub(crate) loops: Vec<(Option<Box<str>>, Span)>,
+    /// Spans of call expressions which call a local variable, rather than an
+    /// item.
+    pub(crate) local_calls: HashSet<Span>,
 }
 
 impl<'a> Indexer<'a> {
+    /// Enter a loop with the given label, which can be the target of `break`
+    /// and `continue` expressions indexed until [exit_loop] is called.
+    ///
+    /// [exit_loop]: Indexer::exit_loop
+    fn enter_loop(&mut self, label: Option<&ast::Label>, span: Span) -> CompileResult<()> {
+        let label = match label {
+            Some(label) => Some(label.resolve(&self.storage, &self.source)?.into()),
+            None => None,
+        };
+
+        self.loops.push((label, span));
+        Ok(())
+    }
+
+    /// Exit the loop most recently entered with [enter_loop].
+    ///
+    /// [enter_loop]: Indexer::enter_loop
+    fn exit_loop(&mut self) {
+        self.loops.pop();
+    }
+
+    /// Mark the loop targeted by a `break` or `continue` with the given label
+    /// as having loop control, so it won't be unrolled.
+    fn mark_loop_control(&mut self, label: Option<&ast::Label>) -> CompileResult<()> {
+        let target = match label {
+            Some(label) => {
+                let label = label.resolve(&self.storage, &self.source)?;
+
+                self.loops
+                    .iter()
+                    .rev()
+                    .find(|(l, _)| l.as_deref() == Some(&*label))
+            }
+            None => self.loops.last(),
+        };
+
+        if let Some((_, span)) = target {
+            self.query.mark_loop_control(self.source_id, *span);
+        }
+
+        Ok(())
+    }
+
+    /// Describe under which conditions the given block unconditionally
+    /// diverges, either by panicking or by entering a loop which is never
+    /// broken out of.
+    ///
+    /// This is conservative, so it's up to the caller to make sure that the
+    /// block doesn't return early.
+    fn block_diverges(&self, block: &ast::Block) -> Diverges {
+        let mut any = Vec::new();
+
+        for stmt in &block.statements {
+            let expr = match stmt {
+                ast::Stmt::Local(local) => &local.expr,
+                ast::Stmt::Expr(expr, _) => expr,
+                ast::Stmt::Item(..) => continue,
+            };
+
+            match self.expr_diverges(expr) {
+                Diverges::Never => (),
+                Diverges::Always => return Diverges::Always,
+                diverges => any.push(diverges),
+            }
+        }
+
+        Diverges::any(any)
+    }
+
+    /// Describe under which conditions the given expression unconditionally
+    /// diverges.
+    fn expr_diverges(&self, expr: &ast::Expr) -> Diverges {
+        match expr {
+            ast::Expr::Call(call) => match &call.expr {
+                ast::Expr::Path(path) if !self.local_calls.contains(&call.span()) => {
+                    Diverges::Call(path.clone())
+                }
+                _ => Diverges::Never,
+            },
+            ast::Expr::Loop(expr_loop) if self.infinite_loops.contains(&expr_loop.span()) => {
+                Diverges::Always
+            }
+            ast::Expr::Block(expr_block) if expr_block.async_token.is_none() => {
+                self.block_diverges(&expr_block.block)
+            }
+            ast::Expr::If(expr_if) => {
+                let expr_else = match &expr_if.expr_else {
+                    Some(expr_else) => expr_else,
+                    None => return Diverges::Never,
+                };
+
+                let mut all = vec![
+                    self.block_diverges(&expr_if.block),
+                    self.block_diverges(&expr_else.block),
+                ];
+
+                for expr_else_if in &expr_if.expr_else_ifs {
+                    all.push(self.block_diverges(&expr_else_if.block));
+                }
+
+                Diverges::all(all)
+            }
+            ast::Expr::Group(expr_group) => self.expr_diverges(&expr_group.expr),
+            ast::Expr::ForceSemi(force_semi) => self.expr_diverges(&force_semi.expr),
+            _ => Diverges::Never,
+        }
+    }
+
+    /// Process an `#[allow(..)]` attribute, suppressing the named lints within
+    /// the given span.
+    fn allow_lints(
+        &mut self,
+        attributes: &mut attrs::Attributes,
+        span: Span,
+    ) -> Result<(), CompileError> {
+        let (_, allow) = match attributes.try_parse::<attrs::Allow>()? {
+            Some(allow) => allow,
+            None => return Ok(()),
+        };
+
+        for (ident, _) in &allow.args {
+            let name = ident.resolve(&self.storage, &self.source)?;
+
+            match WarningKind::resolve_lint(name.as_ref()) {
+                Some(lint) => self.diagnostics.allow(self.source_id, span, lint),
+                None => self.diagnostics.unknown_lint(self.source_id, ident.span()),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Mark the local variable being assigned to by an assignment expression,
+    /// since reassigned variables can't be propagated as constants.
+    fn mark_assigned(&mut self, lhs: &ast::Expr) -> Result<(), CompileError> {
+        let ident = match lhs {
+            ast::Expr::Path(path) => match path.try_as_ident() {
+                Some(ident) => ident,
+                None => return Ok(()),
+            },
+            _ => return Ok(()),
+        };
+
+        let ident = ident.resolve(&self.storage, &self.source)?;
+
+        if let Some(span) = self.scopes.declaration(ident.as_ref()) {
+            self.query.mark_non_constant(self.source_id, span);
+        }
+
+        Ok(())
+    }
+
     /// Try to expand an internal macro.
     fn try_expand_internal_macro(
         &mut self,
@@ -141,6 +305,7 @@ impl<'a> Indexer<'a> {
         args: &attrs::BuiltInArgs,
     ) -> Result<BuiltInMacro, ParseError> {
         let mut p = Parser::from_token_stream(&ast.stream);
+        p.set_nesting_limit(self.options.nesting_limit);
         let mut exprs = Vec::new();
 
         while !p.is_eof()? {
@@ -167,6 +332,7 @@ impl<'a> Indexer<'a> {
         _: &attrs::BuiltInArgs,
     ) -> Result<BuiltInMacro, ParseError> {
         let mut p = Parser::from_token_stream(&ast.stream);
+        p.set_nesting_limit(self.options.nesting_limit);
 
         let value = p.parse::<ast::Expr>()?;
 
@@ -359,12 +525,14 @@ impl<'a> Indexer<'a> {
 
         let mut compiler = MacroCompiler {
             item,
+            source_id: self.source_id,
             storage: self.query.storage(),
             options: self.options,
             context: self.context,
             source: self.source.clone(),
             query: self.query.clone(),
             consts: self.consts.clone(),
+            visitor: self.visitor.clone(),
         };
 
         let expanded = compiler.eval_macro::<T>(ast)?;
@@ -518,7 +686,7 @@ impl<'a> Indexer<'a> {
     /// Handle a filesystem module.
     pub(crate) fn handle_file_mod(&mut self, item_mod: &mut ast::ItemMod) -> CompileResult<()> {
         let span = item_mod.span();
-        let name = item_mod.name.resolve(&self.storage, &*self.source)?;
+        let name = item_mod.name.resolve(&self.storage, &self.source)?;
         let _guard = self.items.push_name(name.as_ref());
 
         let root = match &self.root {
@@ -609,6 +777,14 @@ impl Index for ast::ItemFn {
         let span = self.span();
         log::trace!("ItemFn => {:?}", idx.source.source(span));
 
+        let mut attributes = attrs::Attributes::new(
+            self.attributes.clone(),
+            idx.storage.clone(),
+            idx.source.clone(),
+        );
+
+        idx.allow_lints(&mut attributes, span)?;
+
         let name = self.name.resolve(&idx.storage, &*idx.source)?;
         let _guard = idx.items.push_name(name.as_ref());
 
@@ -654,6 +830,10 @@ impl Index for ast::ItemFn {
             }
         }
 
+        if let Some((_, ty)) = &mut self.output {
+            ty.index(idx)?;
+        }
+
         // Take and restore item nesting.
         let last = idx.nested_item.replace(self.descriptive_span());
         self.body.index(idx)?;
@@ -680,20 +860,18 @@ impl Index for ast::ItemFn {
             }
         };
 
-        let fun = Function {
-            ast: Box::new(self.clone()),
-            call,
-        };
+        if let IndexFnKind::None = f.kind {
+            if !f.generator && !f.has_return {
+                let diverges = idx.block_diverges(&self.body);
+                idx.query.insert_diverges(&item.item, &idx.source, diverges);
+            }
+        }
 
         // NB: it's only a public item in the sense of exporting it if it's not
         // inside of a nested item.
         let is_public = item.is_public() && idx.nested_item.is_none();
 
-        let mut attributes = attrs::Attributes::new(
-            self.attributes.clone(),
-            idx.storage.clone(),
-            idx.source.clone(),
-        );
+        let track_caller = attributes.try_parse::<attrs::TrackCaller>()?.is_some();
 
         let is_test = match attributes.try_parse::<attrs::Test>()? {
             Some((span, _)) => {
@@ -706,11 +884,25 @@ impl Index for ast::ItemFn {
                     ));
                 }
 
+                if !self.args.is_empty() && !self.is_instance() {
+                    return Err(CompileError::new(
+                        span.join(self.descriptive_span()),
+                        CompileErrorKind::TestWithArguments,
+                    ));
+                }
+
                 true
             }
             _ => false,
         };
 
+        let fun = Function {
+            ast: Box::new(self.clone()),
+            call,
+            track_caller,
+            is_test,
+        };
+
         if let Some(attrs) = attributes.remaining() {
             return Err(CompileError::msg(attrs, "unrecognized function attribute"));
         }
@@ -727,11 +919,14 @@ impl Index for ast::ItemFn {
                 CompileError::new(span, CompileErrorKind::InstanceFunctionOutsideImpl)
             })?;
 
+            let return_arity = fun.ast.return_arity();
+
             let f = InstanceFunction {
                 ast: fun.ast,
                 impl_item: impl_item.clone(),
                 instance_span: span,
                 call: fun.call,
+                track_caller: fun.track_caller,
             };
 
             // NB: all instance functions must be pre-emptively built,
@@ -748,6 +943,7 @@ impl Index for ast::ItemFn {
             let kind = CompileMetaKind::Function {
                 type_hash: Hash::type_hash(&item.item),
                 is_test: false,
+                return_arity,
             };
 
             let meta = CompileMeta {
@@ -762,6 +958,8 @@ impl Index for ast::ItemFn {
 
             idx.query.insert_meta(span, meta)?;
         } else if is_public || is_test {
+            let return_arity = fun.ast.return_arity();
+
             // NB: immediately compile all toplevel functions.
             idx.query.push_build_entry(BuildEntry {
                 location: Location::new(idx.source_id, fun.ast.descriptive_span()),
@@ -774,6 +972,7 @@ impl Index for ast::ItemFn {
             let kind = CompileMetaKind::Function {
                 type_hash: Hash::type_hash(&item.item),
                 is_test,
+                return_arity,
             };
 
             let meta = CompileMeta {
@@ -1005,6 +1204,15 @@ impl Index for ast::Pat {
             ast::Pat::PatIgnore(..) => (),
             ast::Pat::PatLit(..) => (),
             ast::Pat::PatRest(..) => (),
+            ast::Pat::PatIn(..) => (),
+            ast::Pat::PatRange(..) => (),
+            ast::Pat::PatOr(pat_or) => {
+                pat_or.first.index(idx)?;
+
+                for (_, pat) in &mut pat_or.rest {
+                    pat.index(idx)?;
+                }
+            }
         }
 
         Ok(())
@@ -1151,6 +1359,9 @@ impl Index for ast::Expr {
             ast::Expr::Select(expr_select) => {
                 expr_select.index(idx)?;
             }
+            ast::Expr::TryCatch(expr_try_catch) => {
+                expr_try_catch.index(idx)?;
+            }
             // ignored because they have no effect on indexing.
             ast::Expr::Call(expr_call) => {
                 expr_call.index(idx)?;
@@ -1232,6 +1443,7 @@ impl Index for ast::ExprAssign {
 
         self.lhs.index(idx)?;
         self.rhs.index(idx)?;
+        idx.mark_assigned(&self.lhs)?;
         Ok(())
     }
 }
@@ -1243,6 +1455,11 @@ impl Index for ast::ExprBinary {
 
         self.lhs.index(idx)?;
         self.rhs.index(idx)?;
+
+        if self.op.is_assign() {
+            idx.mark_assigned(&self.lhs)?;
+        }
+
         Ok(())
     }
 }
@@ -1353,11 +1570,24 @@ impl Index for Box<ast::ItemStruct> {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
         let span = self.span();
 
-        if let Some(first) = self.attributes.first() {
-            return Err(CompileError::msg(
-                first,
-                "struct attributes are not supported",
-            ));
+        let mut attributes = attrs::Attributes::new(
+            self.attributes.clone(),
+            idx.storage.clone(),
+            idx.source.clone(),
+        );
+
+        idx.allow_lints(&mut attributes, span)?;
+
+        let repr = match attributes.try_parse::<attrs::Repr>()? {
+            Some((_, repr)) => {
+                repr.validate(&idx.storage, &idx.source)?;
+                true
+            }
+            None => false,
+        };
+
+        if let Some(span) = attributes.remaining() {
+            return Err(CompileError::msg(span, "unsupported struct attribute"));
         }
 
         for (field, _) in self.body.fields() {
@@ -1387,7 +1617,8 @@ impl Index for Box<ast::ItemStruct> {
         )?;
         self.id = Some(item.id);
 
-        idx.query.index_struct(&item, &idx.source, self.clone())?;
+        idx.query
+            .index_struct(&item, &idx.source, self.clone(), repr)?;
         Ok(())
     }
 }
@@ -1519,6 +1750,7 @@ impl Index for ast::Item {
             }
             ast::Item::Struct(item_struct) => {
                 item_struct.index(idx)?;
+                attributes.drain();
             }
             ast::Item::Fn(item_fn) => {
                 item_fn.index(idx)?;
@@ -1574,7 +1806,10 @@ impl Index for ast::Path {
             }
             Some(ast::PathKind::Ident(ident)) => {
                 let ident = ident.resolve(&idx.storage, &*idx.source)?;
-                idx.scopes.mark_use(ident.as_ref());
+
+                if let Some(span) = idx.scopes.mark_use(ident.as_ref()) {
+                    idx.query.mark_non_constant(idx.source_id, span);
+                }
             }
             None => (),
         }
@@ -1583,6 +1818,26 @@ impl Index for ast::Path {
     }
 }
 
+impl Index for ast::Type {
+    fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
+        let span = self.span();
+        log::trace!("Type => {:?}", idx.source.source(span));
+
+        match self {
+            ast::Type::Path(path) => {
+                path.index(idx)?;
+            }
+            ast::Type::Tuple(tuple) => {
+                for (ty, _) in tuple {
+                    ty.index(idx)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl Index for ast::ExprWhile {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
         let span = self.span();
@@ -1590,7 +1845,9 @@ impl Index for ast::ExprWhile {
 
         let _guard = idx.scopes.push_scope();
         self.condition.index(idx)?;
+        idx.enter_loop(self.label.as_ref().map(|(label, _)| label), span)?;
         self.body.index(idx)?;
+        idx.exit_loop();
         Ok(())
     }
 }
@@ -1600,8 +1857,34 @@ impl Index for ast::ExprLoop {
         let span = self.span();
         log::trace!("ExprLoop => {:?}", idx.source.source(span));
 
-        let _guard = idx.scopes.push_scope();
+        let outer = std::mem::take(&mut idx.has_break);
+
+        {
+            let _guard = idx.scopes.push_scope();
+            idx.enter_loop(self.label.as_ref().map(|(label, _)| label), span)?;
+            self.body.index(idx)?;
+            idx.exit_loop();
+        }
+
+        if !idx.has_break {
+            idx.infinite_loops.insert(span);
+        }
+
+        idx.has_break |= outer;
+        Ok(())
+    }
+}
+
+impl Index for ast::ExprTryCatch {
+    fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
+        let span = self.span();
+        log::trace!("ExprTryCatch => {:?}", idx.source.source(span));
+
         self.body.index(idx)?;
+
+        let _guard = idx.scopes.push_scope();
+        self.binding.index(idx)?;
+        self.catch_body.index(idx)?;
         Ok(())
     }
 }
@@ -1614,9 +1897,19 @@ impl Index for ast::ExprFor {
         // NB: creating the iterator is evaluated in the parent scope.
         self.iter.index(idx)?;
 
-        let _guard = idx.scopes.push_scope();
-        self.binding.index(idx)?;
-        self.body.index(idx)?;
+        {
+            let _guard = idx.scopes.push_scope();
+            self.binding.index(idx)?;
+            idx.enter_loop(self.label.as_ref().map(|(label, _)| label), span)?;
+            self.body.index(idx)?;
+            idx.exit_loop();
+        }
+
+        if let Some(expr_else) = &mut self.expr_else {
+            let _guard = idx.scopes.push_scope();
+            expr_else.block.index(idx)?;
+        }
+
         Ok(())
     }
 }
@@ -1713,13 +2006,11 @@ impl Index for ast::ExprBreak {
         let span = self.span();
         log::trace!("ExprBreak => {:?}", idx.source.source(span));
 
+        idx.has_break = true;
+        idx.mark_loop_control(self.label.as_ref())?;
+
         if let Some(expr) = &mut self.expr {
-            match expr {
-                ast::ExprBreakValue::Expr(expr) => {
-                    expr.index(idx)?;
-                }
-                ast::ExprBreakValue::Label(..) => (),
-            }
+            expr.index(idx)?;
         }
 
         Ok(())
@@ -1730,6 +2021,8 @@ impl Index for ast::ExprContinue {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
         let span = self.span();
         log::trace!("ExprContinue => {:?}", idx.source.source(span));
+
+        idx.mark_loop_control(self.label.as_ref())?;
         Ok(())
     }
 }
@@ -1755,6 +2048,8 @@ impl Index for ast::ExprReturn {
         let span = self.span();
         log::trace!("ExprReturn => {:?}", idx.source.source(span));
 
+        idx.scopes.mark_return();
+
         if let Some(expr) = &mut self.expr {
             expr.index(idx)?;
         }
@@ -1780,6 +2075,7 @@ impl Index for ast::ExprTry {
         let span = self.span();
         log::trace!("ExprTry => {:?}", idx.source.source(span));
 
+        idx.scopes.mark_return();
         self.expr.index(idx)?;
         Ok(())
     }
@@ -1831,6 +2127,17 @@ impl Index for ast::ExprCall {
         }
 
         self.expr.index(idx)?;
+
+        if let ast::Expr::Path(path) = &self.expr {
+            if let Some(ident) = path.try_as_ident() {
+                let ident = ident.resolve(&idx.storage, &idx.source)?;
+
+                if idx.scopes.declaration(ident.as_ref()).is_some() {
+                    idx.local_calls.insert(span);
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -1903,6 +2210,10 @@ impl Index for ast::ExprObject {
             }
         }
 
+        if let Some((_, base)) = &mut self.base {
+            base.index(idx)?;
+        }
+
         Ok(())
     }
 }
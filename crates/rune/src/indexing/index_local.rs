@@ -34,6 +34,15 @@ impl IndexLocal for ast::Pat {
             ast::Pat::PatIgnore(..) => (),
             ast::Pat::PatLit(..) => (),
             ast::Pat::PatRest(..) => (),
+            ast::Pat::PatIn(..) => (),
+            ast::Pat::PatRange(..) => (),
+            ast::Pat::PatOr(pat_or) => {
+                pat_or.first.index_local(idx)?;
+
+                for (_, pat) in &mut pat_or.rest {
+                    pat.index_local(idx)?;
+                }
+            }
         }
 
         Ok(())
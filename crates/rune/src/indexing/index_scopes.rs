@@ -57,6 +57,7 @@ impl IndexScopeGuard {
                 generator: fun.generator,
                 kind: fun.kind,
                 has_await: fun.has_await,
+                has_return: fun.has_return,
             }),
             _ => Err(CompileError::msg(&span, "expected function")),
         }
@@ -118,6 +119,7 @@ pub(crate) struct Function {
     pub(crate) kind: IndexFnKind,
     #[allow(dead_code)]
     pub(crate) has_await: bool,
+    pub(crate) has_return: bool,
 }
 
 pub(crate) struct Closure {
@@ -135,6 +137,7 @@ pub struct IndexFunction {
     scope: IndexScope,
     generator: bool,
     has_await: bool,
+    has_return: bool,
 }
 
 impl IndexFunction {
@@ -145,6 +148,7 @@ impl IndexFunction {
             scope: IndexScope::new(),
             generator: false,
             has_await: false,
+            has_return: false,
         }
     }
 }
@@ -195,30 +199,61 @@ impl IndexScopes {
         Ok(())
     }
 
+    /// Find the span where the given variable was declared, if it's declared
+    /// within the current function.
+    pub(crate) fn declaration(&self, var: &str) -> Option<Span> {
+        let levels = self.levels.borrow();
+
+        for level in levels.iter().rev() {
+            match level {
+                IndexScopeLevel::IndexScope(scope) => {
+                    if let Some(span) = scope.locals.get(var) {
+                        return Some(*span);
+                    }
+                }
+                IndexScopeLevel::IndexClosure(closure) => {
+                    if let Some(span) = closure.scope.locals.get(var) {
+                        return Some(*span);
+                    }
+                }
+                IndexScopeLevel::IndexFunction(fun) => {
+                    return fun.scope.locals.get(var).copied();
+                }
+            }
+        }
+
+        None
+    }
+
     /// Mark that the given variable is used.
-    pub(crate) fn mark_use(&mut self, var: &str) {
+    ///
+    /// Returns the span where the variable was declared if this use caused it
+    /// to be captured by a closure.
+    pub(crate) fn mark_use(&mut self, var: &str) -> Option<Span> {
         let mut levels = self.levels.borrow_mut();
         let iter = levels.iter_mut().rev();
 
         let mut closures = Vec::new();
-        let mut found = false;
+        let mut found = None;
 
         for level in iter {
             match level {
                 IndexScopeLevel::IndexScope(scope) => {
-                    if scope.locals.get(var).is_some() {
-                        found = true;
+                    if let Some(span) = scope.locals.get(var) {
+                        found = Some(Some(*span));
                         break;
                     }
                 }
                 IndexScopeLevel::IndexClosure(closure) => {
+                    // NB: already captured, so the declaration has been
+                    // reported before.
                     if closure.existing.contains(var) {
-                        found = true;
+                        found = Some(None);
                         break;
                     }
 
-                    if closure.scope.locals.get(var).is_some() {
-                        found = true;
+                    if let Some(span) = closure.scope.locals.get(var) {
+                        found = Some(Some(*span));
                         break;
                     }
 
@@ -226,26 +261,32 @@ impl IndexScopes {
                 }
                 // NB: cannot capture variables outside of functions.
                 IndexScopeLevel::IndexFunction(scope) => {
-                    found = scope.scope.locals.get(var).is_some();
+                    found = scope.scope.locals.get(var).map(|span| Some(*span));
                     break;
                 }
             }
         }
 
+        let span = found?;
+
+        if closures.is_empty() {
+            return None;
+        }
+
         // mark all traversed closures to capture the given variable.
-        if found {
-            for closure in closures {
-                closure
-                    .captures
-                    .push(CompileMetaCapture { ident: var.into() });
+        for closure in closures {
+            closure
+                .captures
+                .push(CompileMetaCapture { ident: var.into() });
 
-                let inserted = closure.existing.insert(var.into());
+            let inserted = closure.existing.insert(var.into());
 
-                // NB: should be checked above, because closures where it's
-                // already captured are skipped.
-                debug_assert!(inserted);
-            }
+            // NB: should be checked above, because closures where it's
+            // already captured are skipped.
+            debug_assert!(inserted);
         }
+
+        span
     }
 
     /// Mark that a yield was used, meaning the encapsulating function is a
@@ -308,6 +349,26 @@ impl IndexScopes {
         ))
     }
 
+    /// Mark that the encapsulating function might return early, either through
+    /// a `return` or a try operator.
+    ///
+    /// Returns inside of closures only affect the closure, so they are
+    /// ignored.
+    pub(crate) fn mark_return(&mut self) {
+        let mut levels = self.levels.borrow_mut();
+
+        for level in levels.iter_mut().rev() {
+            match level {
+                IndexScopeLevel::IndexFunction(fun) => {
+                    fun.has_return = true;
+                    return;
+                }
+                IndexScopeLevel::IndexClosure(..) => return,
+                IndexScopeLevel::IndexScope(..) => (),
+            }
+        }
+    }
+
     /// Push a function.
     pub(crate) fn push_function(&mut self, kind: IndexFnKind) -> IndexScopeGuard {
         self.levels
@@ -19,6 +19,7 @@ impl IrEval for ir::Ir {
             ir::IrKind::Assign(ir_assign) => ir_assign.eval(interp, used),
             ir::IrKind::Template(ir_template) => ir_template.eval(interp, used),
             ir::IrKind::Name(name) => Ok(interp.resolve_var(self.span(), name.as_ref(), used)?),
+            ir::IrKind::Path(item) => Ok(interp.resolve_path(self.span(), item, used)?),
             ir::IrKind::Target(ir_target) => Ok(interp.scopes.get_target(ir_target)?),
             ir::IrKind::Value(value) => Ok(IrValue::from_const(value.clone())),
             ir::IrKind::Branches(branches) => branches.eval(interp, used),
@@ -31,6 +32,7 @@ impl IrEval for ir::Ir {
             ir::IrKind::Tuple(ir_tuple) => ir_tuple.eval(interp, used),
             ir::IrKind::Object(ir_object) => ir_object.eval(interp, used),
             ir::IrKind::Call(ir_call) => ir_call.eval(interp, used),
+            ir::IrKind::Len(ir_len) => ir_len.eval(interp, used),
         }
     }
 }
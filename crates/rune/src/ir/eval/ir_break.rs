@@ -7,16 +7,17 @@ impl IrEval for ir::IrBreak {
         let span = self.span();
         interp.budget.take(span)?;
 
-        match &self.kind {
-            ir::IrBreakKind::Ir(ir) => {
-                let value = ir.eval(interp, used)?;
-                Err(IrEvalOutcome::Break(span, IrEvalBreak::Value(value)))
-            }
-            ir::IrBreakKind::Label(label) => Err(IrEvalOutcome::Break(
-                span,
-                IrEvalBreak::Label(label.clone()),
-            )),
-            ir::IrBreakKind::Inherent => Err(IrEvalOutcome::Break(span, IrEvalBreak::Inherent)),
-        }
+        let value = match &self.expr {
+            Some(ir) => Some(ir.eval(interp, used)?),
+            None => None,
+        };
+
+        let b = match (&self.label, value) {
+            (Some(label), value) => IrEvalBreak::Label(label.clone(), value),
+            (None, Some(value)) => IrEvalBreak::Value(value),
+            (None, None) => IrEvalBreak::Inherent,
+        };
+
+        Err(IrEvalOutcome::Break(span, b))
     }
 }
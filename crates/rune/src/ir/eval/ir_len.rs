@@ -0,0 +1,29 @@
+use crate::ir::eval::prelude::*;
+
+impl IrEval for ir::IrLen {
+    type Output = IrValue;
+
+    fn eval(
+        &self,
+        interp: &mut IrInterpreter<'_>,
+        used: Used,
+    ) -> Result<Self::Output, IrEvalOutcome> {
+        let span = self.span();
+
+        let len = match self.target.eval(interp, used)? {
+            IrValue::String(s) => s.borrow_ref().map_err(IrError::access(span))?.len(),
+            IrValue::Bytes(b) => b.borrow_ref().map_err(IrError::access(span))?.len(),
+            IrValue::Vec(vec) => vec.borrow_ref().map_err(IrError::access(span))?.len(),
+            IrValue::Tuple(tuple) => tuple.borrow_ref().map_err(IrError::access(span))?.len(),
+            IrValue::Object(object) => object.borrow_ref().map_err(IrError::access(span))?.len(),
+            _ => {
+                return Err(IrEvalOutcome::from(IrError::msg(
+                    span,
+                    "expected a value with a length",
+                )))
+            }
+        };
+
+        Ok(IrValue::Integer(len.into()))
+    }
+}
@@ -9,44 +9,49 @@ impl IrEval for ir::IrLoop {
 
         let guard = interp.scopes.push();
 
-        loop {
+        let value = loop {
             if let Some(condition) = &self.condition {
                 interp.scopes.clear_current(&*condition)?;
 
                 if !condition.eval(interp, used)? {
-                    break;
+                    break IrValue::Unit;
                 }
             }
 
-            match self.body.eval(interp, used) {
-                Ok(..) => (),
-                Err(outcome) => match outcome {
-                    IrEvalOutcome::Break(span, b) => match b {
-                        IrEvalBreak::Inherent => break,
-                        IrEvalBreak::Label(l) => {
-                            if self.label.as_ref() == Some(&l) {
-                                break;
-                            }
-
-                            return Err(IrEvalOutcome::Break(span, IrEvalBreak::Label(l)));
-                        }
-                        IrEvalBreak::Value(value) => {
-                            if self.condition.is_none() {
-                                return Ok(value);
-                            }
-
-                            return Err(IrEvalOutcome::from(IrError::msg(
-                                span,
-                                "break with value is not supported for unconditional loops",
-                            )));
-                        }
-                    },
-                    outcome => return Err(outcome),
-                },
+            let (span, b) = match self.body.eval(interp, used) {
+                Ok(..) => continue,
+                Err(IrEvalOutcome::Break(span, b)) => (span, b),
+                Err(outcome) => return Err(outcome),
             };
-        }
+
+            interp.scopes.pop_nested(&guard);
+
+            let value = match b {
+                IrEvalBreak::Inherent => None,
+                IrEvalBreak::Value(value) => Some(value),
+                IrEvalBreak::Label(l, value) => {
+                    if self.label.as_ref() != Some(&l) {
+                        interp.scopes.pop(self, guard)?;
+                        return Err(IrEvalOutcome::Break(span, IrEvalBreak::Label(l, value)));
+                    }
+
+                    value
+                }
+            };
+
+            match value {
+                None => break IrValue::Unit,
+                Some(value) if self.condition.is_none() => break value,
+                Some(..) => {
+                    return Err(IrEvalOutcome::from(IrError::msg(
+                        span,
+                        "break with value is not supported for conditional loops",
+                    )));
+                }
+            }
+        };
 
         interp.scopes.pop(self, guard)?;
-        Ok(IrValue::Unit)
+        Ok(value)
     }
 }
@@ -11,6 +11,7 @@ mod ir_break;
 mod ir_call;
 mod ir_condition;
 mod ir_decl;
+mod ir_len;
 mod ir_loop;
 mod ir_object;
 mod ir_scope;
@@ -124,6 +125,6 @@ pub enum IrEvalBreak {
     Inherent,
     /// The break had a value.
     Value(IrValue),
-    /// The break had a label.
-    Label(Box<str>),
+    /// The break had a label, and optionally a value.
+    Label(Box<str>, Option<IrValue>),
 }
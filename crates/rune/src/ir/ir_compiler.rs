@@ -2,7 +2,7 @@ use crate::ir;
 use crate::query::BuiltInMacro;
 use crate::query::BuiltInTemplate;
 use crate::{IrErrorKind, Resolve, Spanned, Storage};
-use runestick::{Bytes, ConstValue, Source};
+use runestick::{Bytes, ConstValue, Item, Source};
 use std::sync::Arc;
 
 use crate::ast;
@@ -96,7 +96,7 @@ impl IrCompile for ast::Expr {
             ast::Expr::Group(expr_group) => expr_group.expr.compile(c)?,
             ast::Expr::Binary(expr_binary) => expr_binary.compile(c)?,
             ast::Expr::Assign(expr_assign) => expr_assign.compile(c)?,
-            ast::Expr::Call(expr_call) => ir::Ir::new(self.span(), expr_call.compile(c)?),
+            ast::Expr::Call(expr_call) => expr_call.compile(c)?,
             ast::Expr::If(expr_if) => ir::Ir::new(self.span(), expr_if.compile(c)?),
             ast::Expr::Loop(expr_loop) => ir::Ir::new(self.span(), expr_loop.compile(c)?),
             ast::Expr::While(expr_while) => ir::Ir::new(self.span(), expr_while.compile(c)?),
@@ -201,11 +201,29 @@ impl IrCompile for ast::ExprAssign {
 }
 
 impl IrCompile for ast::ExprCall {
-    type Output = ir::IrCall;
+    type Output = ir::Ir;
 
     fn compile(&self, c: &mut IrCompiler<'_>) -> Result<Self::Output, IrError> {
         let span = self.span();
 
+        if let ast::Expr::FieldAccess(expr_field_access) = &self.expr {
+            if let ast::ExprField::Path(field) = &expr_field_access.expr_field {
+                if let Some(ident) = field.try_as_ident() {
+                    if self.args.is_empty() && c.resolve(ident)? == "len" {
+                        let target = expr_field_access.expr.compile(c)?;
+
+                        return Ok(ir::Ir::new(
+                            span,
+                            ir::IrLen {
+                                span,
+                                target: Box::new(target),
+                            },
+                        ));
+                    }
+                }
+            }
+        }
+
         let mut args = Vec::new();
 
         for (expr, _) in &self.args {
@@ -216,11 +234,14 @@ impl IrCompile for ast::ExprCall {
             if let Some(ident) = path.try_as_ident() {
                 let target = c.resolve(ident)?;
 
-                return Ok(ir::IrCall {
+                return Ok(ir::Ir::new(
                     span,
-                    target: target.into(),
-                    args,
-                });
+                    ir::IrCall {
+                        span,
+                        target: target.into(),
+                        args,
+                    },
+                ));
             }
         }
 
@@ -378,6 +399,13 @@ impl IrCompile for ast::ExprObject {
     type Output = ir::IrObject;
 
     fn compile(&self, c: &mut IrCompiler<'_>) -> Result<Self::Output, IrError> {
+        if let Some((dot_dot, base)) = &self.base {
+            return Err(IrError::msg(
+                dot_dot.span().join(base.span()),
+                "struct update syntax is not supported in constant expressions",
+            ));
+        }
+
         let mut assignments = Vec::new();
 
         for (assign, _) in &self.assignments {
@@ -530,7 +558,22 @@ impl IrCompile for ast::Path {
             return Ok(ir::Ir::new(span, <Box<str>>::from(name)));
         }
 
-        Err(IrError::msg(span, "not supported yet"))
+        if self.global.is_some() || self.trailing.is_some() {
+            return Err(IrError::msg(span, "not supported yet"));
+        }
+
+        let mut item = Item::new();
+
+        for segment in std::iter::once(&self.first).chain(self.rest.iter().map(|(_, s)| s)) {
+            match segment {
+                ast::PathSegment::Ident(ident) => {
+                    item.push(c.resolve(ident)?.as_ref());
+                }
+                _ => return Err(IrError::msg(segment, "not supported yet")),
+            }
+        }
+
+        Ok(ir::Ir::new(span, item))
     }
 }
 
@@ -540,17 +583,17 @@ impl IrCompile for ast::ExprBreak {
     fn compile(&self, c: &mut IrCompiler<'_>) -> Result<Self::Output, IrError> {
         let span = self.span();
 
-        let kind = match &self.expr {
-            Some(expr) => match expr {
-                ast::ExprBreakValue::Expr(expr) => ir::IrBreakKind::Ir(Box::new(expr.compile(c)?)),
-                ast::ExprBreakValue::Label(label) => {
-                    ir::IrBreakKind::Label(c.resolve(label)?.into())
-                }
-            },
-            None => ir::IrBreakKind::Inherent,
+        let label = match &self.label {
+            Some(label) => Some(c.resolve(label)?.into()),
+            None => None,
+        };
+
+        let expr = match &self.expr {
+            Some(expr) => Some(Box::new(expr.compile(c)?)),
+            None => None,
         };
 
-        Ok(ir::IrBreak { span, kind })
+        Ok(ir::IrBreak { span, label, expr })
     }
 }
 
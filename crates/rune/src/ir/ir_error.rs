@@ -107,6 +107,12 @@ pub enum IrErrorKind {
     /// Exceeded evaluation budget.
     #[error("evaluation budget exceeded")]
     BudgetExceeded,
+    /// Exceeded the maximum depth of nested constant function calls.
+    #[error("constant function calls nested deeper than the maximum of {max}")]
+    CallDepthExceeded {
+        /// The maximum call depth.
+        max: usize,
+    },
     /// Integer underflow.
     #[error("integer underflow")]
     IntegerUnderflow,
@@ -4,17 +4,22 @@ use crate::ir::{IrQuery, IrValue};
 use crate::query::Used;
 use crate::shared::Consts;
 use crate::{IrError, IrErrorKind, Spanned};
-use runestick::{CompileMetaKind, CompileMod, ConstValue, Item, Span};
+use runestick::{CompileMetaKind, CompileMod, ComponentRef, ConstValue, Item, Span};
 use std::sync::Arc;
 
 /// Ir Scopes.
 pub(crate) type IrScopes = crate::shared::Scopes<IrValue>;
 
+/// The maximum depth of nested constant function calls.
+const MAX_CALL_DEPTH: usize = 64;
+
 /// The interpreter that executed [Ir][crate::ir::Ir].
 pub struct IrInterpreter<'a> {
     /// A budget associated with the compiler, for how many expressions it's
     /// allowed to evaluate.
     pub(crate) budget: IrBudget,
+    /// The current depth of nested constant function calls.
+    pub(crate) call_depth: usize,
     /// The module in which the interpreter is run.
     pub(crate) module: Arc<CompileMod>,
     /// The item where the constant expression is located.
@@ -104,19 +109,74 @@ impl IrInterpreter<'_> {
             return Ok(ir_value.clone());
         }
 
+        if let Some(value) = self.lookup_const(spanned, &Item::with_item(&[name]), used)? {
+            return Ok(value);
+        }
+
+        if name.starts_with(char::is_lowercase) {
+            Err(IrError::new(
+                spanned,
+                IrErrorKind::MissingLocal { name: name.into() },
+            ))
+        } else {
+            Err(IrError::new(
+                spanned,
+                IrErrorKind::MissingConst { name: name.into() },
+            ))
+        }
+    }
+
+    /// Resolve a path to a constant, like `module::VALUE`, relative to the
+    /// current item.
+    pub(crate) fn resolve_path(
+        &mut self,
+        spanned: Span,
+        path: &Item,
+        used: Used,
+    ) -> Result<IrValue, IrError> {
+        if let Some(value) = self.lookup_const(spanned, path, used)? {
+            return Ok(value);
+        }
+
+        // NB: the first component might name a crate, in which case the path
+        // refers to a constant provided by the context.
+        if let Some(ComponentRef::Str(name)) = path.first() {
+            let item = Item::with_crate(name).join(path.iter().skip(1));
+
+            if let Some(const_value) = self.consts.get(&item) {
+                return Ok(IrValue::from_const(const_value));
+            }
+        }
+
+        Err(IrError::new(
+            spanned,
+            IrErrorKind::MissingConst {
+                name: path.to_string().into(),
+            },
+        ))
+    }
+
+    /// Look up the constant at `path` in the current item or any of its
+    /// parents, evaluating it if needed.
+    fn lookup_const(
+        &mut self,
+        spanned: Span,
+        path: &Item,
+        used: Used,
+    ) -> Result<Option<IrValue>, IrError> {
         let mut base = self.item.clone();
 
         loop {
-            let item = base.extended(name);
+            let item = base.join(path);
 
             if let Some(const_value) = self.consts.get(&item) {
-                return Ok(IrValue::from_const(const_value));
+                return Ok(Some(IrValue::from_const(const_value)));
             }
 
             if let Some(meta) = self.query.query_meta(spanned, &item, used)? {
                 match &meta.kind {
                     CompileMetaKind::Const { const_value, .. } => {
-                        return Ok(IrValue::from_const(const_value.clone()));
+                        return Ok(Some(IrValue::from_const(const_value.clone())));
                     }
                     _ => {
                         return Err(IrError::new(spanned, IrErrorKind::UnsupportedMeta { meta }));
@@ -125,23 +185,11 @@ impl IrInterpreter<'_> {
             }
 
             if base.is_empty() {
-                break;
+                return Ok(None);
             }
 
             base.pop();
         }
-
-        if name.starts_with(char::is_lowercase) {
-            Err(IrError::new(
-                spanned,
-                IrErrorKind::MissingLocal { name: name.into() },
-            ))
-        } else {
-            Err(IrError::new(
-                spanned,
-                IrErrorKind::MissingConst { name: name.into() },
-            ))
-        }
     }
 
     pub(crate) fn call_const_fn<S>(
@@ -190,13 +238,26 @@ impl IrInterpreter<'_> {
             ));
         }
 
+        if self.call_depth >= MAX_CALL_DEPTH {
+            return Err(IrError::new(
+                spanned,
+                IrErrorKind::CallDepthExceeded {
+                    max: MAX_CALL_DEPTH,
+                },
+            ));
+        }
+
         let guard = self.scopes.isolate();
 
         for (name, value) in const_fn.ir_fn.args.iter().zip(args) {
             self.scopes.decl(&**name, value, spanned)?;
         }
 
-        let value = self.eval_value(&const_fn.ir_fn.ir, used)?;
+        self.call_depth += 1;
+        let value = self.eval_value(&const_fn.ir_fn.ir, used);
+        self.call_depth -= 1;
+
+        let value = value?;
         self.scopes.pop(spanned, guard)?;
         Ok(value)
     }
@@ -20,7 +20,7 @@ pub(crate) use self::ir_interpreter::IrBudget;
 pub(crate) use self::ir_query::IrQuery;
 
 use crate::Spanned;
-use runestick::{ConstValue, Span};
+use runestick::{ConstValue, Item, Span};
 
 macro_rules! decl_kind {
     (
@@ -105,6 +105,8 @@ decl_kind! {
         Template(IrTemplate),
         /// A named value.
         Name(Box<str>),
+        /// A path to a constant, like `module::VALUE`.
+        Path(Item),
         /// A local name. Could either be a local variable or a reference to
         /// something else, like another const declaration.
         Target(IrTarget),
@@ -124,6 +126,8 @@ decl_kind! {
         Object(IrObject),
         /// A call.
         Call(IrCall),
+        /// Query the length of a container.
+        Len(IrLen),
     }
 }
 
@@ -281,19 +285,10 @@ pub struct IrBreak {
     /// The span of the break.
     #[rune(span)]
     pub(crate) span: Span,
-    /// The kind of the break.
-    pub(crate) kind: IrBreakKind,
-}
-
-/// The kind of a break expression.
-#[derive(Debug, Clone)]
-pub enum IrBreakKind {
-    /// Break to the next loop.
-    Inherent,
-    /// Break to the given label.
-    Label(Box<str>),
-    /// Break with the value acquired from evaluating the ir.
-    Ir(Box<Ir>),
+    /// The label of the loop to break out of.
+    pub(crate) label: Option<Box<str>>,
+    /// The value to break with.
+    pub(crate) expr: Option<Box<Ir>>,
 }
 
 /// Tuple expression.
@@ -328,6 +323,16 @@ pub struct IrCall {
     pub(crate) args: Vec<Ir>,
 }
 
+/// Length of a constant container, like `VALUES.len()`.
+#[derive(Debug, Clone, Spanned)]
+pub struct IrLen {
+    /// Span of the length query.
+    #[rune(span)]
+    pub(crate) span: Span,
+    /// The container whose length is being queried.
+    pub(crate) target: Box<Ir>,
+}
+
 /// Vector expression.
 #[derive(Debug, Clone, Spanned)]
 pub struct IrVec {
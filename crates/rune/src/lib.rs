@@ -175,6 +175,7 @@ pub mod ast;
 mod attrs;
 mod compiling;
 mod diagnostics;
+mod disassemble;
 #[cfg(feature = "diagnostics")]
 mod emit_diagnostics;
 mod indexing;
@@ -198,10 +199,12 @@ mod collections {
 }
 
 pub use self::compiling::{
-    BuildError, CompileError, CompileErrorKind, CompileResult, CompileVisitor, ImportEntryStep,
-    LinkerError, NoopCompileVisitor, UnitBuilder,
+    AstRewriter, BuildError, CompileError, CompileErrorKind, CompileOptions, CompileResult,
+    CompileSourcesError, CompileVisitor, ImportEntryStep, LinkerError, NoopCompileVisitor,
+    UnitBuilder,
 };
 pub use self::diagnostics::{Diagnostic, Diagnostics, Error, ErrorKind, Warning, WarningKind};
+pub use self::disassemble::{Disassemble, DisassembledFn, DisassembledInst, Disassembly};
 #[cfg(feature = "diagnostics")]
 pub use self::emit_diagnostics::{
     termcolor, DiagnosticsError, DumpInstructions, EmitDiagnostics, EmitSource,
@@ -217,10 +220,14 @@ pub use self::parsing::{
     Id, Lexer, Parse, ParseError, ParseErrorKind, Parser, Peek, Peeker, Resolve, ResolveError,
     ResolveErrorKind, ResolveOwned,
 };
-pub use self::query::{QueryError, QueryErrorKind, Used};
+pub use self::query::{QueryError, QueryErrorKind, References, Used};
 pub use self::shared::{ScopeError, ScopeErrorKind};
 pub use self::spanned::{OptionSpanned, Spanned};
-pub use compiling::compile;
+pub use self::worker::ParseCache;
+pub use compiling::{
+    compile, compile_with_cache, compile_with_options, compile_with_references,
+    compile_with_rewriter,
+};
 pub use rune_macros::quote;
 
 pub(crate) use rune_macros::{OptionSpanned, Parse, Spanned, ToTokens};
@@ -101,11 +101,14 @@ pub fn load_sources_with_visitor<'a>(
         &unit,
         diagnostics,
         &options,
-        visitor,
-        source_loader,
+        compiling::CompileOptions {
+            visitor: Some(visitor),
+            source_loader: Some(source_loader),
+            ..Default::default()
+        },
     );
 
-    if let Err(()) = result {
+    if let Err(compiling::CompileSourcesError) = result {
         return Err(LoadSourcesError);
     }
 
@@ -1,4 +1,4 @@
-use runestick::{Source, SourceId};
+use runestick::{Location, Source, SourceId};
 use std::sync::Arc;
 
 /// A collection of source files, and a queue of things to compile.
@@ -39,13 +39,57 @@ impl Sources {
         self.sources.get(source_id)
     }
 
+    /// Get the source text which the given location refers to, if the
+    /// location is valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rune::Sources;
+    /// use runestick::{Location, Source, Span};
+    ///
+    /// let mut sources = Sources::new();
+    /// sources.insert(Source::new("a", "let a = 1;"));
+    /// let source_id = sources.insert(Source::new("b", "let b = 2;"));
+    ///
+    /// let location = Location::new(source_id, Span::new(4, 5));
+    /// assert_eq!(sources.source_for_span(location), Some("b"));
+    ///
+    /// let location = Location::new(source_id, Span::new(4, 20));
+    /// assert_eq!(sources.source_for_span(location), None);
+    /// ```
+    pub fn source_for_span(&self, location: Location) -> Option<&str> {
+        self.get(location.source_id)?.source(location.span)
+    }
+
+    /// Iterate over all loaded sources and their ids.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rune::Sources;
+    /// use runestick::Source;
+    ///
+    /// let mut sources = Sources::new();
+    /// sources.insert(Source::new("a", "let a = 1;"));
+    /// sources.insert(Source::new("b", "let b = 2;"));
+    ///
+    /// let names = sources
+    ///     .iter()
+    ///     .map(|(id, source)| (id, source.name()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(names, vec![(0, "a"), (1, "b")]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (SourceId, &Source)> {
+        self.sources
+            .iter()
+            .enumerate()
+            .map(|(source_id, source)| (source_id, &**source))
+    }
+
     /// Get all available source ids.
     pub(crate) fn source_ids(&self) -> impl Iterator<Item = SourceId> {
         0..self.sources.len()
     }
-
-    /// Iterate over all sources in order by index.
-    pub(crate) fn iter(&self) -> impl Iterator<Item = &Source> {
-        self.sources.iter().map(|s| &**s)
-    }
 }
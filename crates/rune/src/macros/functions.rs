@@ -1,7 +1,8 @@
 use crate::ir::{IrCompile, IrError, IrEval};
 use crate::macros::{current_context, ToTokens, TokenStream};
 use crate::parsing::{ResolveError, ResolveOwned};
-use crate::Spanned;
+use crate::{CompileError, Spanned};
+use std::fmt;
 
 /// Evaluate the given target as a constant expression.
 ///
@@ -70,3 +71,52 @@ where
 {
     current_context(|ctx| ctx.stringify(stream).to_string())
 }
+
+/// Render the token stream as a human-readable listing of tokens, annotated
+/// with their spans and kinds.
+///
+/// Unlike [stringify], which reconstructs the source, this shows the
+/// structure of the token stream.
+///
+/// # Panics
+///
+/// This will panic if it's called outside of a macro context.
+pub fn debug_render<T>(stream: &T) -> String
+where
+    T: ToTokens,
+{
+    current_context(|ctx| ctx.debug_render(stream).to_string())
+}
+
+/// Construct a compile error with the given message, pointing at the given
+/// span. Returning it from a macro aborts expansion and reports it as a
+/// regular compile diagnostic.
+///
+/// # Panics
+///
+/// This will panic if it's called outside of a macro context.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{macros, ast, MacroContext};
+///
+/// let ctx = MacroContext::empty();
+///
+/// macros::with_context(ctx, || {
+///     let stream = rune::quote!(42).into_token_stream();
+///
+///     let mut p = rune::Parser::from_token_stream(&stream);
+///     let lit = p.parse_all::<ast::Lit>().unwrap();
+///     let error = macros::error(&lit, "expected a string");
+///
+///     assert_eq!(error.to_string(), "expected a string");
+/// });
+/// ```
+pub fn error<S, M>(spanned: S, message: M) -> CompileError
+where
+    S: Spanned,
+    M: fmt::Display,
+{
+    current_context(|ctx| ctx.error(spanned, message))
+}
@@ -5,19 +5,23 @@ use crate::query::Query;
 use crate::shared::Consts;
 use crate::CompileResult;
 use crate::{
-    ast, CompileError, CompileErrorKind, IrError, Options, Parse, ParseError, Parser, Spanned as _,
+    ast, CompileError, CompileErrorKind, CompileVisitor, IrError, OptionSpanned as _, Options,
+    Parse, ParseError, Parser, Spanned as _,
 };
-use runestick::{CompileItem, Context, Hash, Source};
+use runestick::{CompileItem, Context, Hash, Source, SourceId};
+use std::rc::Rc;
 use std::sync::Arc;
 
 pub(crate) struct MacroCompiler<'a> {
     pub(crate) item: Arc<CompileItem>,
+    pub(crate) source_id: SourceId,
     pub(crate) storage: Storage,
     pub(crate) options: &'a Options,
     pub(crate) context: &'a Context,
     pub(crate) source: Arc<Source>,
     pub(crate) query: Query,
     pub(crate) consts: Consts,
+    pub(crate) visitor: Rc<dyn CompileVisitor>,
 }
 
 impl MacroCompiler<'_> {
@@ -128,9 +132,14 @@ impl MacroCompiler<'_> {
         };
 
         let mut parser = Parser::from_token_stream(&token_stream);
+        parser.set_nesting_limit(self.options.nesting_limit);
         let output = parser.parse::<T>()?;
         parser.eof()?;
 
+        let expanded_span = token_stream.option_span().unwrap_or(span);
+        self.visitor
+            .visit_macro_expansion(self.source_id, span, expanded_span);
+
         Ok(output)
     }
 }
@@ -10,7 +10,7 @@ use crate::parsing::{ResolveError, ResolveOwned};
 use crate::query;
 use crate::query::Used;
 use crate::shared::Consts;
-use crate::{IrError, Spanned};
+use crate::{CompileError, CompileErrorKind, IrError, Spanned};
 use query::Query;
 use runestick::{CompileItem, Source, Span};
 use std::cell::RefCell;
@@ -152,6 +152,7 @@ impl MacroContext {
 
         let mut ir_interpreter = IrInterpreter {
             budget: IrBudget::new(1_000_000),
+            call_depth: 0,
             scopes: Default::default(),
             module: self.item.module.clone(),
             item: self.item.item.clone(),
@@ -181,6 +182,40 @@ impl MacroContext {
         Stringify { ctx: self, stream }
     }
 
+    /// Render the given tokens as a human-readable listing, with one token per
+    /// line annotated with its span and kind.
+    pub fn debug_render<T>(&self, tokens: &T) -> DebugRender<'_>
+    where
+        T: ToTokens,
+    {
+        let mut stream = TokenStream::new();
+        tokens.to_tokens(self, &mut stream);
+        DebugRender { ctx: self, stream }
+    }
+
+    /// Construct a compile error with the given message, pointing at the
+    /// given span. Empty spans, like the ones of tokens produced by
+    /// [quote!][crate::quote], fall back to the span of the whole macro.
+    pub fn error<S, M>(&self, spanned: S, message: M) -> CompileError
+    where
+        S: Spanned,
+        M: fmt::Display,
+    {
+        let span = spanned.span();
+        let span = if span.is_empty() {
+            self.macro_span
+        } else {
+            span
+        };
+
+        CompileError::new(
+            span,
+            CompileErrorKind::MacroError {
+                message: message.to_string().into(),
+            },
+        )
+    }
+
     /// Access span of the whole macro.
     pub fn macro_span(&self) -> Span {
         self.macro_span
@@ -341,3 +376,29 @@ impl<'a> fmt::Display for Stringify<'a> {
         Ok(())
     }
 }
+
+/// A token stream rendered for debugging through
+/// [MacroContext::debug_render].
+pub struct DebugRender<'a> {
+    ctx: &'a MacroContext,
+    stream: TokenStream,
+}
+
+impl<'a> fmt::Display for DebugRender<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in self.stream.iter() {
+            write!(f, "{}..{} {}", token.span.start, token.span.end, token.kind)?;
+
+            // NB: marker tokens have no textual representation.
+            if !matches!(token.kind, ast::Kind::Eof | ast::Kind::Error) {
+                write!(f, " `")?;
+                token.token_fmt(self.ctx, f)?;
+                write!(f, "`")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
@@ -51,6 +51,30 @@ impl TokenStream {
         }
     }
 
+    /// Render the token stream as a human-readable listing, with one token per
+    /// line annotated with its span and kind.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if called outside of a macro context.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rune::macros::{with_context, MacroContext};
+    ///
+    /// with_context(MacroContext::empty(), || {
+    ///     let stream = rune::quote!(let a = 1;).into_token_stream();
+    ///     let output = stream.debug_render();
+    ///
+    ///     assert!(output.contains("let `let`"));
+    ///     assert!(output.contains("number `1`"));
+    /// });
+    /// ```
+    pub fn debug_render(&self) -> String {
+        crate::macros::debug_render(self)
+    }
+
     /// Return something that once formatted will produce a stream of kinds.
     pub fn kinds(&self) -> Kinds<'_> {
         Kinds {
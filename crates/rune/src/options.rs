@@ -9,6 +9,12 @@ pub enum ConfigurationError {
         /// The unsupported option.
         option: String,
     },
+    /// Tried to configure an option with a value it doesn't support.
+    #[error("invalid value for option `{option}`")]
+    InvalidOptionValue {
+        /// The option with an invalid value.
+        option: String,
+    },
 }
 
 /// Compiler options.
@@ -18,6 +24,25 @@ pub struct Options {
     pub(crate) link_checks: bool,
     /// Memoize the instance function in a loop.
     pub(crate) memoize_instance_fn: bool,
+    /// Fold branches whose conditions are constant.
+    pub(crate) fold_constant_conditions: bool,
+    /// Unroll `for` loops over small constant ranges.
+    pub(crate) unroll_loops: bool,
+    /// Propagate constant `let` bindings to where they're used.
+    pub(crate) propagate_constants: bool,
+    /// Require `let mut` for local variables which are reassigned.
+    pub(crate) let_mut: bool,
+    /// Warn about binary operations which mix integer and float literals.
+    pub(crate) warn_mixed_numbers: bool,
+    /// Warn about parentheses which can be removed.
+    pub(crate) warn_redundant_parens: bool,
+    /// Preserve comments in the parsed syntax tree.
+    pub(crate) preserve_comments: bool,
+    /// The maximum nesting depth of expressions when parsing.
+    pub(crate) nesting_limit: usize,
+    /// Call tuple structs and tuple variants without fields when they're
+    /// referenced by value.
+    pub(crate) auto_call_unit_tuples: bool,
     /// Include debug information when compiling.
     pub(crate) debug_info: bool,
     /// Support (experimental) macros.
@@ -45,6 +70,40 @@ impl Options {
             Some("memoize-instance-fn") => {
                 self.memoize_instance_fn = it.next() != Some("false");
             }
+            Some("fold-constant-conditions") => {
+                self.fold_constant_conditions = it.next() != Some("false");
+            }
+            Some("unroll-loops") => {
+                self.unroll_loops = it.next() != Some("false");
+            }
+            Some("propagate-constants") => {
+                self.propagate_constants = it.next() != Some("false");
+            }
+            Some("let-mut") => {
+                self.let_mut = it.next() != Some("false");
+            }
+            Some("warn-mixed-numbers") => {
+                self.warn_mixed_numbers = it.next() != Some("false");
+            }
+            Some("warn-redundant-parens") => {
+                self.warn_redundant_parens = it.next() != Some("false");
+            }
+            Some("preserve-comments") => {
+                self.preserve_comments = it.next() != Some("false");
+            }
+            Some("nesting-limit") => {
+                self.nesting_limit = match it.next().map(str::parse) {
+                    Some(Ok(nesting_limit)) => nesting_limit,
+                    _ => {
+                        return Err(ConfigurationError::InvalidOptionValue {
+                            option: option.to_owned(),
+                        });
+                    }
+                };
+            }
+            Some("auto-call-unit-tuples") => {
+                self.auto_call_unit_tuples = it.next() != Some("false");
+            }
             Some("debug-info") => {
                 self.debug_info = it.next() != Some("false");
             }
@@ -104,6 +163,71 @@ impl Options {
     pub fn memoize_instance_fn(&mut self, enabled: bool) {
         self.memoize_instance_fn = enabled;
     }
+
+    /// Fold `if` and `while` branches whose conditions are constant, like
+    /// `if true { .. }`, as well as `&&` and `||` expressions whose left hand
+    /// side is constant, like `true || value`. Defaults to `false`.
+    pub fn fold_constant_conditions(&mut self, enabled: bool) {
+        self.fold_constant_conditions = enabled;
+    }
+
+    /// Unroll `for` loops over small constant ranges, like `for i in 0..4`, by
+    /// emitting the body once for each value in the range. Defaults to
+    /// `false`.
+    pub fn unroll_loops(&mut self, enabled: bool) {
+        self.unroll_loops = enabled;
+    }
+
+    /// Propagate `let` bindings of constant values, like `let n = 1 + 2;`, to
+    /// where the variable is read, eliminating the binding. Variables which
+    /// are reassigned or captured by a closure are left as-is. Defaults to
+    /// `false`.
+    pub fn propagate_constants(&mut self, enabled: bool) {
+        self.propagate_constants = enabled;
+    }
+
+    /// Require local variables declared with `let` to be marked `let mut` to
+    /// be reassigned. Reassigning an immutable variable is a compile error.
+    /// Defaults to `false`.
+    pub fn let_mut(&mut self, enabled: bool) {
+        self.let_mut = enabled;
+    }
+
+    /// Warn about binary operations which mix an integer literal and a float
+    /// literal, like `1 + 2.0`. Defaults to `false`.
+    pub fn warn_mixed_numbers(&mut self, enabled: bool) {
+        self.warn_mixed_numbers = enabled;
+    }
+
+    /// Warn about parentheses which can be removed without changing how an
+    /// expression is evaluated, like `let a = (1 + 2);` or `1 + (2 * 3)`.
+    /// Defaults to `false`.
+    pub fn warn_redundant_parens(&mut self, enabled: bool) {
+        self.warn_redundant_parens = enabled;
+    }
+
+    /// Preserve comments when parsing source files, attaching them to the
+    /// [File][crate::ast::File] they belong to instead of discarding them.
+    /// Comments have no effect on compilation. Defaults to `false`.
+    pub fn preserve_comments(&mut self, enabled: bool) {
+        self.preserve_comments = enabled;
+    }
+
+    /// Set the maximum depth that expressions can be nested to when parsing.
+    /// Deeper expressions produce a parse error instead of overflowing the
+    /// stack. Defaults to `64`.
+    pub fn nesting_limit(&mut self, nesting_limit: usize) {
+        self.nesting_limit = nesting_limit;
+    }
+
+    /// Construct tuple structs and tuple variants without fields, like `Foo`
+    /// in `struct Foo();`, when they're referenced by value. If disabled,
+    /// referencing them produces their constructor as a function value
+    /// instead, which can be called later like `Foo()`. Patterns matching
+    /// against `Foo` are unaffected. Defaults to `true`.
+    pub fn auto_call_unit_tuples(&mut self, enabled: bool) {
+        self.auto_call_unit_tuples = enabled;
+    }
 }
 
 impl Default for Options {
@@ -111,6 +235,15 @@ impl Default for Options {
         Self {
             link_checks: true,
             memoize_instance_fn: true,
+            fold_constant_conditions: false,
+            unroll_loops: false,
+            propagate_constants: false,
+            let_mut: false,
+            warn_mixed_numbers: false,
+            warn_redundant_parens: false,
+            preserve_comments: false,
+            nesting_limit: crate::parsing::DEFAULT_NESTING_LIMIT,
+            auto_call_unit_tuples: true,
             debug_info: true,
             macros: true,
             bytecode: false,
@@ -13,6 +13,8 @@ pub struct Lexer<'a> {
     modes: LexerModes,
     /// Buffered tokens.
     buffer: VecDeque<ast::Token>,
+    /// If comments should be emitted as tokens.
+    preserve_comments: bool,
 }
 
 impl<'a> Lexer<'a> {
@@ -46,9 +48,35 @@ impl<'a> Lexer<'a> {
             iter: SourceIter::new(source),
             modes: LexerModes::default(),
             buffer: VecDeque::new(),
+            preserve_comments: false,
         }
     }
 
+    /// Configure the lexer to emit comments as [ast::Kind::Comment] tokens
+    /// instead of discarding them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rune::Lexer;
+    /// use rune::ast;
+    /// use runestick::span;
+    ///
+    /// let mut lexer = Lexer::new("// hello\nfn");
+    /// lexer.set_preserve_comments(true);
+    ///
+    /// assert_eq! {
+    ///     lexer.next().unwrap().unwrap(),
+    ///     ast::Token {
+    ///         kind: ast::Kind::Comment,
+    ///         span: span!(0, 8),
+    ///     }
+    /// };
+    /// ```
+    pub fn set_preserve_comments(&mut self, preserve_comments: bool) {
+        self.preserve_comments = preserve_comments;
+    }
+
     /// Access the span of the lexer.
     pub fn span(&self) -> Span {
         self.iter.end_span(0)
@@ -326,6 +354,18 @@ impl<'a> Lexer<'a> {
         while !matches!(self.iter.next(), Some('\n') | None) {}
     }
 
+    /// Consume a line comment, not including the trailing newline.
+    fn next_comment(&mut self, start: usize) -> Result<Option<ast::Token>, ParseError> {
+        while !matches!(self.iter.peek(), Some('\n') | None) {
+            self.iter.next();
+        }
+
+        Ok(Some(ast::Token {
+            kind: ast::Kind::Comment,
+            span: self.iter.span_from(start),
+        }))
+    }
+
     fn template_next(&mut self) -> Result<(), ParseError> {
         use std::mem::take;
 
@@ -517,6 +557,10 @@ impl<'a> Lexer<'a> {
                             break ast::Kind::PipeEq;
                         }
                         ('/', '/') => {
+                            if self.preserve_comments {
+                                return self.next_comment(start);
+                            }
+
                             self.consume_line();
                             continue 'outer;
                         }
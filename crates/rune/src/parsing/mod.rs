@@ -10,6 +10,7 @@ pub use self::lexer::{Lexer, LexerMode};
 pub(crate) use self::opaque::Opaque;
 pub use self::parse::Parse;
 pub use self::parse_error::{ParseError, ParseErrorKind};
+pub(crate) use self::parser::DEFAULT_NESTING_LIMIT;
 pub use self::parser::{Parser, Peeker};
 pub use self::peek::Peek;
 pub use self::resolve::{Resolve, ResolveError, ResolveErrorKind, ResolveOwned};
@@ -110,4 +110,6 @@ pub enum ParseErrorKind {
     BadNumber,
     #[error("can only specify one attribute named `{name}`")]
     MultipleMatchingAttributes { name: &'static str },
+    #[error("expression is nested too deeply, the limit is `{limit}`")]
+    NestingLimitExceeded { limit: usize },
 }
@@ -7,6 +7,13 @@ use std::collections::VecDeque;
 use std::fmt;
 use std::ops;
 
+/// The default limit on how deeply expressions can be nested, see
+/// [Parser::set_nesting_limit].
+///
+/// NB: this is kept conservative, since in debug builds parsing expressions
+/// nested around a hundred levels deep overflows a 2 MiB thread stack.
+pub(crate) const DEFAULT_NESTING_LIMIT: usize = 64;
+
 /// Parser for the rune language.
 ///
 /// # Examples
@@ -22,6 +29,10 @@ pub struct Parser<'a> {
     peeker: Peeker<'a>,
     /// The default span to use in case no better one is available.
     span: Option<Span>,
+    /// The current nesting depth of expressions.
+    depth: usize,
+    /// The maximum nesting depth of expressions.
+    nesting_limit: usize,
 }
 
 impl<'a> Parser<'a> {
@@ -29,6 +40,7 @@ impl<'a> Parser<'a> {
     pub fn new(source: &'a str) -> Self {
         Self::with_source(Source {
             inner: SourceInner::Lexer(Lexer::new(source)),
+            comments: Vec::new(),
         })
     }
 
@@ -66,6 +78,7 @@ impl<'a> Parser<'a> {
     pub fn from_token_stream(token_stream: &'a TokenStream) -> Self {
         Self::with_source(Source {
             inner: SourceInner::TokenStream(token_stream.iter()),
+            comments: Vec::new(),
         })
     }
 
@@ -81,7 +94,98 @@ impl<'a> Parser<'a> {
                 last: None,
             },
             span,
+            depth: 0,
+            nesting_limit: DEFAULT_NESTING_LIMIT,
+        }
+    }
+
+    /// Set the limit on how deeply expressions can be nested before parsing
+    /// fails with [ParseErrorKind::NestingLimitExceeded], instead of
+    /// overflowing the stack.
+    ///
+    /// Defaults to `64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rune::{ast, Parser, ParseErrorKind};
+    ///
+    /// let mut parser = Parser::new("((1))");
+    /// parser.set_nesting_limit(2);
+    /// let error = parser.parse::<ast::Expr>().unwrap_err();
+    /// assert!(matches!(error.kind(), ParseErrorKind::NestingLimitExceeded { limit: 2 }));
+    /// ```
+    pub fn set_nesting_limit(&mut self, nesting_limit: usize) {
+        self.nesting_limit = nesting_limit;
+    }
+
+    /// Configure the parser to preserve comments, which are attached to the
+    /// [File][crate::ast::File] they are part of as trivia.
+    ///
+    /// Comments are otherwise discarded by the lexer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rune::{ast, Parser};
+    ///
+    /// let mut parser = Parser::new("// hello\nfn foo() {}");
+    /// parser.set_preserve_comments(true);
+    /// let file = parser.parse_all::<ast::File>().unwrap();
+    /// assert_eq!(file.comments.len(), 1);
+    /// ```
+    pub fn set_preserve_comments(&mut self, preserve_comments: bool) {
+        if let SourceInner::Lexer(lexer) = &mut self.peeker.source.inner {
+            lexer.set_preserve_comments(preserve_comments);
+        }
+    }
+
+    /// The number of comments collected so far.
+    pub(crate) fn comments_len(&self) -> usize {
+        self.peeker.source.comments.len()
+    }
+
+    /// Take the comments collected since the given number of comments were
+    /// collected, which precede the next token.
+    ///
+    /// Comments which follow the next token might've been collected during
+    /// lookahead, but belong to whatever is parsed next.
+    pub(crate) fn take_comments(&mut self, from: usize) -> Result<Vec<Token>, ParseError> {
+        let next = self.peeker.at(0)?;
+        let comments = &mut self.peeker.source.comments;
+
+        let to = match next {
+            Some(next) => {
+                from + comments[from..]
+                    .iter()
+                    .take_while(|c| c.span.start < next.span.start)
+                    .count()
+            }
+            None => comments.len(),
+        };
+
+        Ok(comments.drain(from..to).collect())
+    }
+
+    /// Parse something which increases the nesting depth, erroring if the
+    /// nesting limit is exceeded.
+    pub(crate) fn nested<T, F>(&mut self, f: F) -> Result<T, ParseError>
+    where
+        F: FnOnce(&mut Self) -> Result<T, ParseError>,
+    {
+        if self.depth >= self.nesting_limit {
+            return Err(ParseError::new(
+                self.span_at(0),
+                ParseErrorKind::NestingLimitExceeded {
+                    limit: self.nesting_limit,
+                },
+            ));
         }
+
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+        result
     }
 
     /// Get the span for the given range offset of tokens.
@@ -271,6 +375,9 @@ impl<'a> Peeker<'a> {
 /// A source adapter.
 pub(crate) struct Source<'a> {
     inner: SourceInner<'a>,
+    /// Comments encountered in the source, which are not part of the token
+    /// stream seen by the parser.
+    comments: Vec<Token>,
 }
 
 impl Source<'_> {
@@ -284,9 +391,18 @@ impl Source<'_> {
 
     /// Get the next token in the stream.
     pub(crate) fn next(&mut self) -> Result<Option<Token>, ParseError> {
-        match &mut self.inner {
-            SourceInner::Lexer(lexer) => lexer.next(),
-            SourceInner::TokenStream(token_stream) => Ok(token_stream.next()),
+        loop {
+            let token = match &mut self.inner {
+                SourceInner::Lexer(lexer) => lexer.next()?,
+                SourceInner::TokenStream(token_stream) => token_stream.next(),
+            };
+
+            match token {
+                Some(token) if token.kind == Kind::Comment => {
+                    self.comments.push(token);
+                }
+                token => return Ok(token),
+            }
         }
     }
 }
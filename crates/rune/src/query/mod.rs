@@ -35,6 +35,59 @@ pub(crate) enum BuiltInMacro {
     Line(BuiltInLine),
 }
 
+/// The conditions under which a function unconditionally diverges.
+///
+/// Calls are recorded by path and resolved lazily, since the items they refer
+/// to might not have been indexed yet.
+#[derive(Debug, Clone)]
+pub(crate) enum Diverges {
+    /// Never diverges.
+    Never,
+    /// Always diverges, like a loop which is never broken out of.
+    Always,
+    /// Diverges if the called function diverges.
+    Call(Box<ast::Path>),
+    /// Diverges if any of the conditions diverge.
+    Any(Vec<Diverges>),
+    /// Diverges if all of the conditions diverge.
+    All(Vec<Diverges>),
+}
+
+impl Diverges {
+    /// Diverges if any of the given conditions diverge.
+    pub(crate) fn any(mut any: Vec<Diverges>) -> Self {
+        match any.len() {
+            0 => Self::Never,
+            1 => any.remove(0),
+            _ => Self::Any(any),
+        }
+    }
+
+    /// Diverges if all of the given conditions diverge.
+    pub(crate) fn all(all: Vec<Diverges>) -> Self {
+        if all.iter().any(|d| matches!(d, Self::Never)) {
+            return Self::Never;
+        }
+
+        if all.iter().all(|d| matches!(d, Self::Always)) {
+            return Self::Always;
+        }
+
+        Self::All(all)
+    }
+}
+
+/// The state of resolving if a function diverges.
+#[derive(Clone)]
+enum DivergingEntry {
+    /// Not yet resolved, with the source the conditions were recorded in.
+    Unresolved(Arc<Source>, Diverges),
+    /// Currently being resolved.
+    Resolving,
+    /// Resolved to diverge or not.
+    Resolved(bool),
+}
+
 /// An internally resolved template.
 pub(crate) struct BuiltInTemplate {
     /// The span of the built-in template.
@@ -134,6 +187,10 @@ impl Query {
                 items: HashMap::new(),
                 names: Names::default(),
                 modules: HashMap::new(),
+                references: HashMap::new(),
+                non_constant: HashSet::new(),
+                loop_control: HashSet::new(),
+                diverging: HashMap::new(),
             })),
         }
     }
@@ -387,13 +444,14 @@ impl Query {
         item: &Arc<CompileItem>,
         source: &Arc<Source>,
         ast: Box<ast::ItemStruct>,
+        repr: bool,
     ) -> Result<(), QueryError> {
         log::trace!("new struct: {:?}", item.item);
 
         self.inner.borrow_mut().index(IndexedEntry {
             item: item.clone(),
             source: source.clone(),
-            indexed: Indexed::Struct(Struct::new(ast)),
+            indexed: Indexed::Struct(Struct::new(ast, repr)),
         });
 
         Ok(())
@@ -613,6 +671,155 @@ impl Query {
         let mut inner = self.inner.borrow_mut();
         inner.import(span, module, item, used)
     }
+
+    /// Record that the given item is referenced at the given location.
+    pub(crate) fn insert_reference(&self, item: &Item, location: Location) {
+        self.inner
+            .borrow_mut()
+            .references
+            .entry(item.clone())
+            .or_default()
+            .push(location);
+    }
+
+    /// Mark that the local variable declared at the given span is reassigned
+    /// or captured, so it can't be propagated as a constant.
+    pub(crate) fn mark_non_constant(&self, source_id: SourceId, span: Span) {
+        self.inner
+            .borrow_mut()
+            .non_constant
+            .insert((source_id, span));
+    }
+
+    /// Test if the local variable declared at the given span has been marked
+    /// through [mark_non_constant][Query::mark_non_constant].
+    pub(crate) fn is_non_constant(&self, source_id: SourceId, span: Span) -> bool {
+        self.inner
+            .borrow()
+            .non_constant
+            .contains(&(source_id, span))
+    }
+
+    /// Mark that the loop at the given span is the target of a `break` or
+    /// `continue`.
+    pub(crate) fn mark_loop_control(&self, source_id: SourceId, span: Span) {
+        self.inner
+            .borrow_mut()
+            .loop_control
+            .insert((source_id, span));
+    }
+
+    /// Test if the loop at the given span has been marked through
+    /// [mark_loop_control][Query::mark_loop_control].
+    pub(crate) fn has_loop_control(&self, source_id: SourceId, span: Span) -> bool {
+        self.inner
+            .borrow()
+            .loop_control
+            .contains(&(source_id, span))
+    }
+
+    /// Record the conditions under which the given function unconditionally
+    /// diverges, to be resolved through [is_diverging][Query::is_diverging]
+    /// once all items have been indexed.
+    pub(crate) fn insert_diverges(&self, item: &Item, source: &Arc<Source>, diverges: Diverges) {
+        if let Diverges::Never = diverges {
+            return;
+        }
+
+        self.inner.borrow_mut().diverging.insert(
+            item.clone(),
+            DivergingEntry::Unresolved(source.clone(), diverges),
+        );
+    }
+
+    /// Test if the given function unconditionally diverges, and therefore
+    /// never returns a value to its caller.
+    pub(crate) fn is_diverging(&self, context: &Context, item: &Item) -> bool {
+        self.inner.borrow_mut().is_diverging(context, item)
+    }
+
+    /// Take a snapshot of all references recorded so far.
+    pub(crate) fn references(&self) -> References {
+        let inner = self.inner.borrow();
+
+        let definitions = inner
+            .meta
+            .iter()
+            .filter_map(|(item, meta)| {
+                let source = meta.source.as_ref()?;
+                Some((item.clone(), Location::new(source.source_id, source.span)))
+            })
+            .collect();
+
+        References {
+            references: inner.references.clone(),
+            definitions,
+        }
+    }
+}
+
+/// A reverse index of every location an item is referenced from, as collected
+/// during compilation.
+///
+/// This is produced by [compile_with_references][crate::compile_with_references],
+/// or by [compile_with_options][crate::compile_with_options] through
+/// [CompileOptions::references][crate::CompileOptions::references].
+#[derive(Debug, Default, Clone)]
+pub struct References {
+    references: HashMap<Item, Vec<Location>>,
+    definitions: HashMap<Item, Location>,
+}
+
+impl References {
+    /// Get all locations where the given item is referenced, in the order they
+    /// were encountered.
+    pub fn find(&self, item: &Item) -> &[Location] {
+        match self.references.get(item) {
+            Some(locations) => locations,
+            None => &[],
+        }
+    }
+
+    /// Get the location where the given item is defined, if it's defined in
+    /// one of the compiled sources.
+    pub fn definition(&self, item: &Item) -> Option<Location> {
+        self.definitions.get(item).copied()
+    }
+
+    /// Find the item referenced at the given byte offset in a source, together
+    /// with the location where it's defined.
+    ///
+    /// If multiple references overlap the offset, the narrowest one is used.
+    /// This is the basis of go-to-definition.
+    pub fn item_at(&self, source_id: SourceId, offset: usize) -> Option<(&Item, Location)> {
+        let mut found = None::<(&Item, Span)>;
+
+        for (item, locations) in &self.references {
+            for location in locations {
+                if location.source_id != source_id || !location.span.range().contains(&offset) {
+                    continue;
+                }
+
+                if let Some((_, span)) = found {
+                    if span.len() <= location.span.len() {
+                        continue;
+                    }
+                }
+
+                found = Some((item, location.span));
+            }
+        }
+
+        let (item, _) = found?;
+        Some((item, self.definition(item)?))
+    }
+
+    /// Iterate over all referenced items and their locations.
+    pub fn iter(&self) -> impl Iterator<Item = (&Item, &[Location])> {
+        self.references
+            .iter()
+            .map(|(item, locations)| (item, locations.as_slice()))
+    }
 }
 
 #[derive(Clone)]
@@ -652,6 +859,17 @@ struct QueryInner {
     names: Names,
     /// Modules and associated metadata.
     modules: HashMap<Item, Arc<CompileMod>>,
+    /// Reverse index of every location where an item has been referenced.
+    references: HashMap<Item, Vec<Location>>,
+    /// Declarations of local variables which are reassigned or captured by a
+    /// closure, and therefore can't be propagated as constants.
+    non_constant: HashSet<(SourceId, Span)>,
+    /// Loops which are the target of a `break` or `continue`, and therefore
+    /// can't be unrolled.
+    loop_control: HashSet<(SourceId, Span)>,
+    /// The conditions under which functions unconditionally diverge, and
+    /// therefore never return a value to their caller.
+    diverging: HashMap<Item, DivergingEntry>,
 }
 
 impl Default for QueryInner {
@@ -672,11 +890,70 @@ impl Default for QueryInner {
             items: Default::default(),
             names: Default::default(),
             modules: Default::default(),
+            references: Default::default(),
+            non_constant: Default::default(),
+            loop_control: Default::default(),
+            diverging: Default::default(),
         }
     }
 }
 
 impl QueryInner {
+    /// Test if the given function unconditionally diverges, resolving the
+    /// conditions recorded for it on first use.
+    fn is_diverging(&mut self, context: &Context, item: &Item) -> bool {
+        let entry = match self.diverging.get_mut(item) {
+            Some(entry) => entry,
+            None => return false,
+        };
+
+        let (source, diverges) = match std::mem::replace(entry, DivergingEntry::Resolving) {
+            DivergingEntry::Unresolved(source, diverges) => (source, diverges),
+            DivergingEntry::Resolved(diverging) => {
+                *entry = DivergingEntry::Resolved(diverging);
+                return diverging;
+            }
+            // NB: a function which recursively calls itself is not considered
+            // to diverge.
+            DivergingEntry::Resolving => return false,
+        };
+
+        let diverging = self.resolve_diverges(context, &source, &diverges);
+        self.diverging
+            .insert(item.clone(), DivergingEntry::Resolved(diverging));
+        diverging
+    }
+
+    /// Resolve if the given conditions for divergence hold.
+    fn resolve_diverges(
+        &mut self,
+        context: &Context,
+        source: &Source,
+        diverges: &Diverges,
+    ) -> bool {
+        match diverges {
+            Diverges::Never => false,
+            Diverges::Always => true,
+            Diverges::Call(path) => {
+                let storage = self.storage.clone();
+
+                let named = match self.convert_path(context, &storage, source, path) {
+                    Ok(named) => named,
+                    Err(..) => return false,
+                };
+
+                named.item == Item::with_crate_item("std", &["panic"])
+                    || self.is_diverging(context, &named.item)
+            }
+            Diverges::Any(any) => any
+                .iter()
+                .any(|diverges| self.resolve_diverges(context, source, diverges)),
+            Diverges::All(all) => all
+                .iter()
+                .all(|diverges| self.resolve_diverges(context, source, diverges)),
+        }
+    }
+
     /// Get the item for the given identifier.
     fn item_for(&self, span: Span, id: Option<Id>) -> Result<Arc<CompileItem>, QueryError> {
         let item = id
@@ -1226,10 +1503,17 @@ impl QueryInner {
                     &*source,
                 )?
             }
-            Indexed::Struct(st) => {
-                struct_into_item_decl(&query_item.item, st.ast.body, None, &self.storage, &*source)?
-            }
+            Indexed::Struct(st) => struct_into_item_decl(
+                &query_item.item,
+                st.ast.body,
+                None,
+                st.repr,
+                &self.storage,
+                &*source,
+            )?,
             Indexed::Function(f) => {
+                let return_arity = f.ast.return_arity();
+
                 self.queue.push_back(BuildEntry {
                     location: query_item.location,
                     item: query_item.clone(),
@@ -1241,6 +1525,7 @@ impl QueryInner {
                 CompileMetaKind::Function {
                     type_hash: Hash::type_hash(&query_item.item),
                     is_test: false,
+                    return_arity,
                 }
             }
             Indexed::Closure(c) => {
@@ -1282,6 +1567,7 @@ impl QueryInner {
             Indexed::Const(c) => {
                 let mut const_compiler = IrInterpreter {
                     budget: IrBudget::new(1_000_000),
+                    call_depth: 0,
                     scopes: Default::default(),
                     module: c.module.clone(),
                     item: query_item.item.clone(),
@@ -1484,12 +1770,14 @@ pub struct Import {
 pub struct Struct {
     /// The ast of the struct.
     ast: Box<ast::ItemStruct>,
+    /// If the struct has a `#[repr]` layout hint.
+    repr: bool,
 }
 
 impl Struct {
     /// Construct a new struct entry.
-    pub fn new(ast: Box<ast::ItemStruct>) -> Self {
-        Self { ast }
+    pub fn new(ast: Box<ast::ItemStruct>, repr: bool) -> Self {
+        Self { ast, repr }
     }
 }
 
@@ -1513,6 +1801,10 @@ pub(crate) struct Function {
     /// Ast for declaration.
     pub(crate) ast: Box<ast::ItemFn>,
     pub(crate) call: Call,
+    /// If errors in the function should be reported at its caller.
+    pub(crate) track_caller: bool,
+    /// If the function is marked with `#[test]`.
+    pub(crate) is_test: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -1525,6 +1817,8 @@ pub(crate) struct InstanceFunction {
     pub(crate) instance_span: Span,
     /// Calling convention of the instance function.
     pub(crate) call: Call,
+    /// If errors in the function should be reported at its caller.
+    pub(crate) track_caller: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -1700,17 +1994,29 @@ fn struct_body_meta(
     storage: &Storage,
     source: &Source,
     st: ast::Braced<ast::Field, T![,]>,
+    repr: bool,
 ) -> Result<CompileMetaKind, QueryError> {
     let type_hash = Hash::type_hash(item);
 
     let mut fields = HashSet::new();
+    let mut ordered_fields = Vec::new();
 
     for (ast::Field { name, .. }, _) in st {
-        let name = name.resolve(&storage, &*source)?;
-        fields.insert(name.into());
+        let name: Box<str> = name.resolve(&storage, &*source)?.into();
+        fields.insert(name.clone());
+        ordered_fields.push(name);
     }
 
-    let object = CompileMetaStruct { fields };
+    let ordered_fields = if repr {
+        Some(ordered_fields.into())
+    } else {
+        None
+    };
+
+    let object = CompileMetaStruct {
+        fields,
+        ordered_fields,
+    };
 
     Ok(match enum_item {
         Some(enum_item) => CompileMetaKind::StructVariant {
@@ -1734,7 +2040,7 @@ fn variant_into_item_decl(
         ast::ItemVariantBody::UnitBody => unit_body_meta(item, enum_item),
         ast::ItemVariantBody::TupleBody(tuple) => tuple_body_meta(item, enum_item, tuple),
         ast::ItemVariantBody::StructBody(st) => {
-            struct_body_meta(item, enum_item, storage, source, st)?
+            struct_body_meta(item, enum_item, storage, source, st, false)?
         }
     })
 }
@@ -1744,6 +2050,7 @@ fn struct_into_item_decl(
     item: &Item,
     body: ast::ItemStructBody,
     enum_item: Option<&Item>,
+    repr: bool,
     storage: &Storage,
     source: &Source,
 ) -> Result<CompileMetaKind, QueryError> {
@@ -1751,7 +2058,7 @@ fn struct_into_item_decl(
         ast::ItemStructBody::UnitBody => unit_body_meta(item, enum_item),
         ast::ItemStructBody::TupleBody(tuple) => tuple_body_meta(item, enum_item, tuple),
         ast::ItemStructBody::StructBody(st) => {
-            struct_body_meta(item, enum_item, storage, source, st)?
+            struct_body_meta(item, enum_item, storage, source, st, repr)?
         }
     })
 }
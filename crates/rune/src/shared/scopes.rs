@@ -132,6 +132,14 @@ impl<T> Scopes<T> {
         Ok(())
     }
 
+    /// Pop any scopes nested inside of the scope associated with the guard.
+    ///
+    /// This is used to clean up after control flow like `break`, which skips
+    /// over the regular popping of nested scopes.
+    pub(crate) fn pop_nested(&mut self, guard: &ScopeGuard) {
+        self.scopes.truncate(guard.length + 1);
+    }
+
     /// Get the last scope mutably.
     pub(crate) fn last_mut(&mut self) -> Option<&mut Scope<T>> {
         self.scopes.last_mut()
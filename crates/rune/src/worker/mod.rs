@@ -1,20 +1,24 @@
 //! Worker used by compiler.
 
 use crate::ast;
-use crate::collections::HashMap;
+use crate::collections::{HashMap, HashSet};
 use crate::indexing::{Index as _, IndexScopes, Indexer};
 use crate::query::Query;
 use crate::shared::{Consts, Gen, Items};
-use crate::{CompileVisitor, Diagnostics, Options, SourceLoader, Sources, Storage, UnitBuilder};
-use runestick::{Context, Item, SourceId, Span};
+use crate::{
+    CompileVisitor, Diagnostics, Options, Parser, SourceLoader, Sources, Storage, UnitBuilder,
+};
+use runestick::{Context, Item, Source, SourceId, Span};
 use std::collections::VecDeque;
 use std::rc::Rc;
 
 mod import;
+mod parse_cache;
 mod task;
 mod wildcard_import;
 
 pub(crate) use self::import::Import;
+pub use self::parse_cache::ParseCache;
 pub(crate) use self::task::{LoadFileKind, Task};
 pub(crate) use self::wildcard_import::WildcardImport;
 
@@ -25,6 +29,8 @@ pub(crate) struct Worker<'a> {
     pub(crate) diagnostics: &'a mut Diagnostics,
     pub(crate) visitor: Rc<dyn CompileVisitor>,
     pub(crate) source_loader: Rc<dyn SourceLoader + 'a>,
+    /// Cache of previously parsed modules.
+    cache: Option<&'a ParseCache>,
     /// Constants storage.
     pub(crate) consts: Consts,
     /// Worker queue.
@@ -52,6 +58,7 @@ impl<'a> Worker<'a> {
         source_loader: Rc<dyn SourceLoader + 'a>,
         storage: Storage,
         gen: Gen,
+        cache: Option<&'a ParseCache>,
     ) -> Self {
         Self {
             context,
@@ -60,6 +67,7 @@ impl<'a> Worker<'a> {
             diagnostics,
             visitor: visitor.clone(),
             source_loader,
+            cache,
             consts: consts.clone(),
             queue: VecDeque::new(),
             query: Query::new(visitor, storage.clone(), unit, consts, gen.clone()),
@@ -93,12 +101,9 @@ impl<'a> Worker<'a> {
                         }
                     };
 
-                    let mut file = match crate::parse_all::<ast::File>(source.as_str()) {
-                        Ok(file) => file,
-                        Err(error) => {
-                            self.diagnostics.error(source_id, error);
-                            continue;
-                        }
+                    let mut file = match self.parse_file(source_id, &source) {
+                        Some(file) => file,
+                        None => continue,
                     };
 
                     let root = match kind {
@@ -129,6 +134,10 @@ impl<'a> Worker<'a> {
                         visitor: self.visitor.clone(),
                         source_loader: self.source_loader.clone(),
                         nested_item: None,
+                        has_break: false,
+                        infinite_loops: HashSet::new(),
+                        loops: Vec::new(),
+                        local_calls: HashSet::new(),
                     };
 
                     if let Err(error) = file.index(&mut indexer) {
@@ -162,6 +171,39 @@ impl<'a> Worker<'a> {
             }
         }
     }
+
+    /// Parse the given source, reusing a cached syntax tree if the source is
+    /// unchanged since it was last parsed.
+    fn parse_file(&mut self, source_id: SourceId, source: &Source) -> Option<ast::File> {
+        let preserve_comments = self.options.preserve_comments;
+        let nesting_limit = self.options.nesting_limit;
+
+        if let Some(file) = self
+            .cache
+            .and_then(|cache| cache.get(source_id, source, preserve_comments, nesting_limit))
+        {
+            log::trace!("reusing parsed file: {}", source.name());
+            return Some(file);
+        }
+
+        let mut parser = Parser::new(source.as_str());
+        parser.set_preserve_comments(preserve_comments);
+        parser.set_nesting_limit(nesting_limit);
+
+        let file = match parser.parse_all::<ast::File>() {
+            Ok(file) => file,
+            Err(error) => {
+                self.diagnostics.error(source_id, error);
+                return None;
+            }
+        };
+
+        if let Some(cache) = self.cache {
+            cache.insert(source_id, source, preserve_comments, nesting_limit, &file);
+        }
+
+        Some(file)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -0,0 +1,141 @@
+use crate::ast;
+use crate::collections::HashMap;
+use runestick::{Source, SourceId};
+use std::cell::{Cell, RefCell};
+
+/// A cache of parsed modules which can be shared across compilations.
+///
+/// Modules are keyed by their [SourceId], and a cached syntax tree is only
+/// reused if the source text of the module is unchanged since it was last
+/// parsed. This means that when recompiling after a single module has been
+/// edited, only that module has to be parsed again, as long as sources are
+/// inserted in the same order.
+///
+/// Only parsing is cached. Every module is still indexed and compiled from
+/// scratch on each compilation.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{Diagnostics, FileSourceLoader, NoopCompileVisitor, Options, ParseCache};
+/// use rune::{Sources, UnitBuilder};
+/// use runestick::{Context, Source};
+/// use std::rc::Rc;
+///
+/// # fn main() -> runestick::Result<()> {
+/// let context = Context::with_default_modules()?;
+/// let cache = ParseCache::new();
+///
+/// for _ in 0..2 {
+///     let mut sources = Sources::new();
+///     sources.insert(Source::new("entry", "pub fn main() { 42 }"));
+///
+///     let unit = UnitBuilder::with_default_prelude();
+///     let mut diagnostics = Diagnostics::new();
+///
+///     rune::compile_with_cache(
+///         &context,
+///         &mut sources,
+///         &unit,
+///         &mut diagnostics,
+///         &Options::default(),
+///         Rc::new(NoopCompileVisitor::new()),
+///         Rc::new(FileSourceLoader::new()),
+///         &cache,
+///     ).expect("compilation to succeed");
+/// }
+///
+/// assert_eq!(cache.misses(), 1);
+/// assert_eq!(cache.hits(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct ParseCache {
+    entries: RefCell<HashMap<SourceId, Entry>>,
+    hits: Cell<usize>,
+    misses: Cell<usize>,
+}
+
+impl ParseCache {
+    /// Construct a new empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of modules whose syntax tree has been reused from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits.get()
+    }
+
+    /// The number of modules which had to be parsed because they were either
+    /// missing from the cache or had changed.
+    pub fn misses(&self) -> usize {
+        self.misses.get()
+    }
+
+    /// Remove all cached modules.
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+
+    /// Get a previously parsed syntax tree for the given source, if its text is
+    /// unchanged.
+    pub(crate) fn get(
+        &self,
+        source_id: SourceId,
+        source: &Source,
+        preserve_comments: bool,
+        nesting_limit: usize,
+    ) -> Option<ast::File> {
+        let entries = self.entries.borrow();
+
+        let entry = match entries.get(&source_id) {
+            Some(entry)
+                if entry.preserve_comments == preserve_comments
+                    && entry.nesting_limit == nesting_limit
+                    && &*entry.text == source.as_str() =>
+            {
+                entry
+            }
+            _ => {
+                self.misses.set(self.misses.get() + 1);
+                return None;
+            }
+        };
+
+        self.hits.set(self.hits.get() + 1);
+        Some(entry.file.clone())
+    }
+
+    /// Store the freshly parsed syntax tree for the given source.
+    pub(crate) fn insert(
+        &self,
+        source_id: SourceId,
+        source: &Source,
+        preserve_comments: bool,
+        nesting_limit: usize,
+        file: &ast::File,
+    ) {
+        self.entries.borrow_mut().insert(
+            source_id,
+            Entry {
+                text: source.as_str().into(),
+                preserve_comments,
+                nesting_limit,
+                file: file.clone(),
+            },
+        );
+    }
+}
+
+struct Entry {
+    /// The text the syntax tree was parsed from.
+    text: Box<str>,
+    /// If comments were preserved when parsing.
+    preserve_comments: bool,
+    /// The nesting limit used when parsing.
+    nesting_limit: usize,
+    /// The parsed, unindexed syntax tree.
+    file: ast::File,
+}
@@ -169,6 +169,10 @@ pub enum CompileMetaKind {
 
         /// Whether this function has a test annotation
         is_test: bool,
+
+        /// The number of values returned by the function, if it declares a
+        /// tuple return type like `-> (int, int)`.
+        return_arity: Option<usize>,
     },
     /// A closure.
     Closure {
@@ -224,6 +228,8 @@ pub struct CompileMetaEmpty {
 pub struct CompileMetaStruct {
     /// Fields associated with the type.
     pub fields: HashSet<Box<str>>,
+    /// Fields in declaration order, if the type has a `#[repr]` layout hint.
+    pub ordered_fields: Option<Box<[Box<str>]>>,
 }
 
 /// The metadata about a variant.
@@ -0,0 +1,423 @@
+//! Stable hashing of serializable data.
+
+use crate::Hash;
+use serde::ser::{self, Serialize};
+use std::fmt;
+use std::hash::{BuildHasher as _, BuildHasherDefault, Hasher as _};
+use twox_hash::XxHash64;
+
+/// Calculate a hash of the serialized form of the given value.
+///
+/// Maps are hashed independently of the order their entries are visited in,
+/// so the hash doesn't depend on the iteration order of hash maps.
+pub(crate) fn content_hash<T>(value: &T) -> Result<Hash, ContentHashError>
+where
+    T: ?Sized + Serialize,
+{
+    let mut hasher = ContentHasher::new();
+    value.serialize(&mut hasher)?;
+    Ok(Hash::new(hasher.hasher.finish()))
+}
+
+/// Error raised by a serialize implementation while hashing.
+#[derive(Debug)]
+pub(crate) struct ContentHashError(String);
+
+impl fmt::Display for ContentHashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for ContentHashError {}
+
+impl ser::Error for ContentHashError {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: fmt::Display,
+    {
+        Self(msg.to_string())
+    }
+}
+
+const UNIT: u8 = 0;
+const BOOL: u8 = 1;
+const INTEGER: u8 = 2;
+const UNSIGNED: u8 = 3;
+const FLOAT: u8 = 4;
+const CHAR: u8 = 5;
+const STR: u8 = 6;
+const BYTES: u8 = 7;
+const NONE: u8 = 8;
+const SOME: u8 = 9;
+const VARIANT: u8 = 10;
+const SEQ: u8 = 11;
+const SEQ_END: u8 = 12;
+const MAP: u8 = 13;
+
+struct ContentHasher {
+    hasher: XxHash64,
+}
+
+impl ContentHasher {
+    fn new() -> Self {
+        Self {
+            hasher: BuildHasherDefault::<XxHash64>::default().build_hasher(),
+        }
+    }
+
+    fn write_str(&mut self, tag: u8, bytes: &[u8]) {
+        self.hasher.write_u8(tag);
+        self.hasher.write_usize(bytes.len());
+        self.hasher.write(bytes);
+    }
+
+    fn write_variant(&mut self, variant_index: u32) {
+        self.hasher.write_u8(VARIANT);
+        self.hasher.write_u32(variant_index);
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut ContentHasher {
+    type Ok = ();
+    type Error = ContentHashError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = MapHasher<'a>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, v: bool) -> Result<(), Self::Error> {
+        self.hasher.write_u8(BOOL);
+        self.hasher.write_u8(v as u8);
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), Self::Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), Self::Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), Self::Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), Self::Error> {
+        self.hasher.write_u8(INTEGER);
+        self.hasher.write_i64(v);
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), Self::Error> {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), Self::Error> {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), Self::Error> {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), Self::Error> {
+        self.hasher.write_u8(UNSIGNED);
+        self.hasher.write_u64(v);
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), Self::Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), Self::Error> {
+        self.hasher.write_u8(FLOAT);
+        self.hasher.write_u64(v.to_bits());
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), Self::Error> {
+        self.hasher.write_u8(CHAR);
+        self.hasher.write_u32(v as u32);
+        Ok(())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), Self::Error> {
+        self.write_str(STR, v.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Self::Error> {
+        self.write_str(BYTES, v);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), Self::Error> {
+        self.hasher.write_u8(NONE);
+        Ok(())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.hasher.write_u8(SOME);
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Self::Error> {
+        self.hasher.write_u8(UNIT);
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        variant_index: u32,
+        _: &'static str,
+    ) -> Result<(), Self::Error> {
+        self.write_variant(variant_index);
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _: &'static str,
+        variant_index: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.write_variant(variant_index);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        self.hasher.write_u8(SEQ);
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.hasher.write_u8(SEQ);
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.hasher.write_u8(SEQ);
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        variant_index: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        self.write_variant(variant_index);
+        self.hasher.write_u8(SEQ);
+        Ok(self)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(MapHasher {
+            parent: self,
+            entry: None,
+            sum: 0,
+            len: 0,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.hasher.write_u8(SEQ);
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        variant_index: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.write_variant(variant_index);
+        self.hasher.write_u8(SEQ);
+        Ok(self)
+    }
+}
+
+impl ser::SerializeSeq for &mut ContentHasher {
+    type Ok = ();
+    type Error = ContentHashError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        self.hasher.write_u8(SEQ_END);
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for &mut ContentHasher {
+    type Ok = ();
+    type Error = ContentHashError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        self.hasher.write_u8(SEQ_END);
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut ContentHasher {
+    type Ok = ();
+    type Error = ContentHashError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        self.hasher.write_u8(SEQ_END);
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for &mut ContentHasher {
+    type Ok = ();
+    type Error = ContentHashError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        self.hasher.write_u8(SEQ_END);
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for &mut ContentHasher {
+    type Ok = ();
+    type Error = ContentHashError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.write_str(STR, key.as_bytes());
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        self.hasher.write_u8(SEQ_END);
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for &mut ContentHasher {
+    type Ok = ();
+    type Error = ContentHashError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.write_str(STR, key.as_bytes());
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        self.hasher.write_u8(SEQ_END);
+        Ok(())
+    }
+}
+
+/// Hashes each entry of a map separately and combines them with a
+/// commutative operation, so that the order of the entries doesn't matter.
+struct MapHasher<'a> {
+    parent: &'a mut ContentHasher,
+    entry: Option<ContentHasher>,
+    sum: u64,
+    len: usize,
+}
+
+impl ser::SerializeMap for MapHasher<'_> {
+    type Ok = ();
+    type Error = ContentHashError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let mut entry = ContentHasher::new();
+        key.serialize(&mut entry)?;
+        self.entry = Some(entry);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let mut entry = match self.entry.take() {
+            Some(entry) => entry,
+            None => return Err(ser::Error::custom("map value without a key")),
+        };
+
+        value.serialize(&mut entry)?;
+        self.sum = self.sum.wrapping_add(entry.hasher.finish());
+        self.len += 1;
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        self.parent.hasher.write_u8(MAP);
+        self.parent.hasher.write_u64(self.sum);
+        self.parent.hasher.write_usize(self.len);
+        Ok(())
+    }
+}
@@ -11,6 +11,22 @@ use std::{any, fmt, sync::Arc};
 
 use thiserror::Error;
 
+/// An instance function which requires protocols that are not registered for
+/// its instance type.
+#[derive(Debug, Error)]
+#[error(
+    "instance function `{name}` for type `{type_info}` requires missing protocols: {}",
+    protocols.iter().map(|p| format!("`{}`", p)).collect::<Vec<_>>().join(", ")
+)]
+pub struct FunctionMissingProtocols {
+    /// The instance type.
+    pub type_info: TypeInfo,
+    /// The name of the instance function.
+    pub name: String,
+    /// The protocols which are missing.
+    pub protocols: Vec<Protocol>,
+}
+
 /// An error raised when building the context.
 #[derive(Debug, Error)]
 pub enum ContextError {
@@ -96,6 +112,23 @@ pub enum ContextError {
         /// The instance type.
         instance_type: TypeInfo,
     },
+    /// Error raised when instance functions require protocols which are not
+    /// registered for their instance types.
+    #[error(
+        "{}",
+        functions.iter().map(|f| f.to_string()).collect::<Vec<_>>().join("; ")
+    )]
+    MissingProtocols {
+        /// Every instance function which is missing protocols.
+        functions: Vec<FunctionMissingProtocols>,
+    },
+    /// Error raised when installing a module which is not allowed in the
+    /// context.
+    #[error("module `{item}` is not allowed in this context")]
+    ModuleNotAllowed {
+        /// The item of the module.
+        item: Item,
+    },
     /// Error raised when attempting to create a constant value.
     #[error("error when converting to constant value: {error}")]
     ValueError {
@@ -240,6 +273,8 @@ pub struct Context {
     crates: HashSet<Box<str>>,
     /// Constants visible in this context
     constants: HashMap<Hash, ConstValue>,
+    /// Module prefixes which are allowed to be installed, if restricted.
+    allowed_modules: Option<Vec<Item>>,
 }
 
 impl Context {
@@ -254,31 +289,74 @@ impl Context {
     ///   stdout and stderr by default, like `dbg`, `print`, and `println`.
     pub fn with_config(stdio: bool) -> Result<Self, ContextError> {
         let mut this = Self::new();
-        this.install(&crate::modules::any::module()?)?;
-        this.install(&crate::modules::bytes::module()?)?;
-        this.install(&crate::modules::char::module()?)?;
-        this.install(&crate::modules::cmp::module()?)?;
-        this.install(&crate::modules::collections::module()?)?;
-        this.install(&crate::modules::core::module()?)?;
-        this.install(&crate::modules::float::module()?)?;
-        this.install(&crate::modules::fmt::module()?)?;
-        this.install(&crate::modules::future::module()?)?;
-        this.install(&crate::modules::generator::module()?)?;
-        this.install(&crate::modules::int::module()?)?;
-        this.install(&crate::modules::io::module(stdio)?)?;
-        this.install(&crate::modules::iter::module()?)?;
-        this.install(&crate::modules::mem::module()?)?;
-        this.install(&crate::modules::object::module()?)?;
-        this.install(&crate::modules::ops::module()?)?;
-        this.install(&crate::modules::option::module()?)?;
-        this.install(&crate::modules::result::module()?)?;
-        this.install(&crate::modules::stream::module()?)?;
-        this.install(&crate::modules::string::module()?)?;
-        this.install(&crate::modules::vec::module()?)?;
-        this.has_default_modules = true;
+        this.install_default_modules(stdio)?;
         Ok(this)
     }
 
+    /// Construct a default set of modules with the given configuration, but
+    /// only install modules whose item matches one of the allowed `prefixes`.
+    ///
+    /// A module is installed if its item starts with one of the allowed
+    /// prefixes, or if it's an ancestor of one. So allowing `std::string` will
+    /// also install the core `std` module, but not `std::io`.
+    ///
+    /// The restriction also applies to any modules installed into the context
+    /// later. [Context::install] errors for modules which aren't allowed, while
+    /// [Context::install_if_allowed] skips them.
+    ///
+    /// ```rust
+    /// use runestick::{Context, Item};
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let context = Context::with_allowed_modules(true, vec![
+    ///     Item::with_crate_item("std", &["string"]),
+    /// ])?;
+    ///
+    /// assert!(context.contains_prefix(&Item::with_crate_item("std", &["string"])));
+    /// assert!(!context.contains_prefix(&Item::with_crate_item("std", &["io"])));
+    /// # Ok(()) }
+    /// ```
+    pub fn with_allowed_modules<I>(stdio: bool, prefixes: I) -> Result<Self, ContextError>
+    where
+        I: IntoIterator<Item = Item>,
+    {
+        let mut this = Self::new();
+        this.allowed_modules = Some(prefixes.into_iter().collect());
+        this.install_default_modules(stdio)?;
+        Ok(this)
+    }
+
+    /// Install the default modules.
+    fn install_default_modules(&mut self, stdio: bool) -> Result<(), ContextError> {
+        // NB: core types need to be installed before modules which register
+        // instance functions on them.
+        self.install_if_allowed(&crate::modules::core::module()?)?;
+        self.install_if_allowed(&crate::modules::any::module()?)?;
+        self.install_if_allowed(&crate::modules::bytes::module()?)?;
+        self.install_if_allowed(&crate::modules::char::module()?)?;
+        self.install_if_allowed(&crate::modules::cmp::module()?)?;
+        self.install_if_allowed(&crate::modules::collections::module()?)?;
+        self.install_if_allowed(&crate::modules::error::module()?)?;
+        self.install_if_allowed(&crate::modules::float::module()?)?;
+        self.install_if_allowed(&crate::modules::fmt::module()?)?;
+        self.install_if_allowed(&crate::modules::future::module()?)?;
+        self.install_if_allowed(&crate::modules::generator::module()?)?;
+        self.install_if_allowed(&crate::modules::int::module()?)?;
+        self.install_if_allowed(&crate::modules::io::module(stdio)?)?;
+        self.install_if_allowed(&crate::modules::iter::module()?)?;
+        self.install_if_allowed(&crate::modules::math::module()?)?;
+        self.install_if_allowed(&crate::modules::mem::module()?)?;
+        self.install_if_allowed(&crate::modules::object::module()?)?;
+        self.install_if_allowed(&crate::modules::ops::module()?)?;
+        self.install_if_allowed(&crate::modules::option::module()?)?;
+        self.install_if_allowed(&crate::modules::result::module()?)?;
+        self.install_if_allowed(&crate::modules::stream::module()?)?;
+        self.install_if_allowed(&crate::modules::string::module()?)?;
+        self.install_if_allowed(&crate::modules::vec::module()?)?;
+        self.has_default_modules = true;
+        Ok(())
+    }
+
     /// Construct a runtime context used when executing the virtual machine.
     ///
     /// ```rust
@@ -299,6 +377,18 @@ impl Context {
             functions: self.functions.clone(),
             types: self.types.iter().map(|(k, t)| (*k, t.type_check)).collect(),
             constants: self.constants.clone(),
+            items: self
+                .meta
+                .iter()
+                .filter_map(|(item, meta)| match &meta.kind {
+                    CompileMetaKind::Function { type_hash, .. }
+                        if *type_hash != Hash::type_hash(item) =>
+                    {
+                        Some((item.clone(), *type_hash))
+                    }
+                    _ => None,
+                })
+                .collect(),
         }
     }
 
@@ -368,6 +458,17 @@ impl Context {
         self.meta.get(name).cloned()
     }
 
+    /// Iterate over all constants registered through
+    /// [Module::constant][crate::Module::constant].
+    pub fn iter_constants(&self) -> impl Iterator<Item = (&Item, &ConstValue)> {
+        self.meta
+            .iter()
+            .filter_map(|(item, meta)| match &meta.kind {
+                CompileMetaKind::Const { const_value } => Some((item, const_value)),
+                _ => None,
+            })
+    }
+
     /// Iterate over all available functions
     pub fn iter_functions(&self) -> impl Iterator<Item = (Hash, &ContextSignature)> {
         let mut it = self.functions_info.iter();
@@ -388,8 +489,42 @@ impl Context {
         })
     }
 
+    /// Test if the module with the given item is allowed to be installed in
+    /// this context.
+    pub fn is_module_allowed(&self, item: &Item) -> bool {
+        match &self.allowed_modules {
+            Some(prefixes) => prefixes
+                .iter()
+                .any(|prefix| item.starts_with(prefix) || prefix.starts_with(item)),
+            None => true,
+        }
+    }
+
+    /// Install the specified module, unless the context restricts which
+    /// modules are allowed and it isn't one of them.
+    pub fn install_if_allowed(&mut self, module: &Module) -> Result<(), ContextError> {
+        if !self.is_module_allowed(&module.item) {
+            return Ok(());
+        }
+
+        self.install(module)
+    }
+
     /// Install the specified module.
+    ///
+    /// If the context restricts which modules are allowed, installing a module
+    /// which is not allowed errors with [ContextError::ModuleNotAllowed].
     pub fn install(&mut self, module: &Module) -> Result<(), ContextError> {
+        if !self.is_module_allowed(&module.item) {
+            return Err(ContextError::ModuleNotAllowed {
+                item: module.item.clone(),
+            });
+        }
+
+        // NB: checked before anything is installed, so that a failing module
+        // leaves the context untouched.
+        self.check_required_protocols(module)?;
+
         if let Some(ComponentRef::Crate(name)) = module.item.first() {
             self.crates.insert(name.into());
         }
@@ -430,6 +565,45 @@ impl Context {
         Ok(())
     }
 
+    /// Check that all protocols required by the associated functions of the
+    /// given module are either registered in the context or provided by the
+    /// module itself.
+    fn check_required_protocols(&self, module: &Module) -> Result<(), ContextError> {
+        let provided = module
+            .associated_functions
+            .keys()
+            .map(|key| key.kind.hash(key.type_hash, key.hash))
+            .collect::<HashSet<_>>();
+
+        let mut functions = Vec::new();
+
+        for (key, assoc) in &module.associated_functions {
+            let protocols = assoc
+                .requires
+                .iter()
+                .filter(|protocol| {
+                    let hash = Hash::instance_function(key.type_hash, **protocol);
+                    !self.functions.contains_key(&hash) && !provided.contains(&hash)
+                })
+                .copied()
+                .collect::<Vec<_>>();
+
+            if !protocols.is_empty() {
+                functions.push(FunctionMissingProtocols {
+                    type_info: assoc.type_info.clone(),
+                    name: assoc.name.clone(),
+                    protocols,
+                });
+            }
+        }
+
+        if !functions.is_empty() {
+            return Err(ContextError::MissingProtocols { functions });
+        }
+
+        Ok(())
+    }
+
     /// Install the given meta.
     fn install_meta(&mut self, meta: CompileMeta) -> Result<(), ContextError> {
         if let Some(existing) = self.meta.insert(meta.item.item.clone(), meta.clone()) {
@@ -468,6 +642,7 @@ impl Context {
                 type_hash,
                 object: CompileMetaStruct {
                     fields: Default::default(),
+                    ordered_fields: None,
                 },
             },
             source: None,
@@ -537,6 +712,7 @@ impl Context {
                 kind: CompileMetaKind::Function {
                     type_hash: hash,
                     is_test: false,
+                    return_arity: None,
                 },
                 source: None,
             },
@@ -639,6 +815,7 @@ impl Context {
                 kind: CompileMetaKind::Function {
                     type_hash: hash,
                     is_test: false,
+                    return_arity: None,
                 },
                 source: None,
             },
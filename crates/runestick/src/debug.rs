@@ -28,6 +28,41 @@ impl DebugInfo {
         let signature = self.functions.get(&hash)?;
         Some((hash, signature))
     }
+
+    /// Get the function which contains the given instruction pointer.
+    pub fn function_containing(&self, ip: usize) -> Option<(Hash, &DebugSignature)> {
+        let (_, hash) = self
+            .functions_rev
+            .iter()
+            .filter(|(offset, _)| **offset <= ip)
+            .max_by_key(|(offset, _)| **offset)?;
+
+        let signature = self.functions.get(hash)?;
+        Some((*hash, signature))
+    }
+
+    /// Get the location of the instruction at `ip` in the given call stack,
+    /// where `frames` are the instruction pointers of the callers, innermost
+    /// last.
+    ///
+    /// Functions marked with `#[track_caller]` are skipped in favor of the
+    /// location they were called from.
+    pub fn location_at(&self, ip: usize, frames: &[usize]) -> Option<&DebugInst> {
+        let mut ip = ip;
+        let mut callers = frames.iter().rev();
+
+        loop {
+            let track_caller = self
+                .function_containing(ip)
+                .map(|(_, signature)| signature.track_caller)
+                .unwrap_or_default();
+
+            match callers.next() {
+                Some(caller) if track_caller => ip = *caller,
+                _ => return self.instruction_at(ip),
+            }
+        }
+    }
 }
 
 /// Debug information for every instruction.
@@ -61,6 +96,10 @@ pub struct DebugSignature {
     pub path: Item,
     /// The number of arguments expected in the function.
     pub args: DebugArgs,
+    /// If the function is marked with `#[track_caller]`, in which case errors
+    /// raised inside of it are reported at the location of its caller.
+    #[serde(default)]
+    pub track_caller: bool,
 }
 
 impl DebugSignature {
@@ -69,6 +108,7 @@ impl DebugSignature {
         Self {
             path,
             args: DebugArgs::Named(args),
+            track_caller: false,
         }
     }
 }
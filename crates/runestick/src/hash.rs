@@ -49,9 +49,7 @@ impl Hash {
 
     /// Construct a hash from a type id.
     pub fn from_type_id(type_id: any::TypeId) -> Self {
-        // Safety: a type id is exactly a 64-bit unsigned integer.
-        // And has an identical bit pattern to `Hash`.
-        unsafe { mem::transmute(type_id) }
+        Self::of(type_id)
     }
 
     /// Construct a hash to an instance function, where the instance is a
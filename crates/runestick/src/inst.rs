@@ -13,6 +13,12 @@ pub enum PanicReason {
     UnmatchedPattern,
     /// Tried to poll a future that has already been completed.
     FutureCompleted,
+    /// A function returned a value which doesn't match its declared return
+    /// type.
+    ReturnTypeMismatch,
+    /// The base of a struct update expression doesn't match the type of the
+    /// struct being constructed.
+    StructUpdateTypeMismatch,
 }
 
 impl PanicReason {
@@ -22,6 +28,8 @@ impl PanicReason {
             Self::NotImplemented => "not implemented",
             Self::UnmatchedPattern => "unmatched pattern",
             Self::FutureCompleted => "future completed",
+            Self::ReturnTypeMismatch => "return type mismatch",
+            Self::StructUpdateTypeMismatch => "struct update type mismatch",
         }
     }
 }
@@ -34,6 +42,14 @@ impl fmt::Display for PanicReason {
             Self::FutureCompleted => {
                 write!(fmt, "tried to poll future that has already been completed")?
             }
+            Self::ReturnTypeMismatch => write!(
+                fmt,
+                "returned value does not match the declared return type"
+            )?,
+            Self::StructUpdateTypeMismatch => write!(
+                fmt,
+                "base of struct update does not match the type being constructed"
+            )?,
         }
 
         Ok(())
@@ -136,6 +152,41 @@ pub enum Inst {
         /// The number of arguments expected on the stack for this call.
         args: usize,
     },
+    /// Perform a function call where the last argument is a vector which is
+    /// spread out into positional arguments.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <args...>
+    /// <vec>
+    /// => <ret>
+    /// ```
+    CallSpread {
+        /// The hash of the function to call.
+        hash: Hash,
+        /// The number of arguments on the stack, including the vector to
+        /// spread.
+        args: usize,
+    },
+    /// Perform an instance function call where the last argument is a vector
+    /// which is spread out into positional arguments.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <instance>
+    /// <args...>
+    /// <vec>
+    /// => <ret>
+    /// ```
+    CallInstanceSpread {
+        /// The hash of the name of the function to call.
+        hash: Hash,
+        /// The number of arguments on the stack, including the vector to
+        /// spread.
+        args: usize,
+    },
     /// Lookup the specified instance function and put it on the stack.
     /// This might help in cases where a single instance function is called many
     /// times (like in a loop) since it avoids calculating its full hash on
@@ -167,6 +218,23 @@ pub enum Inst {
         /// The number of arguments expected on the stack for this call.
         args: usize,
     },
+    /// Perform a function call on a function pointer stored on the stack,
+    /// where the last argument is a vector which is spread out into positional
+    /// arguments.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <args...>
+    /// <vec>
+    /// <fn>
+    /// => <ret>
+    /// ```
+    CallFnSpread {
+        /// The number of arguments on the stack, including the vector to
+        /// spread.
+        args: usize,
+    },
     /// Perform an index get operation. Pushing the result on the stack.
     ///
     /// # Operation
@@ -416,6 +484,16 @@ pub enum Inst {
     /// => <value>
     /// ```
     Dup,
+    /// Swap the two values on the top of the stack.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <a>
+    /// <b>
+    /// => <b> <a>
+    /// ```
+    Swap,
     /// Replace a value at the offset relative from the top of the stack, with
     /// the top of the stack.
     Replace {
@@ -797,6 +875,45 @@ pub enum Inst {
         /// The bool to test against.
         boolean: bool,
     },
+    /// Test if the top of the stack is greater than or equal to the given
+    /// byte, character, or integer. Values of any other type don't match.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// => <boolean>
+    /// ```
+    GteValue {
+        /// The value to test against.
+        value: InstValue,
+    },
+    /// Test if the top of the stack is less than or equal to the given byte,
+    /// character, or integer. Values of any other type don't match.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// => <boolean>
+    /// ```
+    LteValue {
+        /// The value to test against.
+        value: InstValue,
+    },
+    /// Test if the top of the stack is less than the given byte, character,
+    /// or integer. Values of any other type don't match.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// => <boolean>
+    /// ```
+    LtValue {
+        /// The value to test against.
+        value: InstValue,
+    },
     /// Compare the top of the stack against a static string slot.
     ///
     /// # Operation
@@ -809,6 +926,19 @@ pub enum Inst {
         /// The slot to test against.
         slot: usize,
     },
+    /// Test if the top of the stack is a member of the static set in the given
+    /// slot.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// => <boolean>
+    /// ```
+    InStaticSet {
+        /// The slot of the set to test against.
+        slot: usize,
+    },
     /// Test that the top of the stack is a tuple with the given length
     /// requirements.
     ///
@@ -922,6 +1052,33 @@ pub enum Inst {
         /// A relative jump to perform if the iterator could not be advanced.
         jump: isize,
     },
+    /// Push an error handler which stays active until the matching
+    /// [PopTry][Inst::PopTry].
+    ///
+    /// If a catchable error is raised while the handler is active, the stack is
+    /// unwound to what it was when the handler was pushed, the error is pushed
+    /// onto it, and execution continues at `offset` relative to the current
+    /// instruction pointer.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// *nothing*
+    /// => *nothing*
+    /// ```
+    PushTry {
+        /// Offset to the error handler.
+        offset: isize,
+    },
+    /// Pop the most recently pushed error handler.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// *nothing*
+    /// => *nothing*
+    /// ```
+    PopTry,
     /// Cause the VM to panic and error out without a reason.
     ///
     /// This should only be used during testing or extreme scenarios that are
@@ -1000,6 +1157,15 @@ impl fmt::Display for Inst {
             Self::CallFn { args } => {
                 write!(fmt, "call-fn {}", args)?;
             }
+            Self::CallSpread { hash, args } => {
+                write!(fmt, "call-spread {}, {}", hash, args)?;
+            }
+            Self::CallInstanceSpread { hash, args } => {
+                write!(fmt, "call-instance-spread {}, {}", hash, args)?;
+            }
+            Self::CallFnSpread { args } => {
+                write!(fmt, "call-fn-spread {}", args)?;
+            }
             Self::LoadInstanceFn { hash } => {
                 write!(fmt, "load-instance-fn {}", hash)?;
             }
@@ -1060,6 +1226,9 @@ impl fmt::Display for Inst {
             Self::Dup => {
                 write!(fmt, "dup")?;
             }
+            Self::Swap => {
+                write!(fmt, "swap")?;
+            }
             Self::Replace { offset } => {
                 write!(fmt, "replace {}", offset)?;
             }
@@ -1165,9 +1334,21 @@ impl fmt::Display for Inst {
             Self::EqBool { boolean } => {
                 write!(fmt, "eq-integer {}", boolean)?;
             }
+            Self::GteValue { value } => {
+                write!(fmt, "gte-value {}", value)?;
+            }
+            Self::LteValue { value } => {
+                write!(fmt, "lte-value {}", value)?;
+            }
+            Self::LtValue { value } => {
+                write!(fmt, "lt-value {}", value)?;
+            }
             Self::EqStaticString { slot } => {
                 write!(fmt, "eq-static-string {}", slot)?;
             }
+            Self::InStaticSet { slot } => {
+                write!(fmt, "in-static-set {}", slot)?;
+            }
             Self::MatchSequence {
                 type_check,
                 len,
@@ -1200,6 +1381,12 @@ impl fmt::Display for Inst {
             Self::IterNext { offset, jump } => {
                 write!(fmt, "iter-next {}, {}", offset, jump)?;
             }
+            Self::PushTry { offset } => {
+                write!(fmt, "push-try {}", offset)?;
+            }
+            Self::PopTry => {
+                write!(fmt, "pop-try")?;
+            }
             Self::Panic { reason } => {
                 write!(fmt, "panic {}", reason.ident())?;
             }
@@ -62,6 +62,7 @@ mod bytes;
 mod call;
 mod compile_meta;
 mod const_value;
+mod content_hash;
 pub mod debug;
 mod env;
 pub mod format;
@@ -97,6 +98,7 @@ mod source;
 mod span;
 mod spanned_error;
 mod stack;
+mod static_set;
 mod static_string;
 mod static_type;
 mod stream;
@@ -172,6 +174,7 @@ pub use self::select::Select;
 pub use self::source::Source;
 pub use self::span::{ByteIndex, IntoByteIndex, Span};
 pub use self::spanned_error::{SpannedError, WithSpan};
+pub use self::static_set::StaticSet;
 pub use self::static_string::StaticString;
 pub use self::static_type::{
     StaticType, BOOL_TYPE, BYTES_TYPE, BYTE_TYPE, CHAR_TYPE, FLOAT_TYPE, FORMAT_TYPE,
@@ -192,7 +195,9 @@ pub use crate::any::Any;
 pub use crate::awaited::Awaited;
 pub use crate::bytes::Bytes;
 pub use crate::call::Call;
-pub use crate::context::{Context, ContextError, ContextSignature, ContextTypeInfo};
+pub use crate::context::{
+    Context, ContextError, ContextSignature, ContextTypeInfo, FunctionMissingProtocols,
+};
 pub use crate::debug::{DebugInfo, DebugInst};
 pub use crate::function::{Function, SyncFunction};
 pub use crate::future::Future;
@@ -216,7 +221,7 @@ pub use crate::vec_tuple::VecTuple;
 pub use crate::visibility::Visibility;
 pub use crate::vm::{CallFrame, Vm};
 pub use crate::vm_call::VmCall;
-pub use crate::vm_error::{VmError, VmErrorKind, VmIntegerRepr};
+pub use crate::vm_error::{CaughtError, VmError, VmErrorKind, VmIntegerRepr};
 pub use crate::vm_execution::{VmExecution, VmSendExecution};
 pub use crate::vm_halt::{VmHalt, VmHaltInfo};
 pub(crate) use runestick_macros::__internal_impl_any;
@@ -117,6 +117,7 @@ pub(crate) struct ModuleAssociatedFn {
     pub(crate) args: Option<usize>,
     pub(crate) type_info: TypeInfo,
     pub(crate) name: String,
+    pub(crate) requires: Vec<Protocol>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -552,6 +553,45 @@ impl Module {
         Ok(())
     }
 
+    /// Iterate over the free functions registered in this module.
+    ///
+    /// This yields the full item of each function together with the number of
+    /// arguments it accepts. Functions registered with
+    /// [raw_fn][Module::raw_fn] don't have a known number of arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Item, Module, Stack, VmError};
+    ///
+    /// fn raw(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    ///     Ok(())
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = Module::with_item(&["math"]);
+    /// module.function(&["add"], |a: i64, b: i64| a + b)?;
+    /// module.function(&["neg"], |a: i64| -a)?;
+    /// module.function(&["zero"], || 0i64)?;
+    /// module.raw_fn(&["raw"], raw)?;
+    ///
+    /// let mut functions = module.iter_functions().collect::<Vec<_>>();
+    /// functions.sort();
+    ///
+    /// assert_eq!(functions, vec![
+    ///     (Item::with_item(&["math", "add"]), Some(2)),
+    ///     (Item::with_item(&["math", "neg"]), Some(1)),
+    ///     (Item::with_item(&["math", "raw"]), None),
+    ///     (Item::with_item(&["math", "zero"]), Some(0)),
+    /// ]);
+    /// # Ok(()) }
+    /// ```
+    pub fn iter_functions(&self) -> impl Iterator<Item = (Item, Option<usize>)> + '_ {
+        self.functions
+            .iter()
+            .map(move |(name, f)| (self.item.join(name), f.args))
+    }
+
     /// Register a raw function which interacts directly with the virtual
     /// machine.
     pub fn raw_fn<F, N>(&mut self, name: N, f: F) -> Result<(), ContextError>
@@ -577,6 +617,67 @@ impl Module {
         Ok(())
     }
 
+    /// Register a raw instance function of the type `T` which interacts
+    /// directly with the virtual machine.
+    ///
+    /// The instance is the first of the arguments on the stack.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Stack, Value, VmError};
+    ///
+    /// /// Push any number of values to the vector.
+    /// fn push_all(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    ///     let mut it = stack.drain_stack_top(args)?;
+    ///     let vec = it.next().unwrap_or_default().into_vec()?;
+    ///     let mut vec = vec.borrow_mut()?;
+    ///
+    ///     for value in it {
+    ///         vec.push(value);
+    ///     }
+    ///
+    ///     stack.push(Value::Unit);
+    ///     Ok(())
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    /// module.raw_inst_fn::<runestick::Vec, _, _>("push_all", push_all)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn raw_inst_fn<T, F, N>(&mut self, name: N, f: F) -> Result<(), ContextError>
+    where
+        T: TypeOf,
+        F: 'static + Copy + Fn(&mut Stack, usize) -> Result<(), VmError> + Send + Sync,
+        N: InstFnNameHash,
+    {
+        let type_info = T::type_info();
+
+        let key = ModuleAssocKey {
+            type_hash: T::type_hash(),
+            hash: name.inst_fn_name_hash(),
+            kind: ModuleAssociatedKind::Instance,
+        };
+
+        let name = name.into_name();
+
+        if self.associated_functions.contains_key(&key) {
+            return Err(ContextError::ConflictingInstanceFunction { type_info, name });
+        }
+
+        let instance_function = ModuleAssociatedFn {
+            handler: Arc::new(move |stack, args| f(stack, args)),
+            args: None,
+            type_info,
+            name,
+            requires: Vec::new(),
+        };
+
+        self.associated_functions.insert(key, instance_function);
+        Ok(())
+    }
+
     /// Register an instance function.
     ///
     /// # Examples
@@ -617,7 +718,95 @@ impl Module {
         N: InstFnNameHash,
         Func: InstFn<Args>,
     {
-        self.assoc_fn(name, f, ModuleAssociatedKind::Instance)
+        self.assoc_fn(name, f, ModuleAssociatedKind::Instance, Vec::new())
+    }
+
+    /// Register an instance function which requires the instance type to
+    /// implement the given protocols, like an instance function which assumes
+    /// that its receiver is iterable.
+    ///
+    /// Installing the module into a [Context][crate::Context] fails with
+    /// [ContextError::MissingProtocols] if any of the protocols are neither
+    /// registered for the instance type in the context nor provided by the
+    /// module itself. Nothing from the module is installed in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Any, Protocol};
+    ///
+    /// #[derive(Any)]
+    /// struct Numbers {
+    ///     values: Vec<i64>,
+    /// }
+    ///
+    /// impl Numbers {
+    ///     fn sum(&self) -> i64 {
+    ///         self.values.iter().sum()
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    ///
+    /// module.ty::<Numbers>()?;
+    /// module.inst_fn_requires("sum", Numbers::sum, &[Protocol::INTO_ITER])?;
+    ///
+    /// let mut context = runestick::Context::new();
+    /// assert!(context.install(&module).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn inst_fn_requires<N, Func, Args>(
+        &mut self,
+        name: N,
+        f: Func,
+        requires: &[Protocol],
+    ) -> Result<(), ContextError>
+    where
+        N: InstFnNameHash,
+        Func: InstFn<Args>,
+    {
+        self.assoc_fn(name, f, ModuleAssociatedKind::Instance, requires.to_vec())
+    }
+
+    /// Register a handler which is called when a value of a native type is
+    /// dropped from a script through `std::mem::drop`.
+    ///
+    /// The handler is an instance function of the type, which allows
+    /// resources held by the value to be released deterministically. Values
+    /// of types without a handler are simply taken when they're dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Any;
+    ///
+    /// #[derive(Any)]
+    /// struct Handle {
+    ///     open: bool,
+    /// }
+    ///
+    /// impl Handle {
+    ///     fn close(&mut self) {
+    ///         self.open = false;
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    ///
+    /// module.ty::<Handle>()?;
+    /// module.drop_fn(Handle::close)?;
+    ///
+    /// let mut context = runestick::Context::new();
+    /// context.install(&module)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn drop_fn<Func, Args>(&mut self, f: Func) -> Result<(), ContextError>
+    where
+        Func: InstFn<Args>,
+    {
+        self.inst_fn(Protocol::DROP, f)
     }
 
     /// Install a protocol function for the given field.
@@ -631,7 +820,7 @@ impl Module {
         N: InstFnNameHash,
         Func: InstFn<Args>,
     {
-        self.assoc_fn(name, f, ModuleAssociatedKind::FieldFn(protocol))
+        self.assoc_fn(name, f, ModuleAssociatedKind::FieldFn(protocol), Vec::new())
     }
 
     /// Install an associated function.
@@ -640,6 +829,7 @@ impl Module {
         name: N,
         f: Func,
         kind: ModuleAssociatedKind,
+        requires: Vec<Protocol>,
     ) -> Result<(), ContextError>
     where
         N: InstFnNameHash,
@@ -667,6 +857,7 @@ impl Module {
             args: Some(Func::args()),
             type_info,
             name,
+            requires,
         };
 
         self.associated_functions.insert(key, instance_function);
@@ -727,6 +918,7 @@ impl Module {
             args: Some(Func::args()),
             type_info,
             name,
+            requires: Vec::new(),
         };
 
         self.associated_functions.insert(key, instance_function);
@@ -1,7 +1,8 @@
 //! The `std::char` module.
 
-use crate::{ContextError, Module, Value, VmError, VmErrorKind};
+use crate::{ContextError, Module, VmError};
 use std::char::ParseCharError;
+use std::convert::TryFrom;
 
 /// Construct the `std::char` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -17,18 +18,51 @@ pub fn module() -> Result<Module, ContextError> {
     module.function(&["is_uppercase"], char::is_uppercase)?;
     module.function(&["is_whitespace"], char::is_whitespace)?;
 
-    module.function(&["to_digit"], char::to_digit)?;
+    module.function(&["to_digit"], to_digit)?;
+
+    module.inst_fn("is_alphabetic", char::is_alphabetic)?;
+    module.inst_fn("is_alphanumeric", char::is_alphanumeric)?;
+    module.inst_fn("is_numeric", char::is_numeric)?;
+    module.inst_fn("is_whitespace", char::is_whitespace)?;
+    module.inst_fn("to_digit", to_digit)?;
+    module.inst_fn("to_int", to_int)?;
+    module.inst_fn("to_uppercase", to_uppercase)?;
 
     Ok(module)
 }
 
-fn char_from_int_impl(value: i64) -> Result<Option<Value>, VmError> {
-    if value < 0 {
-        Err(VmError::from(VmErrorKind::Underflow))
-    } else if value > u32::MAX as i64 {
-        Err(VmError::from(VmErrorKind::Overflow))
-    } else {
-        Ok(std::char::from_u32(value as u32).map(|v| v.into()))
+/// Convert an integer into a character, or `None` if it's not a valid unicode
+/// scalar value, like a surrogate or a value above `0x10FFFF`.
+fn char_from_int_impl(value: i64) -> Option<char> {
+    std::char::from_u32(u32::try_from(value).ok()?)
+}
+
+/// Get the code point of the character.
+fn to_int(c: char) -> i64 {
+    c as i64
+}
+
+/// Convert the character to a digit in the given radix, or `None` if it's not
+/// a valid digit. Errors if the radix is not in the range `2..=36`.
+fn to_digit(c: char, radix: i64) -> Result<Option<i64>, VmError> {
+    if !(2..=36).contains(&radix) {
+        return Err(VmError::panic(format!(
+            "radix `{}` is out of range, expected a value between 2 and 36",
+            radix
+        )));
+    }
+
+    Ok(c.to_digit(radix as u32).map(i64::from))
+}
+
+/// Convert the character to uppercase, or `None` if its uppercase form
+/// consists of multiple characters, like `ß` which uppercases to `SS`.
+fn to_uppercase(c: char) -> Option<char> {
+    let mut it = c.to_uppercase();
+
+    match (it.next(), it.next()) {
+        (Some(upper), None) => Some(upper),
+        _ => None,
     }
 }
 
@@ -1,6 +1,6 @@
 //! The core `std` module.
 
-use crate::{ContextError, Module, Panic, Value};
+use crate::{ContextError, Module, Panic, Stack, Value, Vm, VmError, VmErrorKind};
 
 /// Construct the `std` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -16,9 +16,31 @@ pub fn module() -> Result<Module, ContextError> {
     module.function(&["panic"], panic_impl)?;
     module.function(&["is_readable"], is_readable)?;
     module.function(&["is_writable"], is_writable)?;
+    module.raw_fn(&["eq"], raw_eq)?;
     Ok(module)
 }
 
+/// Test if two values are structurally equal, like the `==` operator.
+fn raw_eq(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    if args != 2 {
+        return Err(VmError::from(VmErrorKind::BadArgumentCount {
+            actual: args,
+            expected: 2,
+        }));
+    }
+
+    let b = stack.pop()?;
+    let a = stack.pop()?;
+
+    let eq = crate::env::with(|context, unit| {
+        let mut vm = Vm::new(context.clone(), unit.clone());
+        Value::value_ptr_eq(&mut vm, &a, &b)
+    })?;
+
+    stack.push(Value::from(eq));
+    Ok(())
+}
+
 fn panic_impl(m: &str) -> Result<(), Panic> {
     Err(Panic::custom(m.to_owned()))
 }
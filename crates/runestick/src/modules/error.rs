@@ -0,0 +1,25 @@
+//! The `std::error` module.
+
+use crate::{CaughtError, ContextError, Module, Protocol};
+use std::fmt;
+use std::fmt::Write as _;
+
+/// Construct the `std::error` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::with_crate_item("std", &["error"]);
+
+    module.ty::<CaughtError>()?;
+    module.inst_fn("kind", kind)?;
+    module.inst_fn("message", CaughtError::message)?;
+    module.inst_fn(Protocol::STRING_DISPLAY, format_caught_error)?;
+    Ok(module)
+}
+
+/// The name of the kind of error, like `"Panic"` or `"DivideByZero"`.
+fn kind(error: &CaughtError) -> String {
+    error.kind().name().to_owned()
+}
+
+fn format_caught_error(error: &CaughtError, buf: &mut String) -> fmt::Result {
+    write!(buf, "{}", error.message())
+}
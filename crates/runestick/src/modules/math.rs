@@ -0,0 +1,107 @@
+//! The `std::math` module.
+
+use crate::{ContextError, Module, VmError, VmErrorKind};
+use std::convert::TryFrom as _;
+
+/// Construct the `std::math` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::with_crate_item("std", &["math"]);
+
+    module.function(&["gcd"], gcd)?;
+    module.function(&["lcm"], lcm)?;
+    module.function(&["isqrt"], isqrt)?;
+    module.function(&["clamp_int"], clamp_int)?;
+    module.function(&["saturating_add"], i64::saturating_add)?;
+    module.function(&["saturating_sub"], i64::saturating_sub)?;
+    module.function(&["saturating_mul"], i64::saturating_mul)?;
+    module.function(&["to_bits"], to_bits)?;
+    module.function(&["from_bits"], from_bits)?;
+
+    Ok(module)
+}
+
+/// The greatest common divisor of two integers. `gcd(0, 0)` is `0`.
+fn gcd(a: i64, b: i64) -> Result<i64, VmError> {
+    to_i64(gcd_u64(a.unsigned_abs(), b.unsigned_abs()))
+}
+
+/// The least common multiple of two integers. Is `0` if either argument is
+/// `0`, and errors if the result overflows.
+fn lcm(a: i64, b: i64) -> Result<i64, VmError> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+
+    let (a, b) = (a.unsigned_abs(), b.unsigned_abs());
+    let lcm = (a / gcd_u64(a, b))
+        .checked_mul(b)
+        .ok_or(VmErrorKind::Overflow)?;
+    to_i64(lcm)
+}
+
+/// The integer square root of a non-negative integer, rounded down. Panics if
+/// the argument is negative.
+fn isqrt(n: i64) -> Result<i64, VmError> {
+    if n < 0 {
+        return Err(VmError::panic(format!(
+            "cannot take the integer square root of negative number `{}`",
+            n
+        )));
+    }
+
+    let n = n as u64;
+
+    if n < 2 {
+        return Ok(n as i64);
+    }
+
+    // Newton's method, starting from an estimate which is guaranteed to be
+    // larger than the root.
+    let mut x = n;
+    let mut y = (x + n / x) / 2;
+
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+
+    Ok(x as i64)
+}
+
+/// Restrict an integer to the inclusive range `min..=max`. Panics if `min` is
+/// greater than `max`.
+fn clamp_int(value: i64, min: i64, max: i64) -> Result<i64, VmError> {
+    if min > max {
+        return Err(VmError::panic(format!(
+            "cannot clamp with a minimum `{}` which is greater than the maximum `{}`",
+            min, max
+        )));
+    }
+
+    Ok(value.clamp(min, max))
+}
+
+/// The raw IEEE-754 bit pattern of a float, reinterpreted as an integer.
+fn to_bits(value: f64) -> i64 {
+    value.to_bits() as i64
+}
+
+/// Construct a float from its raw IEEE-754 bit pattern. This is the inverse
+/// of `to_bits`.
+fn from_bits(bits: i64) -> f64 {
+    f64::from_bits(bits as u64)
+}
+
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+
+    a
+}
+
+fn to_i64(n: u64) -> Result<i64, VmError> {
+    Ok(i64::try_from(n).map_err(|_| VmErrorKind::Overflow)?)
+}
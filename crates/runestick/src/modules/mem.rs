@@ -1,6 +1,6 @@
 //! The `std::mem` module.
 
-use crate::{ContextError, Module, Value, VmError};
+use crate::{ContextError, Hash, Module, Protocol, Stack, Value, VmError};
 
 /// Construct the `std` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -10,6 +10,22 @@ pub fn module() -> Result<Module, ContextError> {
 }
 
 fn drop_impl(value: Value) -> Result<(), VmError> {
+    // NB: native types can register a handler to release their resources
+    // through `Module::drop_fn`.
+    if let Value::Any(..) = &value {
+        let hash = Hash::instance_function(value.type_hash()?, Protocol::DROP);
+
+        crate::env::with(|context, _| {
+            if let Some(handler) = context.lookup(hash) {
+                let mut stack = Stack::with_capacity(1);
+                stack.push(value.clone());
+                handler(&mut stack, 1)?;
+            }
+
+            Ok(())
+        })?;
+    }
+
     value.take()?;
     Ok(())
 }
@@ -7,6 +7,7 @@ pub mod char;
 pub mod cmp;
 pub mod collections;
 pub mod core;
+pub mod error;
 pub mod float;
 pub mod fmt;
 pub mod future;
@@ -14,6 +15,7 @@ pub mod generator;
 pub mod int;
 pub mod io;
 pub mod iter;
+pub mod math;
 pub mod mem;
 pub mod object;
 pub mod ops;
@@ -1,6 +1,6 @@
 //! The `std::object` module.
 
-use crate::{ContextError, Iterator, Module, Object, Protocol, Value};
+use crate::{ContextError, Iterator, Module, Object, Protocol, Value, Vec, VmError};
 
 /// Construct the `std::object` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -8,6 +8,8 @@ pub fn module() -> Result<Module, ContextError> {
 
     module.ty::<Object>()?;
 
+    module.function(&["from_pairs"], from_pairs)?;
+
     module.inst_fn("len", Object::len)?;
     module.inst_fn("insert", Object::insert)?;
     module.inst_fn("clear", Object::clear)?;
@@ -21,6 +23,49 @@ pub fn module() -> Result<Module, ContextError> {
     Ok(module)
 }
 
+/// Construct an object from a vector of `(key, value)` tuples. Later
+/// duplicate keys overwrite earlier ones.
+fn from_pairs(pairs: &Vec) -> Result<Object, VmError> {
+    let mut object = Object::with_capacity(pairs.len());
+
+    for pair in pairs.iter() {
+        let pair = match pair {
+            Value::Tuple(tuple) => tuple.borrow_ref()?,
+            actual => {
+                return Err(VmError::panic(format!(
+                    "expected a `(key, value)` tuple, but got `{}`",
+                    actual.type_info()?
+                )));
+            }
+        };
+
+        let (key, value) = match &pair[..] {
+            [key, value] => (key, value),
+            _ => {
+                return Err(VmError::panic(format!(
+                    "expected a `(key, value)` tuple, but got a tuple with {} elements",
+                    pair.len()
+                )));
+            }
+        };
+
+        let key = match key {
+            Value::String(key) => key.borrow_ref()?.clone(),
+            Value::StaticString(key) => (***key).clone(),
+            actual => {
+                return Err(VmError::panic(format!(
+                    "object keys must be strings, but got `{}`",
+                    actual.type_info()?
+                )));
+            }
+        };
+
+        object.insert(key, value.clone());
+    }
+
+    Ok(object)
+}
+
 fn contains_key(object: &Object, key: &str) -> bool {
     object.contains_key(key)
 }
@@ -30,11 +75,19 @@ fn get(object: &Object, key: &str) -> Option<Value> {
 }
 
 fn keys(object: &Object) -> Iterator {
-    let iter = object.keys().cloned().collect::<Vec<_>>().into_iter();
+    let iter = object
+        .keys()
+        .cloned()
+        .collect::<std::vec::Vec<_>>()
+        .into_iter();
     Iterator::from_double_ended("std::object::Keys", iter)
 }
 
 fn values(object: &Object) -> Iterator {
-    let iter = object.values().cloned().collect::<Vec<_>>().into_iter();
+    let iter = object
+        .values()
+        .cloned()
+        .collect::<std::vec::Vec<_>>()
+        .into_iter();
     Iterator::from_double_ended("std::object::Values", iter)
 }
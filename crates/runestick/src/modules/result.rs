@@ -14,6 +14,8 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("expect", expect_impl)?;
     module.inst_fn("and_then", and_then_impl)?;
     module.inst_fn("map", map_impl)?;
+    module.inst_fn("unwrap_or", Result::<Value, Value>::unwrap_or)?;
+    module.inst_fn("unwrap_or_else", unwrap_or_else_impl)?;
     Ok(module)
 }
 
@@ -60,3 +62,10 @@ fn map_impl(this: &Result<Value, Value>, then: Function) -> Result<Result<Value,
         Err(e) => Ok(Err(e.clone())),
     }
 }
+
+fn unwrap_or_else_impl(this: &Result<Value, Value>, default: Function) -> Result<Value, VmError> {
+    match this {
+        Ok(v) => Ok(v.clone()),
+        Err(e) => Ok(default.call::<_, _>((e,))?),
+    }
+}
@@ -1,6 +1,9 @@
 //! The `std::string` module.
 
-use crate::{Any, Bytes, ContextError, Iterator, Module, Protocol, Value, VmError, VmErrorKind};
+use crate::{
+    Any, Bytes, ContextError, Iterator, Module, Object, Protocol, Value, VmError, VmErrorKind,
+};
+use std::fmt;
 
 /// Construct the `std::string` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -14,6 +17,8 @@ pub fn module() -> Result<Module, ContextError> {
 
     module.inst_fn("cmp", str::cmp)?;
     module.inst_fn("len", String::len)?;
+    module.inst_fn("byte_len", String::len)?;
+    module.inst_fn("char_len", string_char_len)?;
     module.inst_fn("starts_with", str::starts_with::<&str>)?;
     module.inst_fn("ends_with", str::ends_with::<&str>)?;
     module.inst_fn("capacity", String::capacity)?;
@@ -29,19 +34,26 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("split", string_split)?;
     module.inst_fn("trim", string_trim)?;
     module.inst_fn("trim_end", string_trim_end)?;
+    module.inst_fn("indent", string_indent)?;
+    module.inst_fn("dedent", string_dedent)?;
     module.inst_fn("replace", str::replace::<&str>)?;
     // TODO: deprecate this variant.
     module.inst_fn("split_str", string_split)?;
     module.inst_fn("is_empty", str::is_empty)?;
     module.inst_fn("chars", string_chars)?;
+    module.inst_fn("char_indices", string_char_indices)?;
+    module.inst_fn("matches", string_matches)?;
     module.inst_fn(Protocol::ADD, add)?;
     module.inst_fn(Protocol::ADD_ASSIGN, String::push_str)?;
     module.inst_fn(Protocol::INDEX_GET, string_index_get)?;
     module.inst_fn("get", string_get)?;
+    module.inst_fn("parse_int", string_parse_int)?;
+    module.inst_fn("parse_float", string_parse_float)?;
 
     // TODO: parameterize once generics are available.
     module.function(&["parse_int"], parse_int)?;
     module.function(&["parse_char"], parse_char)?;
+    module.function(&["template"], template)?;
 
     Ok(module)
 }
@@ -67,6 +79,11 @@ fn into_bytes(s: String) -> Bytes {
     Bytes::from_vec(s.into_bytes())
 }
 
+/// The number of characters in the string, as opposed to its length in bytes.
+fn string_char_len(s: &str) -> usize {
+    s.chars().count()
+}
+
 fn char_at(s: &str, index: usize) -> Option<char> {
     if !s.is_char_boundary(index) {
         return None;
@@ -103,10 +120,156 @@ fn string_trim_end(this: &str) -> String {
     this.trim_end().to_owned()
 }
 
+/// Prepend `prefix` to every line of the string. Empty lines are left as-is so
+/// that they don't end up with trailing whitespace.
+fn string_indent(this: &str, prefix: &str) -> String {
+    let mut out = String::with_capacity(this.len());
+
+    for line in this.split_inclusive('\n') {
+        if !line.trim_end_matches(&['\r', '\n'][..]).is_empty() {
+            out.push_str(prefix);
+        }
+
+        out.push_str(line);
+    }
+
+    out
+}
+
+/// Remove the leading whitespace which is common to all non-blank lines of the
+/// string. Blank lines don't count towards the common indentation.
+fn string_dedent(this: &str) -> String {
+    let mut common: Option<&str> = None;
+
+    for line in this.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let indent = &line[..line.len() - line.trim_start().len()];
+
+        common = Some(match common {
+            Some(common) => {
+                let len = common
+                    .char_indices()
+                    .zip(indent.chars())
+                    .find(|((_, a), b)| a != b)
+                    .map(|((n, _), _)| n)
+                    .unwrap_or_else(|| usize::min(common.len(), indent.len()));
+
+                &common[..len]
+            }
+            None => indent,
+        });
+    }
+
+    let common = common.unwrap_or_default();
+    let mut out = String::with_capacity(this.len());
+
+    for line in this.split_inclusive('\n') {
+        match line.strip_prefix(common) {
+            Some(rest) => out.push_str(rest),
+            // NB: blank lines might be shorter than the common indentation,
+            // strip what's there but keep the line terminator intact.
+            None => out.push_str(
+                line.trim_start_matches(|c: char| c != '\n' && c != '\r' && c.is_whitespace()),
+            ),
+        }
+    }
+
+    out
+}
+
+/// Interpolate `{key}` placeholders in `fmt` with the display of the
+/// corresponding field in `object`.
+///
+/// `{{` and `}}` are escapes for literal braces. Referencing a key which is
+/// not present in the object panics.
+fn template(fmt: &str, object: &Object) -> Result<String, VmError> {
+    let mut out = String::with_capacity(fmt.len());
+    let mut buf = String::new();
+    let mut it = fmt.char_indices().peekable();
+
+    while let Some((start, c)) = it.next() {
+        match c {
+            '{' if matches!(it.peek(), Some((_, '{'))) => {
+                it.next();
+                out.push('{');
+            }
+            '}' if matches!(it.peek(), Some((_, '}'))) => {
+                it.next();
+                out.push('}');
+            }
+            '{' => {
+                let end = loop {
+                    match it.next() {
+                        Some((end, '}')) => break end,
+                        Some(..) => (),
+                        None => {
+                            return Err(VmError::panic(format!(
+                                "unterminated placeholder at byte {} in template",
+                                start
+                            )))
+                        }
+                    }
+                };
+
+                let key = &fmt[start + 1..end];
+
+                let value = match object.get(key) {
+                    Some(value) => value,
+                    None => {
+                        return Err(VmError::panic(format!(
+                            "missing key `{}` referenced by template",
+                            key
+                        )))
+                    }
+                };
+
+                if let Err(fmt::Error) = value.string_display(&mut out, &mut buf)? {
+                    return Err(VmError::panic(format!(
+                        "failed to format key `{}` in template",
+                        key
+                    )));
+                }
+            }
+            '}' => {
+                return Err(VmError::panic(format!(
+                    "unmatched `}}` at byte {} in template",
+                    start
+                )))
+            }
+            c => out.push(c),
+        }
+    }
+
+    Ok(out)
+}
+
 fn parse_int(s: &str) -> Result<i64, std::num::ParseIntError> {
     str::parse::<i64>(s)
 }
 
+/// Parse the string as an integer in the given radix, returning `None` if the
+/// string is not a valid integer. Errors if the radix is not in the range
+/// `2..=36`.
+fn string_parse_int(s: &str, radix: i64) -> Result<Option<i64>, VmError> {
+    if !(2..=36).contains(&radix) {
+        return Err(VmError::panic(format!(
+            "radix `{}` is not in the range 2 to 36",
+            radix
+        )));
+    }
+
+    Ok(i64::from_str_radix(s, radix as u32).ok())
+}
+
+/// Parse the string as a float, returning `None` if the string is not a valid
+/// float.
+fn string_parse_float(s: &str) -> Option<f64> {
+    str::parse::<f64>(s).ok()
+}
+
 fn parse_char(s: &str) -> Result<char, std::char::ParseCharError> {
     str::parse::<char>(s)
 }
@@ -124,6 +287,32 @@ fn string_chars(s: &str) -> Iterator {
     Iterator::from_double_ended("std::str::Chars", iter)
 }
 
+/// Get every character in the string together with the byte index it starts
+/// at.
+fn string_char_indices(s: &str) -> Vec<(usize, char)> {
+    s.char_indices().collect()
+}
+
+/// Get the character indexes of every non-overlapping occurrence of `needle`
+/// in the string.
+fn string_matches(s: &str, needle: &str) -> Result<Vec<usize>, VmError> {
+    if needle.is_empty() {
+        return Err(VmError::panic("cannot match an empty string"));
+    }
+
+    let mut out = Vec::new();
+    let mut chars = 0;
+    let mut last = 0;
+
+    for (index, _) in s.match_indices(needle) {
+        chars += s[last..index].chars().count();
+        out.push(chars);
+        last = index;
+    }
+
+    Ok(out)
+}
+
 /// Get a specific string index.
 fn string_get(s: &str, key: Value) -> Result<Option<String>, VmError> {
     use crate::{FromValue as _, RangeLimits, TypeOf as _};
@@ -1,6 +1,11 @@
 //! The `std::vec` module.
 
-use crate::{ContextError, Module, Protocol, Value, Vec};
+use crate::collections::HashSet;
+use crate::{
+    ContextError, FromValue, Function, Iterator, Key, Module, Object, Protocol, Stack, Value, Vec,
+    Vm, VmError, VmErrorKind,
+};
+use std::cmp::Ordering;
 
 /// Construct the `std::vec` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -9,16 +14,38 @@ pub fn module() -> Result<Module, ContextError> {
     module.ty::<Vec>()?;
 
     module.function(&["Vec", "new"], Vec::new)?;
+    module.inst_fn("binary_search", binary_search)?;
     module.inst_fn("clear", Vec::clear)?;
     module.inst_fn("clone", Vec::clone)?;
+    module.inst_fn("count", count)?;
+    module.inst_fn("count_by", count_by)?;
+    module.inst_fn("dedup_by", dedup_by)?;
+    module.inst_fn("enumerate", enumerate)?;
     module.inst_fn("extend", Vec::extend)?;
+    module.inst_fn("extend_from", extend_from)?;
+    module.inst_fn("fill", fill)?;
+    module.raw_inst_fn::<Vec, _, _>("flat_map", raw_flat_map)?;
     module.inst_fn("get", vec_get)?;
+    module.inst_fn("group_by", group_by)?;
     module.inst_fn("iter", Vec::into_iterator)?;
     module.inst_fn("len", Vec::len)?;
+    module.inst_fn("max", max)?;
+    module.inst_fn("min", min)?;
     module.inst_fn("pop", Vec::pop)?;
+    module.inst_fn("position", position)?;
+    module.inst_fn("product", product)?;
     module.inst_fn("push", Vec::push)?;
+    module.inst_fn("reduce", reduce)?;
     module.inst_fn("remove", Vec::remove)?;
-    module.inst_fn("sort_by", sort_by)?;
+    module.inst_fn("rotate_left", rotate_left)?;
+    module.inst_fn("rotate_right", rotate_right)?;
+    module.inst_fn("skip", skip)?;
+    module.raw_inst_fn::<Vec, _, _>("sort_by", raw_sort_by)?;
+    module.inst_fn("sum", sum)?;
+    module.inst_fn("swap", swap)?;
+    module.inst_fn("take", take)?;
+    module.inst_fn("truncate", Vec::truncate)?;
+    module.inst_fn("unique", unique)?;
     module.inst_fn("insert", Vec::insert)?;
     module.inst_fn(Protocol::INTO_ITER, Vec::into_iterator)?;
     module.inst_fn(Protocol::INDEX_SET, Vec::set)?;
@@ -42,10 +69,433 @@ fn vec_get(vec: &Vec, index: usize) -> Option<Value> {
     vec.get(index).cloned()
 }
 
-fn sort_by(vec: &mut Vec, comparator: &crate::Function) {
-    vec.sort_by(|a, b| {
-        comparator
-            .call::<_, std::cmp::Ordering>((a, b))
-            .expect("an ordering")
+/// Remove consecutive elements for which `f` called with the previously
+/// retained element and the current element returns `true`.
+fn dedup_by(vec: &mut Vec, f: &Function) -> Result<(), VmError> {
+    let mut retained = std::vec::Vec::<Value>::with_capacity(vec.len());
+
+    for value in std::mem::replace(vec, Vec::new()) {
+        if let Some(last) = retained.last() {
+            if f.call::<_, bool>((last.clone(), value.clone()))? {
+                continue;
+            }
+        }
+
+        retained.push(value);
+    }
+
+    *vec = Vec::from(retained);
+    Ok(())
+}
+
+/// Count the number of elements which are equal to `value`.
+fn count(vec: &Vec, value: Value) -> Result<usize, VmError> {
+    crate::env::with(|context, unit| {
+        let mut vm = Vm::new(context.clone(), unit.clone());
+        let mut count = 0;
+
+        for v in vec.iter() {
+            if Value::value_ptr_eq(&mut vm, v, &value)? {
+                count += 1;
+            }
+        }
+
+        Ok(count)
     })
 }
+
+/// Construct a new vector with all duplicate elements removed, keeping the
+/// first occurrence of each element and preserving their order.
+///
+/// Elements which can be used as keys, like integers and strings, are
+/// deduplicated by hashing in `O(n)` time. Other elements, like floats, fall
+/// back to being compared using `==` against every other such element
+/// retained so far, which takes `O(n²)` time.
+fn unique(vec: &Vec) -> Result<Vec, VmError> {
+    crate::env::with(|context, unit| {
+        let mut vm = Vm::new(context.clone(), unit.clone());
+        let mut seen = HashSet::new();
+        let mut unhashable = std::vec::Vec::<Value>::new();
+        let mut out = Vec::new();
+
+        for value in vec.iter() {
+            match Key::from_value(value) {
+                Ok(key) => {
+                    if !seen.insert(key) {
+                        continue;
+                    }
+                }
+                Err(error) => {
+                    if !matches!(error.kind(), VmErrorKind::KeyNotSupported { .. }) {
+                        return Err(error);
+                    }
+
+                    let mut duplicate = false;
+
+                    for other in &unhashable {
+                        if Value::value_ptr_eq(&mut vm, other, value)? {
+                            duplicate = true;
+                            break;
+                        }
+                    }
+
+                    if duplicate {
+                        continue;
+                    }
+
+                    unhashable.push(value.clone());
+                }
+            }
+
+            out.push(value.clone());
+        }
+
+        Ok(out)
+    })
+}
+
+/// Count the number of elements for which `f` returns `true`.
+fn count_by(vec: &Vec, f: &Function) -> Result<usize, VmError> {
+    let mut count = 0;
+
+    for value in vec.iter() {
+        if f.call::<_, bool>((value.clone(),))? {
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Iterate over the vector, yielding `(index, value)` tuples.
+fn enumerate(vec: &Vec) -> Iterator {
+    vec.into_iterator().enumerate()
+}
+
+/// Get the index of the first element for which `f` returns `true`, or `None`
+/// if there is no such element. Stops calling `f` as soon as it matches.
+fn position(vec: &Vec, f: &Function) -> Result<Option<usize>, VmError> {
+    for (index, value) in vec.iter().enumerate() {
+        if f.call::<_, bool>((value.clone(),))? {
+            return Ok(Some(index));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Fold the elements of the vector by calling `f` with the accumulated value
+/// and each element, using the first element as the initial value. Returns
+/// `None` if the vector is empty.
+fn reduce(vec: &Vec, f: &Function) -> Result<Option<Value>, VmError> {
+    let mut it = vec.iter();
+
+    let mut acc = match it.next() {
+        Some(first) => first.clone(),
+        None => return Ok(None),
+    };
+
+    for value in it {
+        acc = f.call::<_, Value>((acc, value.clone()))?;
+    }
+
+    Ok(Some(acc))
+}
+
+/// Append all elements of another vector.
+fn extend_from(vec: &mut Vec, other: &Vec) {
+    vec.extend_from_slice(other);
+}
+
+/// Group the elements of a vector into an object, keyed by the string returned
+/// by calling `f` with each element. Elements keep their relative order within
+/// each group.
+fn group_by(vec: &Vec, f: &Function) -> Result<Object, VmError> {
+    let mut groups = Object::new();
+
+    for value in vec.iter() {
+        let key = match f.call::<_, Value>((value.clone(),))? {
+            Value::String(key) => key.borrow_ref()?.clone(),
+            Value::StaticString(key) => (**key).clone(),
+            actual => {
+                return Err(VmError::panic(format!(
+                    "group_by key must be a string, but got `{}`",
+                    actual.type_info()?
+                )));
+            }
+        };
+
+        match groups.get_mut(&key) {
+            Some(Value::Vec(group)) => group.borrow_mut()?.push(value.clone()),
+            _ => {
+                groups.insert(key, Value::vec(vec![value.clone()]));
+            }
+        }
+    }
+
+    Ok(groups)
+}
+
+/// Call `f` with each element, which must return a vector, and concatenate the
+/// returned vectors into one.
+fn raw_flat_map(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    if args != 2 {
+        return Err(VmError::from(VmErrorKind::BadArgumentCount {
+            actual: args,
+            expected: 2,
+        }));
+    }
+
+    let f = stack.pop()?.into_function()?;
+    let vec = stack.pop()?.into_vec()?;
+
+    let f = f.borrow_ref()?;
+    let vec = vec.borrow_ref()?;
+    let mut out = Vec::new();
+
+    for value in vec.iter() {
+        match f.call::<_, Value>((value.clone(),))? {
+            Value::Vec(values) => out.extend_from_slice(&values.borrow_ref()?),
+            actual => {
+                return Err(VmError::panic(format!(
+                    "flat_map function must return a vector, but got `{}`",
+                    actual.type_info()?
+                )));
+            }
+        }
+    }
+
+    stack.push(out);
+    Ok(())
+}
+
+/// Construct a new vector with the first `n` elements, or all of them if the
+/// vector is shorter than that.
+fn take(vec: &Vec, n: usize) -> Vec {
+    Vec::from(vec[..usize::min(n, vec.len())].to_vec())
+}
+
+/// Construct a new vector without the first `n` elements, which is empty if
+/// the vector is shorter than that.
+fn skip(vec: &Vec, n: usize) -> Vec {
+    Vec::from(vec[usize::min(n, vec.len())..].to_vec())
+}
+
+/// Get the smallest element of the vector, or `None` if it's empty. The first
+/// of several equal elements is returned.
+fn min(vec: &Vec) -> Result<Option<Value>, VmError> {
+    select_by(vec, Ordering::Less)
+}
+
+/// Get the largest element of the vector, or `None` if it's empty. The first
+/// of several equal elements is returned.
+fn max(vec: &Vec) -> Result<Option<Value>, VmError> {
+    select_by(vec, Ordering::Greater)
+}
+
+/// Select the element which compares as `wanted` to all others.
+fn select_by(vec: &Vec, wanted: Ordering) -> Result<Option<Value>, VmError> {
+    let mut it = vec.iter();
+
+    let mut selected = match it.next() {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    for value in it {
+        if compare_numeric(value, selected)? == wanted {
+            selected = value;
+        }
+    }
+
+    Ok(Some(selected.clone()))
+}
+
+/// Binary search a sorted vector for `value`, returning its index if present.
+///
+/// The vector must be sorted in ascending order according to the `<`
+/// operator, the result is unspecified if it isn't. If there are multiple
+/// matching elements, any one of their indexes may be returned.
+fn binary_search(vec: &Vec, value: Value) -> Result<Option<usize>, VmError> {
+    let mut error = None;
+
+    let result = vec.binary_search_by(|probe| {
+        if error.is_some() {
+            return Ordering::Equal;
+        }
+
+        match compare_numeric(probe, &value) {
+            Ok(ordering) => ordering,
+            Err(e) => {
+                error = Some(e);
+                Ordering::Equal
+            }
+        }
+    });
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(result.ok())
+}
+
+/// Compare two values the same way the `<` and `>` operators do.
+fn compare_numeric(a: &Value, b: &Value) -> Result<Ordering, VmError> {
+    let ordering = match (a, b) {
+        (Value::Integer(a), Value::Integer(b)) => Some(a.cmp(b)),
+        (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+        (a, b) => {
+            return Err(VmError::panic(format!(
+                "cannot compare `{}` and `{}`",
+                a.type_info()?,
+                b.type_info()?
+            )));
+        }
+    };
+
+    Ok(ordering.unwrap_or(Ordering::Equal))
+}
+
+/// Sum all elements of the vector, or `0` if it's empty.
+fn sum(vec: &Vec) -> Result<Value, VmError> {
+    fold_numeric(vec, "sum", 0, i64::checked_add, |a, b| a + b)
+}
+
+/// Multiply all elements of the vector, or `1` if it's empty.
+fn product(vec: &Vec) -> Result<Value, VmError> {
+    fold_numeric(vec, "multiply", 1, i64::checked_mul, |a, b| a * b)
+}
+
+/// Fold a vector of integers or floats using the given operations.
+fn fold_numeric(
+    vec: &Vec,
+    op: &str,
+    empty: i64,
+    integer_op: fn(i64, i64) -> Option<i64>,
+    float_op: fn(f64, f64) -> f64,
+) -> Result<Value, VmError> {
+    let mut it = vec.iter();
+
+    let mut acc = match it.next() {
+        Some(value @ Value::Integer(..)) | Some(value @ Value::Float(..)) => value.clone(),
+        Some(value) => {
+            return Err(VmError::panic(format!(
+                "cannot {} `{}`",
+                op,
+                value.type_info()?
+            )));
+        }
+        None => return Ok(Value::Integer(empty)),
+    };
+
+    for value in it {
+        acc = match (acc, value) {
+            (Value::Integer(a), Value::Integer(b)) => {
+                Value::Integer(integer_op(a, *b).ok_or(VmErrorKind::Overflow)?)
+            }
+            (Value::Float(a), Value::Float(b)) => Value::Float(float_op(a, *b)),
+            (a, b) => {
+                return Err(VmError::panic(format!(
+                    "cannot {} `{}` and `{}`",
+                    op,
+                    a.type_info()?,
+                    b.type_info()?
+                )));
+            }
+        };
+    }
+
+    Ok(acc)
+}
+
+/// Rotate the vector in place so that the element at `n` becomes the first
+/// element. `n` wraps around the length of the vector.
+fn rotate_left(vec: &mut Vec, n: usize) {
+    if !vec.is_empty() {
+        let n = n % vec.len();
+        vec.rotate_left(n);
+    }
+}
+
+/// Rotate the vector in place so that the last `n` elements come first. `n`
+/// wraps around the length of the vector.
+fn rotate_right(vec: &mut Vec, n: usize) {
+    if !vec.is_empty() {
+        let n = n % vec.len();
+        vec.rotate_right(n);
+    }
+}
+
+/// Swap the elements at indexes `a` and `b`, erroring if either of them is out
+/// of range.
+fn swap(vec: &mut Vec, a: usize, b: usize) -> Result<(), VmError> {
+    for index in [a, b].iter().copied() {
+        if index >= vec.len() {
+            return Err(VmError::from(VmErrorKind::OutOfRange {
+                index: index.into(),
+                len: vec.len().into(),
+            }));
+        }
+    }
+
+    vec.swap(a, b);
+    Ok(())
+}
+
+/// Set every element in the vector to `value`.
+fn fill(vec: &mut Vec, value: Value) {
+    for v in vec.iter_mut() {
+        *v = value.clone();
+    }
+}
+
+/// Sort a vector in place using a comparator function.
+///
+/// The comparator returns either an integer, where a negative value means
+/// less, zero means equal and a positive value means greater, or an ordering.
+/// The first error raised by the comparator aborts the sort.
+fn raw_sort_by(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    if args != 2 {
+        return Err(VmError::from(VmErrorKind::BadArgumentCount {
+            actual: args,
+            expected: 2,
+        }));
+    }
+
+    let comparator = stack.pop()?.into_function()?;
+    let vec = stack.pop()?.into_vec()?;
+
+    let comparator = comparator.borrow_ref()?;
+    let mut vec = vec.borrow_mut()?;
+    let mut error = None;
+
+    vec.sort_by(|a, b| {
+        if error.is_some() {
+            return Ordering::Equal;
+        }
+
+        match compare_by(&comparator, a, b) {
+            Ok(ordering) => ordering,
+            Err(e) => {
+                error = Some(e);
+                Ordering::Equal
+            }
+        }
+    });
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    stack.push(Value::Unit);
+    Ok(())
+}
+
+/// Call the comparator and convert its result into an ordering.
+fn compare_by(comparator: &Function, a: &Value, b: &Value) -> Result<Ordering, VmError> {
+    match comparator.call::<_, Value>((a.clone(), b.clone()))? {
+        Value::Integer(n) => Ok(n.cmp(&0)),
+        value => Ordering::from_value(value),
+    }
+}
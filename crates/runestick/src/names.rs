@@ -49,6 +49,94 @@ impl Names {
         mem::replace(&mut current.term, true)
     }
 
+    /// Insert the given item as an import, erroring with the path of the
+    /// already present item if it's already been inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{IntoComponent as _, Names};
+    ///
+    /// let mut names = Names::new();
+    /// assert!(names.insert_checked(&["std", "test"]).is_ok());
+    ///
+    /// let existing = names.insert_checked(&["std", "test"]).unwrap_err();
+    /// assert_eq!(existing, vec!["std".into_component(), "test".into_component()]);
+    /// ```
+    pub fn insert_checked<I>(&mut self, iter: I) -> Result<(), Vec<Component>>
+    where
+        I: IntoIterator,
+        I::Item: IntoComponent,
+    {
+        let mut current = &mut self.root;
+        let mut path = Vec::new();
+
+        for c in iter {
+            let c = c.into_component();
+            path.push(c.clone());
+            current = current.children.entry(c).or_default();
+        }
+
+        if mem::replace(&mut current.term, true) {
+            return Err(path);
+        }
+
+        Ok(())
+    }
+
+    /// Remove the given item, returning `true` if it was present.
+    ///
+    /// Nodes which are left without a terminal and without children are
+    /// pruned, so prefixes that only existed because of the removed item no
+    /// longer match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Names;
+    ///
+    /// let mut names = Names::new();
+    /// names.insert(&["std", "a", "b"]);
+    /// names.insert(&["std", "c"]);
+    ///
+    /// assert!(names.remove(&["std", "a", "b"]));
+    /// assert!(!names.remove(&["std", "a", "b"]));
+    /// assert!(!names.contains_prefix(&["std", "a"]));
+    /// assert!(names.contains(&["std", "c"]));
+    /// ```
+    pub fn remove<I>(&mut self, iter: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: IntoComponent,
+    {
+        let path = iter
+            .into_iter()
+            .map(IntoComponent::into_component)
+            .collect::<Vec<_>>();
+
+        return remove(&mut self.root, &path);
+
+        fn remove(node: &mut Node, path: &[Component]) -> bool {
+            let (first, rest) = match path.split_first() {
+                Some(split) => split,
+                None => return mem::take(&mut node.term),
+            };
+
+            let child = match node.children.get_mut(first) {
+                Some(child) => child,
+                None => return false,
+            };
+
+            let removed = remove(child, rest);
+
+            if !child.term && child.children.is_empty() {
+                node.children.remove(first);
+            }
+
+            removed
+        }
+    }
+
     /// Test if the given import exists.
     ///
     /// # Examples
@@ -246,4 +246,56 @@ impl Protocol {
         name: "into_type_name",
         hash: Hash::new(0xbffd08b816c24682),
     };
+
+    /// Function called when a value is explicitly dropped, like through
+    /// `std::mem::drop`.
+    pub const DROP: Protocol = Protocol {
+        name: "drop",
+        hash: Hash::new(0x6f4b37e1a2c9d058),
+    };
+
+    /// Get the binary operator protocol which is implemented by an instance
+    /// function with the given name, like `add` for [Protocol::ADD].
+    ///
+    /// ```rust
+    /// use runestick::Protocol;
+    ///
+    /// assert_eq!(Protocol::from_operator_fn("mul"), Some(Protocol::MUL));
+    /// assert_eq!(Protocol::from_operator_fn("shl"), Some(Protocol::SHL));
+    /// assert_eq!(Protocol::from_operator_fn("len"), None);
+    /// ```
+    pub fn from_operator_fn(name: &str) -> Option<Protocol> {
+        Some(match name {
+            "add" => Self::ADD,
+            "sub" => Self::SUB,
+            "mul" => Self::MUL,
+            "div" => Self::DIV,
+            "rem" => Self::REM,
+            "bit_and" => Self::BIT_AND,
+            "bit_xor" => Self::BIT_XOR,
+            "bit_or" => Self::BIT_OR,
+            "shl" => Self::SHL,
+            "shr" => Self::SHR,
+            _ => return None,
+        })
+    }
+
+    /// Get the iteration protocol which is implemented by an instance function
+    /// with the given name, like `iter` for [Protocol::INTO_ITER] and `next`
+    /// for [Protocol::NEXT].
+    ///
+    /// ```rust
+    /// use runestick::Protocol;
+    ///
+    /// assert_eq!(Protocol::from_iteration_fn("iter"), Some(Protocol::INTO_ITER));
+    /// assert_eq!(Protocol::from_iteration_fn("next"), Some(Protocol::NEXT));
+    /// assert_eq!(Protocol::from_iteration_fn("len"), None);
+    /// ```
+    pub fn from_iteration_fn(name: &str) -> Option<Protocol> {
+        Some(match name {
+            "iter" => Self::INTO_ITER,
+            "next" => Self::NEXT,
+            _ => return None,
+        })
+    }
 }
@@ -20,6 +20,11 @@ pub struct RuntimeContext {
 
     /// Named constant values
     pub(crate) constants: HashMap<Hash, ConstValue>,
+
+    /// Reverse index from items to the hashes of their functions, for
+    /// functions whose hash can't be calculated from their item, like
+    /// instance functions.
+    pub(crate) items: HashMap<Item, Hash>,
 }
 
 impl RuntimeContext {
@@ -38,6 +43,39 @@ impl RuntimeContext {
         self.functions.get(&hash)
     }
 
+    /// Lookup the native function handler for the function with the given
+    /// item path.
+    ///
+    /// Instance functions are looked up through the item of their type, like
+    /// `std::vec::Vec::len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Context, Item};
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let context = Context::with_default_modules()?.runtime();
+    ///
+    /// let len = Item::with_crate_item("std", &["vec", "Vec", "len"]);
+    /// assert!(context.lookup_by_item(&len).is_some());
+    ///
+    /// let new = Item::with_crate_item("std", &["string", "String", "new"]);
+    /// assert!(context.lookup_by_item(&new).is_some());
+    ///
+    /// let missing = Item::with_crate_item("std", &["missing"]);
+    /// assert!(context.lookup_by_item(&missing).is_none());
+    /// # Ok(()) }
+    /// ```
+    pub fn lookup_by_item(&self, item: &Item) -> Option<&Arc<Handler>> {
+        let hash = match self.items.get(item) {
+            Some(hash) => *hash,
+            None => Hash::type_hash(item),
+        };
+
+        self.functions.get(&hash)
+    }
+
     /// Read a constant value from the unit.
     pub fn constant(&self, hash: Hash) -> Option<&ConstValue> {
         self.constants.get(&hash)
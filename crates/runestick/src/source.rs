@@ -16,6 +16,11 @@ pub struct Source {
     path: Option<PathBuf>,
     /// The starting byte indices in the source code.
     line_starts: Vec<usize>,
+    /// The line in the host file at which this source starts.
+    base_line: usize,
+    /// The column in the host file at which the first line of this source
+    /// starts.
+    base_column: usize,
 }
 
 impl Source {
@@ -33,9 +38,48 @@ impl Source {
             source: source.to_owned(),
             path: None,
             line_starts,
+            base_line: 0,
+            base_column: 0,
         }
     }
 
+    /// Set the position in a host file at which this source starts, as a
+    /// zero-based `line` and `column`.
+    ///
+    /// This is used when the source is a fragment embedded in a larger
+    /// document, so that reported positions refer to the host document rather
+    /// than the fragment. The column only applies to the first line of the
+    /// source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Source;
+    ///
+    /// let source = Source::new("template", "let a = 1;\nlet b = 2;").with_base(10, 4);
+    ///
+    /// assert_eq!(source.position_to_unicode_line_char(4), (10, 8));
+    /// assert_eq!(source.position_to_unicode_line_char(15), (11, 4));
+    /// ```
+    pub fn with_base(self, line: usize, column: usize) -> Self {
+        Self {
+            base_line: line,
+            base_column: column,
+            ..self
+        }
+    }
+
+    /// Get the line in the host file at which this source starts.
+    pub fn base_line(&self) -> usize {
+        self.base_line
+    }
+
+    /// Get the column in the host file at which the first line of this source
+    /// starts.
+    pub fn base_column(&self) -> usize {
+        self.base_column
+    }
+
     /// Access all line starts in the source.
     pub fn line_starts(&self) -> &[usize] {
         &self.line_starts
@@ -54,6 +98,8 @@ impl Source {
             source,
             path: Some(path.to_owned()),
             line_starts,
+            base_line: 0,
+            base_column: 0,
         })
     }
 
@@ -106,7 +152,55 @@ impl Source {
     }
 
     /// Convert the given offset to a utf-16 line and character.
+    ///
+    /// The position is adjusted by the base configured with
+    /// [with_base][Self::with_base].
     pub fn position_to_utf16cu_line_char(&self, offset: usize) -> Option<(usize, usize)> {
+        let (line, character) = self.local_utf16cu_line_char(offset)?;
+        Some(self.with_base_position(line, character))
+    }
+
+    /// Convert the given offset to a unicode line and character.
+    ///
+    /// The position is adjusted by the base configured with
+    /// [with_base][Self::with_base].
+    pub fn position_to_unicode_line_char(&self, offset: usize) -> (usize, usize) {
+        let (line, character) = self.local_unicode_line_char(offset);
+        self.with_base_position(line, character)
+    }
+
+    /// Convert the given byte offset to a zero-based line and column, where
+    /// the column is counted in unicode characters.
+    ///
+    /// Lookups use the line index built when the source was constructed, so
+    /// this is cheap to call repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Source;
+    ///
+    /// let source = Source::new("entry", "let a = 1;\nlet ö = 2;\n");
+    ///
+    /// assert_eq!(source.line_col(0), (0, 0));
+    /// assert_eq!(source.line_col(4), (0, 4));
+    /// assert_eq!(source.line_col(11), (1, 0));
+    /// assert_eq!(source.line_col(17), (1, 5));
+    /// ```
+    pub fn line_col(&self, offset: usize) -> (usize, usize) {
+        self.position_to_unicode_line_char(offset)
+    }
+
+    /// Adjust a fragment-local line and character by the base of the source.
+    fn with_base_position(&self, line: usize, character: usize) -> (usize, usize) {
+        if line == 0 {
+            (self.base_line, self.base_column + character)
+        } else {
+            (self.base_line + line, character)
+        }
+    }
+
+    fn local_utf16cu_line_char(&self, offset: usize) -> Option<(usize, usize)> {
         if offset == 0 {
             return Some((0, 0));
         }
@@ -138,8 +232,7 @@ impl Source {
         Some((line, line_count))
     }
 
-    /// Convert the given offset to a utf-16 line and character.
-    pub fn position_to_unicode_line_char(&self, offset: usize) -> (usize, usize) {
+    fn local_unicode_line_char(&self, offset: usize) -> (usize, usize) {
         if offset == 0 {
             return (0, 0);
         }
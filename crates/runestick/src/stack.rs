@@ -145,6 +145,17 @@ impl Stack {
         })
     }
 
+    /// Swap the two values on the top of the stack.
+    pub fn swap_top(&mut self) -> Result<(), StackError> {
+        match self.stack.len().checked_sub(2) {
+            Some(n) if n >= self.stack_bottom => {
+                self.stack.swap(n, n + 1);
+                Ok(())
+            }
+            _ => Err(StackError(())),
+        }
+    }
+
     /// Pop the given number of elements from the stack.
     pub fn popn(&mut self, count: usize) -> Result<(), StackError> {
         drop(self.drain_stack_top(count)?);
@@ -212,6 +223,15 @@ impl Stack {
         Err(StackError(()))
     }
 
+    /// Unwind the stack to the given length and restore the given stack
+    /// bottom.
+    ///
+    /// This is used internally when an error is caught by an error handler.
+    pub(crate) fn unwind(&mut self, len: usize, stack_bottom: usize) {
+        self.stack.truncate(len);
+        self.stack_bottom = stack_bottom;
+    }
+
     /// Pop the current stack top and modify it to a different one.
     ///
     /// This asserts that the size of the current stack frame is exactly zero
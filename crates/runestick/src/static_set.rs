@@ -0,0 +1,103 @@
+use crate::collections::HashSet;
+use crate::{Value, VmError};
+use serde::{Deserialize, Serialize, Serializer};
+
+/// A static set of literal values.
+///
+/// This is used to efficiently test if a value is one of many literals in a
+/// pattern like `in [1, 2, 3]`, without having to compare against each literal
+/// in turn.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::{StaticSet, Value};
+///
+/// # fn main() -> runestick::Result<()> {
+/// let mut set = StaticSet::new();
+/// set.insert_integer(1);
+/// set.insert_string("foo");
+///
+/// assert!(set.contains(&Value::from(1i64))?);
+/// assert!(!set.contains(&Value::from(2i64))?);
+/// assert!(set.contains(&Value::from(String::from("foo")))?);
+/// assert!(!set.contains(&Value::from('a'))?);
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaticSet {
+    /// Integers in the set.
+    #[serde(serialize_with = "serialize_sorted")]
+    integers: HashSet<i64>,
+    /// Strings in the set.
+    #[serde(serialize_with = "serialize_sorted")]
+    strings: HashSet<String>,
+    /// Characters in the set.
+    #[serde(serialize_with = "serialize_sorted")]
+    chars: HashSet<char>,
+    /// Bytes in the set.
+    #[serde(serialize_with = "serialize_sorted")]
+    bytes: HashSet<u8>,
+    /// Booleans in the set.
+    bools: [bool; 2],
+}
+
+impl StaticSet {
+    /// Construct a new empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert an integer into the set.
+    pub fn insert_integer(&mut self, integer: i64) {
+        self.integers.insert(integer);
+    }
+
+    /// Insert a string into the set.
+    pub fn insert_string(&mut self, string: &str) {
+        self.strings.insert(string.to_owned());
+    }
+
+    /// Insert a character into the set.
+    pub fn insert_char(&mut self, c: char) {
+        self.chars.insert(c);
+    }
+
+    /// Insert a byte into the set.
+    pub fn insert_byte(&mut self, byte: u8) {
+        self.bytes.insert(byte);
+    }
+
+    /// Insert a boolean into the set.
+    pub fn insert_bool(&mut self, boolean: bool) {
+        self.bools[boolean as usize] = true;
+    }
+
+    /// Test if the set contains the given value.
+    ///
+    /// Values of a type which can't be stored in the set are never members of
+    /// it.
+    pub fn contains(&self, value: &Value) -> Result<bool, VmError> {
+        Ok(match value {
+            Value::Integer(integer) => self.integers.contains(integer),
+            Value::String(string) => self.strings.contains(string.borrow_ref()?.as_str()),
+            Value::StaticString(string) => self.strings.contains(string.as_str()),
+            Value::Char(c) => self.chars.contains(c),
+            Value::Byte(byte) => self.bytes.contains(byte),
+            Value::Bool(boolean) => self.bools[*boolean as usize],
+            _ => false,
+        })
+    }
+}
+
+/// Serialize a set in sorted order, so that the serialized form of a set
+/// doesn't depend on its iteration order.
+fn serialize_sorted<T, S>(set: &HashSet<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Ord + Serialize,
+    S: Serializer,
+{
+    let mut values = set.iter().collect::<Vec<_>>();
+    values.sort();
+    serializer.collect_seq(values)
+}
@@ -5,7 +5,8 @@
 
 use crate::collections::HashMap;
 use crate::{
-    Call, ConstValue, DebugInfo, Hash, Inst, Rtti, StaticString, VariantRtti, VmError, VmErrorKind,
+    Call, ConstValue, DebugInfo, Hash, Inst, Item, Rtti, StaticSet, StaticString, VariantRtti,
+    VmError, VmErrorKind,
 };
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -29,6 +30,11 @@ pub struct Unit {
     ///
     /// All keys are sorted with the default string sort.
     static_object_keys: Vec<Box<[String]>>,
+    /// Static sets of literals.
+    ///
+    /// This is used when matching against a set of literals in a pattern like
+    /// `in [1, 2, 3]`.
+    static_sets: Vec<StaticSet>,
     /// Runtime information for types.
     rtti: HashMap<Hash, Arc<Rtti>>,
     /// Runtime information for variants.
@@ -37,6 +43,8 @@ pub struct Unit {
     debug: Option<Box<DebugInfo>>,
     /// Named constants
     constants: HashMap<Hash, ConstValue>,
+    /// Functions marked with `#[test]`, in the order they were compiled.
+    tests: Vec<Item>,
 }
 
 impl Unit {
@@ -48,10 +56,12 @@ impl Unit {
         static_strings: Vec<Arc<StaticString>>,
         static_bytes: Vec<Vec<u8>>,
         static_object_keys: Vec<Box<[String]>>,
+        static_sets: Vec<StaticSet>,
         rtti: HashMap<Hash, Arc<Rtti>>,
         variant_rtti: HashMap<Hash, Arc<VariantRtti>>,
         debug: Option<Box<DebugInfo>>,
         constants: HashMap<Hash, ConstValue>,
+        tests: Vec<Item>,
     ) -> Self {
         Self {
             instructions,
@@ -59,10 +69,12 @@ impl Unit {
             static_strings,
             static_bytes,
             static_object_keys,
+            static_sets,
             rtti,
             variant_rtti,
             debug,
             constants,
+            tests,
         }
     }
 
@@ -72,6 +84,19 @@ impl Unit {
         Some(&**debug)
     }
 
+    /// Calculate a hash of the contents of the unit, including its
+    /// instructions, static data, and debug information.
+    ///
+    /// The hash is deterministic, so units compiled from identical sources
+    /// with identical options have the same content hash. This makes it
+    /// suitable for cache invalidation and for checking that builds are
+    /// reproducible.
+    pub fn content_hash(&self) -> Hash {
+        // NB: none of the data stored in a unit raises custom errors when
+        // it's serialized.
+        crate::content_hash::content_hash(self).expect("unit contents are always hashable")
+    }
+
     /// Get the instruction at the given instruction pointer.
     pub fn instruction_at(&self, ip: usize) -> Option<&Inst> {
         self.instructions.get(ip)
@@ -102,6 +127,14 @@ impl Unit {
         self.functions.iter().map(|(h, f)| (*h, f))
     }
 
+    /// Iterate over the items of all functions marked with `#[test]`.
+    ///
+    /// The functions can be called through the hash of their item, like with
+    /// [Hash::type_hash].
+    pub fn iter_tests(&self) -> impl Iterator<Item = &Item> + '_ {
+        self.tests.iter()
+    }
+
     /// Lookup the static string by slot, if it exists.
     pub fn lookup_string(&self, slot: usize) -> Result<&Arc<StaticString>, VmError> {
         Ok(self
@@ -124,6 +157,14 @@ impl Unit {
         self.static_object_keys.get(slot).map(|keys| &keys[..])
     }
 
+    /// Lookup the static set by slot, if it exists.
+    pub fn lookup_set(&self, slot: usize) -> Result<&StaticSet, VmError> {
+        Ok(self
+            .static_sets
+            .get(slot)
+            .ok_or_else(|| VmErrorKind::MissingStaticSet { slot })?)
+    }
+
     /// Lookup runt-time information for the given type hash.
     pub fn lookup_rtti(&self, hash: Hash) -> Option<&Arc<Rtti>> {
         self.rtti.get(&hash)
@@ -118,6 +118,20 @@ impl Struct {
         self.rtti.hash
     }
 
+    /// Iterate over the fields of the struct in declaration order.
+    ///
+    /// Returns `None` unless the struct has a `#[repr]` layout hint, in which
+    /// case the field at index `n` is the `n`th field of the declaration.
+    pub fn ordered_fields(&self) -> Option<impl std::iter::Iterator<Item = (&str, &Value)> + '_> {
+        let fields = self.rtti.ordered_fields.as_ref()?;
+
+        Some(
+            fields
+                .iter()
+                .filter_map(move |field| Some((&**field, self.data.get(&**field)?))),
+        )
+    }
+
     /// Get the given key in the object.
     pub fn get<Q: ?Sized>(&self, k: &Q) -> Option<&Value>
     where
@@ -187,6 +201,8 @@ pub struct Rtti {
     pub hash: Hash,
     /// The item of the type.
     pub item: Item,
+    /// Fields in declaration order, if the type has a `#[repr]` layout hint.
+    pub ordered_fields: Option<Box<[Box<str>]>>,
 }
 
 impl cmp::PartialEq for Rtti {
@@ -1459,6 +1475,22 @@ impl<'de> de::Visitor<'de> for VmVisitor {
         Ok(Value::Integer(v as i64))
     }
 
+    #[inline]
+    fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Value::Float(v as f64))
+    }
+
+    #[inline]
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Value::Float(v))
+    }
+
     #[inline]
     fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
     where
@@ -1475,6 +1507,14 @@ impl<'de> de::Visitor<'de> for VmVisitor {
         Ok(Value::Unit)
     }
 
+    #[inline]
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        de::Deserialize::deserialize(deserializer)
+    }
+
     #[inline]
     fn visit_unit<E>(self) -> Result<Self::Value, E>
     where
@@ -142,6 +142,18 @@ impl Vec {
         self.inner.clear();
     }
 
+    /// Shortens the vector, keeping the first `len` elements and dropping the
+    /// rest. Has no effect if `len` is greater than or equal to the current
+    /// length.
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
+
+    /// Clones and appends all elements in a slice to the vector.
+    pub fn extend_from_slice(&mut self, values: &[Value]) {
+        self.inner.extend_from_slice(values);
+    }
+
     /// Inserts an element at position index within the vector, shifting all
     /// elements after it to the right.
     pub fn insert(&mut self, index: usize, value: Value) {
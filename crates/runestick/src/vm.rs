@@ -2,13 +2,14 @@ use crate::budget;
 use crate::future::SelectFuture;
 use crate::unit::UnitFn;
 use crate::{
-    Args, Awaited, BorrowMut, Bytes, Call, Format, FormatSpec, FromValue, Function, Future,
-    Generator, GuardedArgs, Hash, Inst, InstAddress, InstAssignOp, InstFnNameHash, InstOp,
+    Args, Awaited, BorrowMut, Bytes, Call, CaughtError, Format, FormatSpec, FromValue, Function,
+    Future, Generator, GuardedArgs, Hash, Inst, InstAddress, InstAssignOp, InstFnNameHash, InstOp,
     InstRangeLimits, InstTarget, InstValue, InstVariant, IntoTypeHash, Object, Panic, Protocol,
     Range, RangeLimits, RuntimeContext, Select, Shared, Stack, Stream, Struct, Tuple, TypeCheck,
     Unit, UnitStruct, Value, Variant, VariantData, Vec, VmError, VmErrorKind, VmExecution, VmHalt,
     VmIntegerRepr, VmSendExecution,
 };
+use std::cmp::Ordering;
 use std::fmt;
 use std::mem;
 use std::sync::Arc;
@@ -71,6 +72,8 @@ pub struct Vm {
     pub(crate) stack: Stack,
     /// Frames relative to the stack.
     call_frames: vec::Vec<CallFrame>,
+    /// Error handlers pushed by `try` blocks.
+    try_frames: vec::Vec<TryFrame>,
 }
 
 impl Vm {
@@ -91,6 +94,7 @@ impl Vm {
             ip: 0,
             stack,
             call_frames: vec::Vec::new(),
+            try_frames: vec::Vec::new(),
         }
     }
 
@@ -166,6 +170,7 @@ impl Vm {
         self.ip = 0;
         self.stack.clear();
         self.call_frames.clear();
+        self.try_frames.clear();
     }
 
     /// Modify the current instruction pointer.
@@ -341,6 +346,72 @@ impl Vm {
         Ok(value)
     }
 
+    /// Call the instance function `name` on the given `instance` immediately,
+    /// returning the produced value.
+    ///
+    /// The function is looked up based on the type of `instance`, in the unit
+    /// first and then in the context. The call happens in a separate virtual
+    /// machine sharing the context and unit of this one.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [VmErrorKind::MissingInstanceFunction] if the type of
+    /// `instance` doesn't have an instance function named `name`.
+    ///
+    /// # Panics
+    ///
+    /// See [Vm::call].
+    pub fn call_instance<A, T>(&self, instance: Value, name: &str, args: A) -> Result<T, VmError>
+    where
+        A: GuardedArgs,
+        T: FromValue,
+    {
+        let mut vm = Self::new(self.context.clone(), self.unit.clone());
+
+        let count = args.count() + 1;
+        let hash = Hash::instance_function(instance.type_hash()?, name.inst_fn_name_hash());
+
+        let value = match (self.unit.lookup(hash), self.context.lookup(hash)) {
+            (
+                Some(UnitFn::Offset {
+                    offset,
+                    args: expected,
+                    ..
+                }),
+                _,
+            ) => {
+                Self::check_args(count, expected)?;
+                vm.ip = offset;
+                vm.stack.push(instance);
+
+                // Safety: We hold onto the guard until the vm has completed.
+                let guard = unsafe { args.unsafe_into_stack(&mut vm.stack)? };
+                let value = vm.into_execution().complete()?;
+                drop(guard);
+                value
+            }
+            (None, Some(handler)) => {
+                vm.stack.push(instance);
+
+                // Safety: We hold onto the guard until the handler has
+                // completed.
+                let guard = unsafe { args.unsafe_into_stack(&mut vm.stack)? };
+                handler(&mut vm.stack, count)?;
+                let value = vm.stack.pop()?;
+                drop(guard);
+                value
+            }
+            _ => {
+                return Err(VmError::from(VmErrorKind::MissingInstanceFunction {
+                    instance: instance.type_info()?,
+                    hash,
+                }));
+            }
+        };
+
+        T::from_value(value)
+    }
+
     // Update the instruction pointer to match the function matching the given
     // name and check that the number of argument matches.
     fn set_entrypoint<N>(&mut self, name: N, count: usize) -> Result<(), VmError>
@@ -509,9 +580,89 @@ impl Vm {
 
         self.stack.pop_stack_top(frame.stack_bottom)?;
         self.ip = frame.ip;
+
+        // NB: error handlers which belong to the returning call frame are no
+        // longer reachable.
+        let depth = self.call_frames.len();
+
+        while matches!(self.try_frames.last(), Some(t) if t.depth > depth) {
+            self.try_frames.pop();
+        }
+
         Ok(false)
     }
 
+    /// Push an error handler.
+    #[cfg_attr(feature = "bench", inline(never))]
+    fn op_push_try(&mut self, offset: isize) -> Result<(), VmError> {
+        let depth = self.call_frames.len();
+
+        // NB: any handler in the same call frame whose region we are not
+        // currently inside of has been left without being popped, like when
+        // breaking out of a `try` block.
+        while matches!(self.try_frames.last(), Some(t) if t.depth == depth && !t.contains(self.ip))
+        {
+            self.try_frames.pop();
+        }
+
+        self.try_frames.push(TryFrame {
+            start: self.ip,
+            handler: self.ip.overflowing_add(1).0.wrapping_add(offset as usize),
+            depth,
+            stack_len: self.stack.len(),
+            stack_bottom: self.stack.stack_bottom(),
+        });
+
+        Ok(())
+    }
+
+    /// Pop the most recently pushed error handler.
+    #[cfg_attr(feature = "bench", inline(never))]
+    fn op_pop_try(&mut self) -> Result<(), VmError> {
+        self.try_frames.pop();
+        Ok(())
+    }
+
+    /// Try to catch the given error with the innermost active error handler.
+    ///
+    /// If the error is caught, the virtual machine is unwound to the state it
+    /// was in when the handler was pushed and the error is pushed onto the
+    /// stack as a [CaughtError], which retains its kind and the
+    /// location it was raised at. Otherwise the error is returned.
+    fn catch_error(&mut self, error: VmError) -> Result<(), VmError> {
+        if !error.kind().is_catchable() {
+            return Err(error);
+        }
+
+        let frame = loop {
+            let frame = match self.try_frames.pop() {
+                Some(frame) => frame,
+                None => return Err(error),
+            };
+
+            // NB: the instruction pointer inside of the call frame which
+            // pushed the handler.
+            let ip = match self.call_frames.get(frame.depth) {
+                Some(call_frame) => call_frame.ip,
+                None => self.ip,
+            };
+
+            if frame.contains(ip) {
+                break frame;
+            }
+        };
+
+        // NB: record where the error was raised before unwinding.
+        let error = error.into_unwinded(&self.unit, self.ip, self.call_frames.clone());
+
+        self.call_frames.truncate(frame.depth);
+        self.stack.unwind(frame.stack_len, frame.stack_bottom);
+
+        self.stack.push(CaughtError::new(error));
+        self.ip = frame.handler;
+        Ok(())
+    }
+
     /// Implementation of getting a string index on an object-like type.
     fn try_object_like_index_get(target: &Value, field: &str) -> Result<Option<Value>, VmError> {
         let value = match &target {
@@ -1472,6 +1623,27 @@ impl Vm {
         Ok(())
     }
 
+    /// Spread the vector on the top of the stack out into positional
+    /// arguments, returning the total number of arguments of the call it
+    /// belongs to.
+    #[cfg_attr(feature = "bench", inline(never))]
+    fn op_spread(&mut self, args: usize) -> Result<usize, VmError> {
+        let vec = self.stack.pop()?.into_vec()?;
+        let vec = vec.borrow_ref()?;
+        self.stack.extend(vec.iter().cloned());
+        Ok(args - 1 + vec.len())
+    }
+
+    /// Swap the two values on the top of the stack.
+    #[cfg_attr(feature = "bench", inline(never))]
+    fn op_swap(&mut self) -> Result<(), VmError> {
+        if self.stack.swap_top().is_err() {
+            return Err(VmError::from(VmErrorKind::StackUnderflow));
+        }
+
+        Ok(())
+    }
+
     /// Copy a value from a position relative to the top of the stack, to the
     /// top of the stack.
     #[cfg_attr(feature = "bench", inline(never))]
@@ -2058,6 +2230,27 @@ impl Vm {
         Ok(())
     }
 
+    /// Compare the top of the stack against the given bound of a range
+    /// pattern, where values of different types never match.
+    #[cfg_attr(feature = "bench", inline(never))]
+    fn op_match_bound(
+        &mut self,
+        bound: InstValue,
+        test: impl FnOnce(Ordering) -> bool,
+    ) -> Result<(), VmError> {
+        let value = self.stack.pop()?;
+
+        let ordering = match (value, bound) {
+            (Value::Byte(actual), InstValue::Byte(bound)) => Some(actual.cmp(&bound)),
+            (Value::Char(actual), InstValue::Char(bound)) => Some(actual.cmp(&bound)),
+            (Value::Integer(actual), InstValue::Integer(bound)) => Some(actual.cmp(&bound)),
+            _ => None,
+        };
+
+        self.stack.push(ordering.map(test).unwrap_or_default());
+        Ok(())
+    }
+
     /// Perform a specialized index get operation on an object.
     #[cfg_attr(feature = "bench", inline(never))]
     fn op_object_index_get(&mut self, string_slot: usize) -> Result<(), VmError> {
@@ -2373,6 +2566,16 @@ impl Vm {
         Ok(())
     }
 
+    /// Test if the top of stack is a member of the set at the given static set
+    /// location.
+    #[cfg_attr(feature = "bench", inline(never))]
+    fn op_in_static_set(&mut self, slot: usize) -> Result<(), VmError> {
+        let value = self.stack.pop()?;
+        let contains = self.unit.lookup_set(slot)?.contains(&value)?;
+        self.stack.push(Value::Bool(contains));
+        Ok(())
+    }
+
     #[cfg_attr(feature = "bench", inline(never))]
     fn op_match_sequence(&mut self, ty: TypeCheck, len: usize, exact: bool) -> Result<(), VmError> {
         let value = self.stack.pop()?;
@@ -2716,6 +2919,17 @@ impl Vm {
         // unit.
         let _guard = crate::env::Guard::new(&self.context, &self.unit);
 
+        loop {
+            match self.run_until_error() {
+                Ok(halt) => return Ok(halt),
+                Err(error) => self.catch_error(error)?,
+            }
+        }
+    }
+
+    /// Evaluate instructions until the virtual machine halts or an error is
+    /// raised.
+    fn run_until_error(&mut self) -> Result<VmHalt, VmError> {
         loop {
             if !budget::take() {
                 return Ok(VmHalt::Limited);
@@ -2749,6 +2963,23 @@ impl Vm {
                         return Ok(reason);
                     }
                 }
+                Inst::CallSpread { hash, args } => {
+                    let args = self.op_spread(args)?;
+                    self.op_call(hash, args)?;
+                }
+                Inst::CallInstanceSpread { hash, args } => {
+                    let args = self.op_spread(args)?;
+                    self.op_call_instance(hash, args)?;
+                }
+                Inst::CallFnSpread { args } => {
+                    let function = self.stack.pop()?;
+                    let args = self.op_spread(args)?;
+                    self.stack.push(function);
+
+                    if let Some(reason) = self.op_call_fn(args)? {
+                        return Ok(reason);
+                    }
+                }
                 Inst::LoadInstanceFn { hash } => {
                     self.op_load_instance_fn(hash)?;
                 }
@@ -2829,6 +3060,9 @@ impl Vm {
                 Inst::Dup => {
                     self.op_dup()?;
                 }
+                Inst::Swap => {
+                    self.op_swap()?;
+                }
                 Inst::Replace { offset } => {
                     self.op_replace(offset)?;
                 }
@@ -2919,9 +3153,21 @@ impl Vm {
                 Inst::EqBool { boolean } => {
                     self.op_eq_bool(boolean)?;
                 }
+                Inst::GteValue { value } => {
+                    self.op_match_bound(value, |o| o != Ordering::Less)?;
+                }
+                Inst::LteValue { value } => {
+                    self.op_match_bound(value, |o| o != Ordering::Greater)?;
+                }
+                Inst::LtValue { value } => {
+                    self.op_match_bound(value, |o| o == Ordering::Less)?;
+                }
                 Inst::EqStaticString { slot } => {
                     self.op_eq_static_string(slot)?;
                 }
+                Inst::InStaticSet { slot } => {
+                    self.op_in_static_set(slot)?;
+                }
                 Inst::MatchSequence {
                     type_check,
                     len,
@@ -2957,6 +3203,12 @@ impl Vm {
                 Inst::IterNext { offset, jump } => {
                     self.op_iter_next(offset, jump)?;
                 }
+                Inst::PushTry { offset } => {
+                    self.op_push_try(offset)?;
+                }
+                Inst::PopTry => {
+                    self.op_pop_try()?;
+                }
                 Inst::Panic { reason } => {
                     return Err(VmError::from(VmErrorKind::Panic {
                         reason: Panic::from(reason),
@@ -2995,3 +3247,84 @@ impl CallFrame {
         self.stack_bottom
     }
 }
+
+/// An error handler pushed by a `try` block.
+#[derive(Debug, Clone, Copy)]
+struct TryFrame {
+    /// The instruction pointer of the instruction which pushed the handler.
+    start: usize,
+    /// The instruction pointer of the error handler.
+    handler: usize,
+    /// The number of call frames when the handler was pushed.
+    depth: usize,
+    /// The length of the stack when the handler was pushed.
+    stack_len: usize,
+    /// The bottom of the stack when the handler was pushed.
+    stack_bottom: usize,
+}
+
+impl TryFrame {
+    /// Test if the given instruction pointer is inside of the region guarded by
+    /// this handler.
+    fn contains(&self, ip: usize) -> bool {
+        self.start < ip && ip < self.handler
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Vm;
+    use crate::{FromValue as _, Inst, InstValue, RuntimeContext, Unit, VmErrorKind};
+    use std::sync::Arc;
+
+    fn vm(instructions: Vec<Inst>) -> Vm {
+        let unit = Unit::new(
+            instructions,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            None,
+            Default::default(),
+            Default::default(),
+        );
+
+        Vm::new(Arc::new(RuntimeContext::default()), Arc::new(unit))
+    }
+
+    #[test]
+    fn test_swap() {
+        let vm = vm(vec![
+            Inst::Push {
+                value: InstValue::Integer(1),
+            },
+            Inst::Push {
+                value: InstValue::Integer(2),
+            },
+            Inst::Swap,
+            Inst::Vec { count: 2 },
+            Inst::Return,
+        ]);
+
+        let output = vm.complete().unwrap();
+        assert_eq!(Vec::<i64>::from_value(output).unwrap(), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_swap_underflow() {
+        let vm = vm(vec![
+            Inst::Push {
+                value: InstValue::Integer(1),
+            },
+            Inst::Swap,
+            Inst::Return,
+        ]);
+
+        let error = vm.complete().unwrap_err();
+        let (kind, _) = error.as_unwound();
+        assert!(matches!(kind, VmErrorKind::StackUnderflow));
+    }
+}
@@ -1,12 +1,59 @@
-use crate::{panic::BoxedPanic, CallFrame};
+use crate::{panic::BoxedPanic, Any, CallFrame};
 use crate::{
-    AccessError, Hash, Item, Key, Panic, Protocol, StackError, TypeInfo, TypeOf, Unit, Value,
-    VmHaltInfo,
+    AccessError, Hash, Item, Key, Location, Panic, Protocol, StackError, TypeInfo, TypeOf, Unit,
+    Value, VmHaltInfo,
 };
 use std::fmt;
 use std::sync::Arc;
 use thiserror::Error;
 
+/// An error which has been caught by a `try` block, and is bound to the
+/// variable of its `catch` clause.
+///
+/// The caught error retains its kind, and the location it was raised at if it
+/// was unwound.
+#[derive(Any, Debug)]
+#[rune(module = "crate")]
+pub struct CaughtError {
+    error: VmError,
+}
+
+impl CaughtError {
+    /// Construct a new caught error.
+    pub fn new(error: VmError) -> Self {
+        Self { error }
+    }
+
+    /// Access the kind of the caught error, looking through any unwinding.
+    pub fn kind(&self) -> &VmErrorKind {
+        self.error.as_unwound().0
+    }
+
+    /// Get the message of the caught error, which for panics is the reason of
+    /// the panic.
+    pub fn message(&self) -> String {
+        match self.kind() {
+            VmErrorKind::Panic { reason } => reason.to_string(),
+            kind => kind.to_string(),
+        }
+    }
+
+    /// Get the location the error was raised at, if it's available.
+    pub fn first_location(&self) -> Option<Location> {
+        self.error.first_location()
+    }
+
+    /// Access the underlying error.
+    pub fn error(&self) -> &VmError {
+        &self.error
+    }
+
+    /// Convert into the underlying error.
+    pub fn into_error(self) -> VmError {
+        self.error
+    }
+}
+
 /// Errors raised by the execution of the virtual machine.
 #[derive(Error, Debug)]
 #[error(transparent)]
@@ -111,6 +158,24 @@ impl VmError {
         }
     }
 
+    /// Get the location the error was raised at, if the error has been unwound
+    /// and the unit has debug information.
+    ///
+    /// Errors raised inside of functions marked with `#[track_caller]` are
+    /// reported at the location the function was called from.
+    pub fn first_location(&self) -> Option<Location> {
+        let (unit, ip, frames) = match &*self.kind {
+            VmErrorKind::Unwound {
+                unit, ip, frames, ..
+            } => (unit, *ip, frames),
+            _ => return None,
+        };
+
+        let frames = frames.iter().map(CallFrame::ip).collect::<Vec<_>>();
+        let debug_inst = unit.debug_info()?.location_at(ip, &frames)?;
+        Some(Location::new(debug_inst.source_id, debug_inst.span))
+    }
+
     /// Unsmuggles the vm error, returning Ok(Self) in case the error is
     /// critical and should be propagated unaltered.
     pub fn unpack_critical(self) -> Result<Self, Self> {
@@ -144,191 +209,256 @@ where
     }
 }
 
-/// The kind of error encountered.
-#[allow(missing_docs)]
-#[derive(Debug, Error)]
-pub enum VmErrorKind {
-    /// A vm error that was propagated from somewhere else.
-    ///
-    /// In order to represent this, we need to preserve the instruction pointer
-    /// and eventually unit from where the error happened.
-    #[error("{kind} (at inst {ip})")]
-    Unwound {
-        /// The wrapper error.
-        kind: Box<VmErrorKind>,
-        /// Associated unit.
-        unit: Arc<Unit>,
-        /// The instruction pointer of where the original error happened.
-        ip: usize,
-        /// All lower call frames before the unwind trigger point
-        frames: Vec<CallFrame>,
-    },
-    #[error("{error}")]
-    AccessError {
-        #[from]
-        error: AccessError,
-    },
-    #[error("panicked: {reason}")]
-    Panic { reason: Panic },
-    #[error("no running virtual machines")]
-    NoRunningVm,
-    #[error("halted for unexpected reason `{halt}`")]
-    Halted { halt: VmHaltInfo },
-    #[error("failed to format argument")]
-    FormatError,
-    #[error("stack error: {error}")]
-    StackError {
-        #[from]
-        error: StackError,
-    },
-    #[error("numerical overflow")]
-    Overflow,
-    #[error("numerical underflow")]
-    Underflow,
-    #[error("division by zero")]
-    DivideByZero,
-    #[error("missing constant with hash `{hash}`")]
-    MissingConst { hash: Hash },
-    #[error("missing entry `{item}` with hash `{hash}`")]
-    MissingEntry { item: Item, hash: Hash },
-    #[error("missing function with hash `{hash}`")]
-    MissingFunction { hash: Hash },
-    #[error("missing instance function `{hash}` for `{instance}`")]
-    MissingInstanceFunction { hash: Hash, instance: TypeInfo },
-    #[error("instruction pointer is out-of-bounds")]
-    IpOutOfBounds,
-    #[error("unsupported vm operation `{lhs} {op} {rhs}`")]
-    UnsupportedBinaryOperation {
-        op: &'static str,
-        lhs: TypeInfo,
-        rhs: TypeInfo,
-    },
-    #[error("unsupported vm operation `{op}{operand}`")]
-    UnsupportedUnaryOperation { op: &'static str, operand: TypeInfo },
-    #[error("`{actual}` does not implement the `{protocol}` protocol")]
-    MissingProtocol {
-        protocol: Protocol,
-        actual: TypeInfo,
-    },
-    #[error("static string slot `{slot}` does not exist")]
-    MissingStaticString { slot: usize },
-    #[error("static object keys slot `{slot}` does not exist")]
-    MissingStaticObjectKeys { slot: usize },
-    #[error("missing runtime information for variant with hash `{hash}`")]
-    MissingVariantRtti { hash: Hash },
-    #[error("missing runtime information for type with hash `{hash}`")]
-    MissingRtti { hash: Hash },
-    #[error("wrong number of arguments `{actual}`, expected `{expected}`")]
-    BadArgumentCount { actual: usize, expected: usize },
-    #[error("bad argument #{arg}, expected `{expected}` but got `{actual}`")]
-    BadArgumentAt {
-        arg: usize,
-        expected: TypeInfo,
-        actual: TypeInfo,
-    },
-    #[error("bad argument #{arg}: {error}")]
-    BadArgument {
-        #[source]
-        error: VmError,
-        arg: usize,
-    },
-    #[error("the index set operation `{target}[{index}] = {value}` is not supported")]
-    UnsupportedIndexSet {
-        target: TypeInfo,
-        index: TypeInfo,
-        value: TypeInfo,
-    },
-    #[error("the index get operation `{target}[{index}]` is not supported")]
-    UnsupportedIndexGet { target: TypeInfo, index: TypeInfo },
-    #[error("the tuple index get operation is not supported on `{target}`")]
-    UnsupportedTupleIndexGet { target: TypeInfo },
-    #[error("the tuple index set operation is not supported on `{target}`")]
-    UnsupportedTupleIndexSet { target: TypeInfo },
-    #[error("field not available on `{target}`")]
-    UnsupportedObjectSlotIndexGet { target: TypeInfo },
-    #[error("field not available on `{target}`")]
-    UnsupportedObjectSlotIndexSet { target: TypeInfo },
-    #[error("`{value} is {test_type}` is not supported")]
-    UnsupportedIs {
-        value: TypeInfo,
-        test_type: TypeInfo,
-    },
-    #[error("`{actual_type}` cannot be called since it's not a function")]
-    UnsupportedCallFn { actual_type: TypeInfo },
-    #[error("missing index by static string slot `{slot}` in object")]
-    ObjectIndexMissing { slot: usize },
-    #[error("`{target}` missing index `{index}`")]
-    MissingIndex {
-        target: TypeInfo,
-        index: VmIntegerRepr,
-    },
-    #[error("`{target}` missing index `{index:?}`")]
-    MissingIndexKey { target: TypeInfo, index: Key },
-    #[error("index out of bounds: the len is ${len} but the index is {index}")]
-    OutOfRange {
-        index: VmIntegerRepr,
-        len: VmIntegerRepr,
-    },
-    #[error("missing field `{field}` on `{target}`")]
-    MissingField { target: TypeInfo, field: String },
-    #[error("missing dynamic field for struct field `{target}::{name}`")]
-    MissingStructField {
-        target: &'static str,
-        name: &'static str,
-    },
-    #[error("missing dynamic index #{index} in tuple struct `{target}`")]
-    MissingTupleIndex { target: &'static str, index: usize },
-    #[error("expected result or option with value to unwrap, but got `{actual}`")]
-    UnsupportedUnwrap { actual: TypeInfo },
-    #[error("expected Some value, but got `None`")]
-    UnsupportedUnwrapNone,
-    #[error("expected Ok value, but got `Err({err})`")]
-    UnsupportedUnwrapErr { err: TypeInfo },
-    #[error("expected result or option as value, but got `{actual}`")]
-    UnsupportedIsValueOperand { actual: TypeInfo },
-    /// Trying to resume a generator that has completed.
-    #[error("cannot resume a generator that has completed")]
-    GeneratorComplete,
-    #[error("expected `{expected}`, but found `{actual}`")]
-    Expected {
-        expected: TypeInfo,
-        actual: TypeInfo,
-    },
-    #[error("expected `Any` type, but found `{actual}`")]
-    ExpectedAny { actual: TypeInfo },
-    #[error("failed to convert value `{from}` to integer `{to}`")]
-    ValueToIntegerCoercionError {
-        from: VmIntegerRepr,
-        to: &'static str,
-    },
-    #[error("failed to convert integer `{from}` to value `{to}`")]
-    IntegerToValueCoercionError {
-        from: VmIntegerRepr,
-        to: &'static str,
-    },
-    #[error("expected a tuple of length `{expected}`, but found one with length `{actual}`")]
-    ExpectedTupleLength { actual: usize, expected: usize },
-    #[error("unexpectedly ran out of items to iterate over")]
-    IterationError,
-    #[error("missing variant name in runtime information")]
-    MissingVariantName,
-    #[error("no variant matching `{name}`")]
-    MissingVariant { name: Box<str> },
-    #[error("expected an enum variant, but got `{actual}`")]
-    ExpectedVariant { actual: TypeInfo },
-    #[error("{actual} can't be converted to a constant value")]
-    ConstNotSupported { actual: TypeInfo },
-    #[error("{actual} can't be converted to a hash key")]
-    KeyNotSupported { actual: TypeInfo },
-    #[error("missing interface environment")]
-    MissingInterfaceEnvironment,
-    #[error("index out of bounds")]
-    IndexOutOfBounds,
-    #[error("unsupported range")]
-    UnsupportedRange,
+/// Declare the [VmErrorKind] enum, generating a method which returns the name of
+/// each variant.
+macro_rules! vm_error_kind {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident $({ $($field:tt)* })?,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant $({ $($field)* })?,
+            )*
+        }
+
+        impl $name {
+            /// Get the name of the variant.
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant { .. } => stringify!($variant),)*
+                }
+            }
+        }
+    };
+}
+
+vm_error_kind! {
+    /// The kind of error encountered.
+    #[allow(missing_docs)]
+    #[derive(Debug, Error)]
+    pub enum VmErrorKind {
+        /// A vm error that was propagated from somewhere else.
+        ///
+        /// In order to represent this, we need to preserve the instruction pointer
+        /// and eventually unit from where the error happened.
+        #[error("{kind} (at inst {ip})")]
+        Unwound {
+            /// The wrapper error.
+            kind: Box<VmErrorKind>,
+            /// Associated unit.
+            unit: Arc<Unit>,
+            /// The instruction pointer of where the original error happened.
+            ip: usize,
+            /// All lower call frames before the unwind trigger point
+            frames: Vec<CallFrame>,
+        },
+        #[error("{error}")]
+        AccessError {
+            #[from]
+            error: AccessError,
+        },
+        #[error("panicked: {reason}")]
+        Panic { reason: Panic },
+        #[error("no running virtual machines")]
+        NoRunningVm,
+        #[error("halted for unexpected reason `{halt}`")]
+        Halted { halt: VmHaltInfo },
+        #[error("failed to format argument")]
+        FormatError,
+        #[error("stack error: {error}")]
+        StackError {
+            #[from]
+            error: StackError,
+        },
+        /// Fewer values than an operation requires are available on the
+        /// stack.
+        #[error("stack underflow")]
+        StackUnderflow,
+        #[error("numerical overflow")]
+        Overflow,
+        #[error("numerical underflow")]
+        Underflow,
+        #[error("division by zero")]
+        DivideByZero,
+        #[error("missing constant with hash `{hash}`")]
+        MissingConst { hash: Hash },
+        #[error("missing entry `{item}` with hash `{hash}`")]
+        MissingEntry { item: Item, hash: Hash },
+        #[error("missing function with hash `{hash}`")]
+        MissingFunction { hash: Hash },
+        #[error("missing instance function `{hash}` for `{instance}`")]
+        MissingInstanceFunction { hash: Hash, instance: TypeInfo },
+        #[error("instruction pointer is out-of-bounds")]
+        IpOutOfBounds,
+        #[error("unsupported vm operation `{lhs} {op} {rhs}`")]
+        UnsupportedBinaryOperation {
+            op: &'static str,
+            lhs: TypeInfo,
+            rhs: TypeInfo,
+        },
+        #[error("unsupported vm operation `{op}{operand}`")]
+        UnsupportedUnaryOperation { op: &'static str, operand: TypeInfo },
+        #[error("`{actual}` does not implement the `{protocol}` protocol")]
+        MissingProtocol {
+            protocol: Protocol,
+            actual: TypeInfo,
+        },
+        #[error("static string slot `{slot}` does not exist")]
+        MissingStaticString { slot: usize },
+        #[error("static object keys slot `{slot}` does not exist")]
+        MissingStaticObjectKeys { slot: usize },
+        #[error("static set slot `{slot}` does not exist")]
+        MissingStaticSet { slot: usize },
+        #[error("missing runtime information for variant with hash `{hash}`")]
+        MissingVariantRtti { hash: Hash },
+        #[error("missing runtime information for type with hash `{hash}`")]
+        MissingRtti { hash: Hash },
+        #[error("wrong number of arguments `{actual}`, expected `{expected}`")]
+        BadArgumentCount { actual: usize, expected: usize },
+        #[error("bad argument #{arg}, expected `{expected}` but got `{actual}`")]
+        BadArgumentAt {
+            arg: usize,
+            expected: TypeInfo,
+            actual: TypeInfo,
+        },
+        #[error("bad argument #{arg}: {error}")]
+        BadArgument {
+            #[source]
+            error: VmError,
+            arg: usize,
+        },
+        #[error("the index set operation `{target}[{index}] = {value}` is not supported")]
+        UnsupportedIndexSet {
+            target: TypeInfo,
+            index: TypeInfo,
+            value: TypeInfo,
+        },
+        #[error("the index get operation `{target}[{index}]` is not supported")]
+        UnsupportedIndexGet { target: TypeInfo, index: TypeInfo },
+        #[error("the tuple index get operation is not supported on `{target}`")]
+        UnsupportedTupleIndexGet { target: TypeInfo },
+        #[error("the tuple index set operation is not supported on `{target}`")]
+        UnsupportedTupleIndexSet { target: TypeInfo },
+        #[error("field not available on `{target}`")]
+        UnsupportedObjectSlotIndexGet { target: TypeInfo },
+        #[error("field not available on `{target}`")]
+        UnsupportedObjectSlotIndexSet { target: TypeInfo },
+        #[error("`{value} is {test_type}` is not supported")]
+        UnsupportedIs {
+            value: TypeInfo,
+            test_type: TypeInfo,
+        },
+        #[error("`{actual_type}` cannot be called since it's not a function")]
+        UnsupportedCallFn { actual_type: TypeInfo },
+        #[error("missing index by static string slot `{slot}` in object")]
+        ObjectIndexMissing { slot: usize },
+        #[error("`{target}` missing index `{index}`")]
+        MissingIndex {
+            target: TypeInfo,
+            index: VmIntegerRepr,
+        },
+        #[error("`{target}` missing index `{index:?}`")]
+        MissingIndexKey { target: TypeInfo, index: Key },
+        #[error("index out of bounds: the len is ${len} but the index is {index}")]
+        OutOfRange {
+            index: VmIntegerRepr,
+            len: VmIntegerRepr,
+        },
+        #[error("missing field `{field}` on `{target}`")]
+        MissingField { target: TypeInfo, field: String },
+        #[error("missing dynamic field for struct field `{target}::{name}`")]
+        MissingStructField {
+            target: &'static str,
+            name: &'static str,
+        },
+        #[error("missing dynamic index #{index} in tuple struct `{target}`")]
+        MissingTupleIndex { target: &'static str, index: usize },
+        #[error("expected result or option with value to unwrap, but got `{actual}`")]
+        UnsupportedUnwrap { actual: TypeInfo },
+        #[error("expected Some value, but got `None`")]
+        UnsupportedUnwrapNone,
+        #[error("expected Ok value, but got `Err({err})`")]
+        UnsupportedUnwrapErr { err: TypeInfo },
+        #[error("expected result or option as value, but got `{actual}`")]
+        UnsupportedIsValueOperand { actual: TypeInfo },
+        /// Trying to resume a generator that has completed.
+        #[error("cannot resume a generator that has completed")]
+        GeneratorComplete,
+        #[error("expected `{expected}`, but found `{actual}`")]
+        Expected {
+            expected: TypeInfo,
+            actual: TypeInfo,
+        },
+        #[error("expected `Any` type, but found `{actual}`")]
+        ExpectedAny { actual: TypeInfo },
+        #[error("failed to convert value `{from}` to integer `{to}`")]
+        ValueToIntegerCoercionError {
+            from: VmIntegerRepr,
+            to: &'static str,
+        },
+        #[error("failed to convert integer `{from}` to value `{to}`")]
+        IntegerToValueCoercionError {
+            from: VmIntegerRepr,
+            to: &'static str,
+        },
+        #[error("expected a tuple of length `{expected}`, but found one with length `{actual}`")]
+        ExpectedTupleLength { actual: usize, expected: usize },
+        #[error("unexpectedly ran out of items to iterate over")]
+        IterationError,
+        #[error("missing variant name in runtime information")]
+        MissingVariantName,
+        #[error("no variant matching `{name}`")]
+        MissingVariant { name: Box<str> },
+        #[error("expected an enum variant, but got `{actual}`")]
+        ExpectedVariant { actual: TypeInfo },
+        #[error("{actual} can't be converted to a constant value")]
+        ConstNotSupported { actual: TypeInfo },
+        #[error("{actual} can't be converted to a hash key")]
+        KeyNotSupported { actual: TypeInfo },
+        #[error("missing interface environment")]
+        MissingInterfaceEnvironment,
+        #[error("index out of bounds")]
+        IndexOutOfBounds,
+        #[error("unsupported range")]
+        UnsupportedRange,
+    }
 }
 
 impl VmErrorKind {
+    /// Get the name of the kind of error, like `"Panic"` or `"DivideByZero"`.
+    ///
+    /// Unwound errors report the name of the error they wrap.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Unwound { kind, .. } => kind.name(),
+            kind => kind.variant_name(),
+        }
+    }
+
+    /// Test if the error can be caught by a `try` block.
+    ///
+    /// Errors which indicate that the virtual machine itself is in a bad state
+    /// can't be caught.
+    pub fn is_catchable(&self) -> bool {
+        match self {
+            Self::Unwound { kind, .. } => kind.is_catchable(),
+            Self::NoRunningVm => false,
+            Self::Halted { .. } => false,
+            Self::StackError { .. } => false,
+            Self::StackUnderflow => false,
+            Self::IpOutOfBounds => false,
+            _ => true,
+        }
+    }
+
     /// Unpack an unwound error, if it is present.
     pub fn as_unwound_ref(&self) -> (&Self, Option<(Arc<Unit>, usize, Vec<CallFrame>)>) {
         match self {
@@ -2,7 +2,7 @@
 
 pub use rune::WarningKind::*;
 pub use rune::{CompileErrorKind, CompileErrorKind::*};
-use rune::{Diagnostics, Options, Sources, UnitBuilder};
+use rune::{CompileOptions, Diagnostics, Options, Sources, UnitBuilder};
 pub use rune::{ParseErrorKind, ParseErrorKind::*};
 pub use rune::{QueryErrorKind, QueryErrorKind::*};
 pub use rune::{ResolveErrorKind, ResolveErrorKind::*};
@@ -46,12 +46,11 @@ impl RunError {
 
 fn internal_compile_source(
     context: &runestick::Context,
+    unit: UnitBuilder,
     sources: &mut Sources,
 ) -> Result<(Unit, Diagnostics), Diagnostics> {
     let mut diagnostics = Diagnostics::new();
 
-    let unit = UnitBuilder::with_default_prelude();
-
     if let Err(()) = rune::compile(context, sources, &unit, &mut diagnostics) {
         return Err(diagnostics);
     }
@@ -71,16 +70,71 @@ fn internal_compile_source(
 pub fn compile_source(
     context: &runestick::Context,
     source: &str,
+) -> Result<(Unit, Diagnostics), Diagnostics> {
+    compile_source_with_unit(context, UnitBuilder::with_default_prelude(), source)
+}
+
+/// Compile the given source into the given unit, returning the built unit and
+/// a collection of warnings.
+pub fn compile_source_with_unit(
+    context: &runestick::Context,
+    unit: UnitBuilder,
+    source: &str,
 ) -> Result<(Unit, Diagnostics), Diagnostics> {
     let mut sources = Sources::new();
     sources.insert(Source::new("main", source.to_owned()));
 
-    internal_compile_source(context, &mut sources)
+    internal_compile_source(context, unit, &mut sources)
+}
+
+/// Compile the given source with the given options, panicking with the
+/// diagnostics if it fails.
+pub fn compile_with_options(
+    context: &runestick::Context,
+    options: &Options,
+    source: &str,
+) -> Arc<Unit> {
+    let mut sources = Sources::new();
+    sources.insert(Source::new("main", source));
+
+    compile_sources_with_options(context, &mut sources, options, Default::default())
+}
+
+/// Compile the given sources with the given options and compile hooks,
+/// panicking with the diagnostics if it fails.
+pub fn compile_sources_with_options(
+    context: &runestick::Context,
+    sources: &mut Sources,
+    options: &Options,
+    compile_options: CompileOptions<'_>,
+) -> Arc<Unit> {
+    let mut diagnostics = Diagnostics::new();
+    let unit = UnitBuilder::with_default_prelude();
+
+    let result = rune::compile_with_options(
+        context,
+        sources,
+        &unit,
+        &mut diagnostics,
+        options,
+        compile_options,
+    );
+
+    if let Err(error) = result {
+        panic!("{}: {:?}", error, diagnostics);
+    }
+
+    match unit.build() {
+        Ok(unit) => Arc::new(unit),
+        Err(error) => panic!("failed to build unit: {}", error),
+    }
 }
 
 /// Construct a virtual machine for the given sources.
 pub fn vm(context: &runestick::Context, sources: &mut Sources) -> Result<runestick::Vm, RunError> {
-    let (unit, _) = internal_compile_source(context, sources).map_err(RunError::Diagnostics)?;
+    let unit = UnitBuilder::with_default_prelude();
+    let (unit, _) =
+        internal_compile_source(context, unit, sources).map_err(RunError::Diagnostics)?;
     let context = Arc::new(context.runtime());
 
     Ok(runestick::Vm::new(context, Arc::new(unit)))
@@ -10,3 +10,70 @@ fn test_bad_attributes() {
         }
     };
 }
+
+#[test]
+fn test_repr_preserves_field_order() {
+    let value: Value = rune! { Value =>
+        #[repr(C)]
+        struct Pixel { z, y, x }
+
+        pub fn main() {
+            Pixel { x: 1, y: 2, z: 3 }
+        }
+    };
+
+    let value = match value {
+        Value::Struct(value) => value,
+        actual => panic!("expected struct, got {:?}", actual),
+    };
+
+    let value = value.borrow_ref().unwrap();
+
+    let fields = value
+        .ordered_fields()
+        .expect("ordered fields")
+        .map(|(name, value)| Ok((name.to_owned(), i64::from_value(value.clone())?)))
+        .collect::<Result<Vec<_>, VmError>>()
+        .unwrap();
+
+    assert_eq!(
+        fields,
+        vec![
+            ("z".to_owned(), 3),
+            ("y".to_owned(), 2),
+            ("x".to_owned(), 1)
+        ]
+    );
+
+    let value: Value = rune! { Value =>
+        struct Pixel { z, y, x }
+
+        pub fn main() {
+            Pixel { x: 1, y: 2, z: 3 }
+        }
+    };
+
+    match value {
+        Value::Struct(value) => assert!(value.borrow_ref().unwrap().ordered_fields().is_none()),
+        actual => panic!("expected struct, got {:?}", actual),
+    }
+}
+
+#[test]
+fn test_bad_repr() {
+    assert_compile_error! {
+        r#"#[repr(packed)] struct Foo { a }"#,
+        span, CompileErrorKind::ParseError { error: ParseErrorKind::Custom { message } } => {
+            assert_eq!(message, "unsupported repr");
+            assert_eq!(span, Span::new(7, 13));
+        }
+    };
+
+    assert_compile_error! {
+        r#"#[foo] struct Foo { a }"#,
+        span, CompileErrorKind::Custom { message } => {
+            assert_eq!(message, "unsupported struct attribute");
+            assert_eq!(span, Span::new(0, 6));
+        }
+    };
+}
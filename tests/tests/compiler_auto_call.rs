@@ -0,0 +1,61 @@
+use runestick::{FromValue as _, Value};
+use std::sync::Arc;
+
+fn run_with_options(source: &str, options: &rune::Options) -> Value {
+    let context = runestick::Context::with_default_modules().unwrap();
+    let unit = rune_tests::compile_with_options(&context, options, source);
+
+    let vm = runestick::Vm::new(Arc::new(context.runtime()), unit);
+    vm.call(["main"], ()).unwrap()
+}
+
+const SOURCE: &str = r#"
+struct Empty();
+enum Enum { Variant() }
+
+pub fn main() {
+    let a = Empty;
+    let b = Enum::Variant;
+    (a, b)
+}
+"#;
+
+#[test]
+fn test_auto_call_unit_tuples() {
+    let output = run_with_options(SOURCE, &rune::Options::default());
+    let (a, b) = <(Value, Value)>::from_value(output).unwrap();
+
+    assert!(matches!(a, Value::TupleStruct(..)));
+    assert!(matches!(b, Value::Variant(..)));
+}
+
+#[test]
+fn test_disable_auto_call_unit_tuples() {
+    let mut options = rune::Options::default();
+    options.auto_call_unit_tuples(false);
+
+    let output = run_with_options(SOURCE, &options);
+    let (a, b) = <(Value, Value)>::from_value(output).unwrap();
+
+    assert!(matches!(a, Value::Function(..)));
+    assert!(matches!(b, Value::Function(..)));
+
+    // Patterns are unaffected by the option.
+    let output = run_with_options(
+        r#"
+        enum Enum { Variant() }
+
+        pub fn main() {
+            let constructor = Enum::Variant;
+
+            match constructor() {
+                Enum::Variant => true,
+                _ => false,
+            }
+        }
+        "#,
+        &options,
+    );
+
+    assert!(bool::from_value(output).unwrap());
+}
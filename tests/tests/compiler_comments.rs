@@ -0,0 +1,110 @@
+use rune::{ast, MacroContext, Parser, ToTokens as _, TokenStream};
+use runestick::Span;
+
+const SOURCE: &str = r#"
+// leading comment
+fn foo() {
+    // inside of a function
+    42
+}
+
+mod bar {
+    // inside of a module
+    fn baz() {} // trailing comment
+}
+// last comment
+"#;
+
+fn comments(source: &str, file: &ast::File) -> Vec<String> {
+    file.comments
+        .iter()
+        .map(|c| source[c.span.start.into_usize()..c.span.end.into_usize()].to_owned())
+        .collect()
+}
+
+#[test]
+fn test_preserve_comments() {
+    let mut parser = Parser::new(SOURCE);
+    let file = parser.parse_all::<ast::File>().unwrap();
+    assert!(file.comments.is_empty());
+
+    let mut parser = Parser::new(SOURCE);
+    parser.set_preserve_comments(true);
+    let file = parser.parse_all::<ast::File>().unwrap();
+
+    assert_eq!(
+        comments(SOURCE, &file),
+        vec![
+            "// leading comment",
+            "// inside of a function",
+            "// last comment",
+        ]
+    );
+
+    let module = match &file.items[1].0 {
+        ast::Item::Mod(item_mod) => item_mod,
+        _ => panic!("expected module"),
+    };
+
+    let inner = match &module.body {
+        ast::ItemModBody::InlineBody(body) => &body.file,
+        _ => panic!("expected inline module"),
+    };
+
+    assert_eq!(
+        comments(SOURCE, inner),
+        vec!["// inside of a module", "// trailing comment"]
+    );
+}
+
+#[test]
+fn test_preserve_comments_roundtrip() {
+    let mut parser = Parser::new(SOURCE);
+    parser.set_preserve_comments(true);
+    let file = parser.parse_all::<ast::File>().unwrap();
+
+    let ctx = MacroContext::empty();
+    let mut stream = TokenStream::new();
+    file.to_tokens(&ctx, &mut stream);
+
+    let comments = stream
+        .into_iter()
+        .filter(|t| t.kind == ast::Kind::Comment)
+        .map(|t| t.span)
+        .collect::<Vec<_>>();
+
+    assert_eq!(comments.len(), 5);
+    assert!(comments.windows(2).all(|w| w[0].start < w[1].start));
+    assert_eq!(comments[0], Span::new(1, 19));
+
+    let mut stream = TokenStream::new();
+    file.to_tokens(&ctx, &mut stream);
+
+    let mut parser = Parser::from_token_stream(&stream);
+    let file2 = parser.parse_all::<ast::File>().unwrap();
+    assert_eq!(file, file2);
+}
+
+#[test]
+fn test_compile_preserving_comments() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let mut options = rune::Options::default();
+    options.preserve_comments(true);
+
+    let mut sources = rune::Sources::new();
+    sources.insert(runestick::Source::new(
+        "main",
+        "// comment\npub fn main() { 42 } // trailing",
+    ));
+
+    let mut diagnostics = rune::Diagnostics::new();
+    let unit = rune::load_sources(&context, &options, &mut sources, &mut diagnostics).unwrap();
+
+    let vm = runestick::Vm::new(
+        std::sync::Arc::new(context.runtime()),
+        std::sync::Arc::new(unit),
+    );
+    let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+    assert_eq!(output.into_integer().unwrap(), 42);
+}
@@ -6,8 +6,101 @@ fn test_assign_exprs() {
 
     assert_compile_error! {
         r#"pub fn main() { 1 = 42; }"#,
-        span, UnsupportedAssignExpr => {
-            assert_eq!(span, Span::new(16, 22));
+        span, InvalidAssignmentTarget => {
+            assert_eq!(span, Span::new(16, 17));
         }
     };
+
+    assert_compile_error! {
+        r#"fn f() {} pub fn main() { f() = 1; }"#,
+        span, InvalidAssignmentTarget => {
+            assert_eq!(span, Span::new(26, 29));
+        }
+    };
+}
+
+fn compile_let_mut(source: &str) -> Result<runestick::Unit, rune::Diagnostics> {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let mut options = rune::Options::default();
+    options.let_mut(true);
+
+    let mut sources = rune::Sources::new();
+    sources.insert(runestick::Source::new("main", source));
+
+    let mut diagnostics = rune::Diagnostics::new();
+
+    match rune::load_sources(&context, &options, &mut sources, &mut diagnostics) {
+        Ok(unit) => Ok(unit),
+        Err(..) => Err(diagnostics),
+    }
+}
+
+fn assert_assign_to_immutable(source: &str, expected_span: Span, expected_decl_span: Span) {
+    let diagnostics = compile_let_mut(source).expect_err("expected compilation to fail");
+
+    let error = diagnostics
+        .into_diagnostics()
+        .into_iter()
+        .find_map(|d| match d {
+            rune::Diagnostic::Error(error) => Some(error),
+            rune::Diagnostic::Warning(..) => None,
+        })
+        .expect("expected error");
+
+    match error.into_kind() {
+        rune::ErrorKind::CompileError(error) => {
+            let span = rune::Spanned::span(&error);
+
+            match error.into_kind() {
+                CompileErrorKind::AssignToImmutable { decl_span } => {
+                    assert_eq!(span, expected_span);
+                    assert_eq!(decl_span, expected_decl_span);
+                }
+                kind => panic!("unexpected compile error: {:?}", kind),
+            }
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
+
+#[test]
+fn test_let_mut_allows_reassignment() {
+    let unit = compile_let_mut(
+        r#"
+        pub fn main(n) {
+            let mut a = 1;
+            a = 2;
+            a += n;
+            let mut (b, c) = (1, 2);
+            b = c;
+            n = b;
+            for i in 0..2 { i = 0; }
+            a + b + n
+        }
+        "#,
+    );
+
+    assert!(unit.is_ok());
+}
+
+#[test]
+fn test_let_mut_rejects_reassignment() {
+    assert_assign_to_immutable(
+        r#"pub fn main() { let a = 1; a = 2; }"#,
+        Span::new(27, 32),
+        Span::new(20, 21),
+    );
+
+    assert_assign_to_immutable(
+        r#"pub fn main() { let a = 1; a += 2; }"#,
+        Span::new(27, 33),
+        Span::new(20, 21),
+    );
+
+    assert_assign_to_immutable(
+        r#"pub fn main() { let (a, b) = (1, 2); b = a; }"#,
+        Span::new(37, 42),
+        Span::new(24, 25),
+    );
 }
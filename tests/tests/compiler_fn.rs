@@ -16,3 +16,77 @@ fn test_fn_const_async() {
         }
     };
 }
+
+#[test]
+fn test_fn_tuple_return() {
+    let out: (i64, i64) = rune! { (i64, i64) =>
+        fn divmod(a, b) -> (int, int) { (a / b, a % b) }
+        pub fn main() { let (q, r) = divmod(7, 2); (q, r) }
+    };
+
+    assert_eq!(out, (3, 1));
+
+    assert_compile_error! {
+        r#"fn divmod(a, b) -> (int, int) { (a / b, a % b) } pub fn main() { let (q, r, x) = divmod(7, 2); }"#,
+        span, ReturnArityMismatch { expected, actual, .. } => {
+            assert_eq!(span, Span::new(69, 78));
+            assert_eq!(expected, 2);
+            assert_eq!(actual, 3);
+        }
+    };
+}
+
+#[test]
+fn test_fn_duplicate_parameter() {
+    assert_compile_error! {
+        r#"fn foo(a, b, a) { a } pub fn main() { foo(1, 2, 3) }"#,
+        span, DuplicateParameter { name, first_span, second_span } => {
+            assert_eq!(name, "a");
+            assert_eq!(first_span, Span::new(7, 8));
+            assert_eq!(second_span, Span::new(13, 14));
+            assert_eq!(span, second_span);
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { let f = |x, x| x; f(1, 2) }"#,
+        span, DuplicateParameter { name, .. } => {
+            assert_eq!(name, "x");
+            assert_eq!(span, Span::new(28, 29));
+        }
+    };
+
+    assert_eq! {
+        rune!(i64 => fn foo(_, _, c) { c } pub fn main() { foo(1, 2, 3) }),
+        3,
+    };
+}
+
+#[test]
+fn test_async_fn_return_type() {
+    let out: i64 = rune! { i64 =>
+        async fn foo(n) -> int { if n > 0 { return n; } 0 }
+        pub async fn main() { foo(1).await + foo(-1).await }
+    };
+
+    assert_eq!(out, 1);
+
+    assert_vm_error!(
+        r#"
+        async fn foo(value) -> int { value }
+        pub async fn main() { foo("hello").await }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "returned value does not match the declared return type");
+        }
+    );
+
+    assert_compile_error! {
+        r#"async fn foo() -> String { 42 } pub async fn main() { foo().await }"#,
+        span, ReturnTypeMismatch { expected, actual } => {
+            assert_eq!(span, Span::new(0, 31));
+            assert_eq!(&*expected, "String");
+            assert_eq!(actual.to_string(), "integer");
+        }
+    };
+}
@@ -84,3 +84,92 @@ fn test_bad_struct_declaration() {
         }
     };
 }
+
+#[test]
+fn test_nesting_limit() {
+    let nested = |depth: usize| {
+        format!(
+            "pub fn main() {{ {}1{} }}",
+            "(".repeat(depth),
+            ")".repeat(depth)
+        )
+    };
+
+    assert_eq!(rune_s!(i64 => &nested(60)), 1);
+
+    assert_parse_error! {
+        &nested(10_000),
+        span, NestingLimitExceeded { limit } => {
+            assert_eq!(limit, 64);
+            assert_eq!(span, Span::new(80, 81));
+        }
+    };
+}
+
+#[test]
+fn test_nesting_limit_option() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let mut options = rune::Options::default();
+    options.parse_option("nesting-limit=4").unwrap();
+    assert!(options.parse_option("nesting-limit=deep").is_err());
+
+    let mut sources = rune::Sources::new();
+    sources.insert(runestick::Source::new(
+        "main",
+        "pub fn main() { ((((((1)))))) }",
+    ));
+
+    let mut diagnostics = rune::Diagnostics::new();
+    assert!(rune::load_sources(&context, &options, &mut sources, &mut diagnostics).is_err());
+
+    match diagnostics.into_diagnostics().into_iter().next() {
+        Some(rune::Diagnostic::Error(e)) => match e.into_kind() {
+            rune::ErrorKind::ParseError(e) => {
+                assert!(matches!(
+                    e.kind(),
+                    rune::ParseErrorKind::NestingLimitExceeded { limit: 4 }
+                ));
+            }
+            kind => panic!("expected parse error but was `{:?}`", kind),
+        },
+        actual => panic!("expected diagnostic error but was `{:?}`", actual),
+    }
+}
+
+#[test]
+fn test_not_callable() {
+    assert_compile_error! {
+        r#"struct Foo; pub fn main() { Foo() }"#,
+        span, NotCallable { item } => {
+            assert_eq!(span, Span::new(28, 33));
+            assert_eq!(item, runestick::Item::with_item(&["Foo"]));
+        }
+    };
+
+    assert_compile_error! {
+        r#"struct Foo { a } pub fn main() { Foo(1) }"#,
+        span, NotCallable { item } => {
+            assert_eq!(span, Span::new(33, 39));
+            assert_eq!(item, runestick::Item::with_item(&["Foo"]));
+        }
+    };
+
+    assert_compile_error! {
+        r#"enum Foo { Bar } pub fn main() { Foo::Bar() }"#,
+        span, NotCallable { item } => {
+            assert_eq!(span, Span::new(33, 43));
+            assert_eq!(item, runestick::Item::with_item(&["Foo", "Bar"]));
+        }
+    };
+
+    assert_compile_error! {
+        r#"const FOO = 1; pub fn main() { FOO() }"#,
+        span, NotCallable { item } => {
+            assert_eq!(span, Span::new(31, 36));
+            assert_eq!(item, runestick::Item::with_item(&["FOO"]));
+        }
+    };
+
+    assert_eq!(rune!(i64 => pub fn main() { let f = || 42; f() }), 42);
+}
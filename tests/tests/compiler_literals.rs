@@ -39,3 +39,46 @@ fn test_number_literals() {
         }
     };
 }
+
+#[test]
+fn test_static_strings_are_interned() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let mut sources = rune::Sources::new();
+    sources.insert(runestick::Source::new(
+        "main",
+        r#"
+        fn check(value) {
+            match value { "hello" => true, _ => false }
+        }
+
+        pub fn main() {
+            let a = ["hello", "hello", "world"];
+            let b = "hello";
+            check("hello") && check(b) && a[0] == "hello"
+        }
+        "#,
+    ));
+
+    let mut diagnostics = rune::Diagnostics::new();
+    let unit = rune::load_sources(
+        &context,
+        &rune::Options::default(),
+        &mut sources,
+        &mut diagnostics,
+    )
+    .unwrap();
+
+    let hello = unit
+        .iter_static_strings()
+        .filter(|s| s.as_str() == "hello")
+        .count();
+
+    let world = unit
+        .iter_static_strings()
+        .filter(|s| s.as_str() == "world")
+        .count();
+
+    assert_eq!(hello, 1);
+    assert_eq!(world, 1);
+}
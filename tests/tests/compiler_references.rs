@@ -0,0 +1,97 @@
+use rune::{
+    CompileOptions, Diagnostics, FileSourceLoader, NoopCompileVisitor, Options, References,
+    Sources, UnitBuilder,
+};
+use runestick::{Context, Item, Source, Span};
+use std::rc::Rc;
+
+#[test]
+fn test_find_references_across_sources() {
+    let context = Context::with_default_modules().unwrap();
+
+    let mut sources = Sources::new();
+
+    let a = sources.insert(Source::new(
+        "a",
+        r#"pub fn foo() { 42 } pub fn main() { foo() + foo() }"#,
+    ));
+
+    let b = sources.insert(Source::new("b", r#"pub fn bar() { foo() }"#));
+
+    let unit = UnitBuilder::with_default_prelude();
+    let mut diagnostics = Diagnostics::new();
+
+    let references = rune::compile_with_references(
+        &context,
+        &mut sources,
+        &unit,
+        &mut diagnostics,
+        &Options::default(),
+        Rc::new(NoopCompileVisitor::new()),
+        Rc::new(FileSourceLoader::new()),
+    )
+    .expect("sources should compile");
+
+    let mut found = references
+        .find(&Item::with_item(&["foo"]))
+        .iter()
+        .map(|l| (l.source_id, l.span))
+        .collect::<Vec<_>>();
+
+    found.sort_by_key(|(source_id, span)| (*source_id, span.start));
+
+    assert_eq!(
+        found,
+        vec![
+            (a, Span::new(36, 39)),
+            (a, Span::new(44, 47)),
+            (b, Span::new(15, 18)),
+        ]
+    );
+
+    assert!(references.find(&Item::with_item(&["missing"])).is_empty());
+}
+
+#[test]
+fn test_item_at_resolves_definition() {
+    let context = Context::with_default_modules().unwrap();
+
+    let mut sources = Sources::new();
+
+    let a = sources.insert(Source::new(
+        "a",
+        r#"pub fn foo() { 42 } pub fn main() { foo() + b::bar() }"#,
+    ));
+
+    let b = sources.insert(Source::new("b", r#"pub mod b { pub fn bar() { 1 } }"#));
+
+    let unit = UnitBuilder::with_default_prelude();
+    let mut diagnostics = Diagnostics::new();
+
+    let mut references = References::default();
+
+    rune::compile_with_options(
+        &context,
+        &mut sources,
+        &unit,
+        &mut diagnostics,
+        &Options::default(),
+        CompileOptions {
+            references: Some(&mut references),
+            ..Default::default()
+        },
+    )
+    .expect("sources should compile");
+
+    let (item, location) = references.item_at(a, 37).expect("reference to foo");
+    assert_eq!(*item, Item::with_item(&["foo"]));
+    assert_eq!(location.source_id, a);
+    assert_eq!(location.span, Span::new(0, 19));
+
+    let (item, location) = references.item_at(a, 48).expect("reference to bar");
+    assert_eq!(*item, Item::with_item(&["b", "bar"]));
+    assert_eq!(location.source_id, b);
+    assert_eq!(location.span, Span::new(12, 30));
+
+    assert!(references.item_at(a, 3).is_none());
+}
@@ -0,0 +1,62 @@
+use rune::{
+    ast, AstRewriter, CompileError, Diagnostics, FileSourceLoader, NoopCompileVisitor, Options,
+    Parser, Sources, UnitBuilder,
+};
+use rune_tests::capture_output;
+use runestick::{Context, FromValue as _, Item, Source, Vm};
+use std::rc::Rc;
+use std::sync::Arc;
+
+struct InjectPrint;
+
+impl AstRewriter for InjectPrint {
+    fn rewrite_fn(&self, item: &Item, ast: &mut ast::ItemFn) -> Result<(), CompileError> {
+        let message = ast::Lit::new(format!("enter {};", item));
+        let stream = rune::quote!(print(#message);).into_token_stream();
+        let stmt = Parser::from_token_stream(&stream).parse::<ast::Stmt>()?;
+        ast.body.statements.insert(0, stmt);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_rewriter_injects_print() {
+    let mut context = Context::with_config(false).unwrap();
+    context
+        .install(&capture_output::output_redirect_module().unwrap())
+        .unwrap();
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new(
+        "main",
+        r#"
+        fn add(a, b) { a + b }
+        pub fn main() { add(1, 2) }
+        "#,
+    ));
+
+    let unit = UnitBuilder::with_default_prelude();
+    let mut diagnostics = Diagnostics::new();
+
+    rune::compile_with_rewriter(
+        &context,
+        &mut sources,
+        &unit,
+        &mut diagnostics,
+        &Options::default(),
+        Rc::new(NoopCompileVisitor::new()),
+        Rc::new(FileSourceLoader::new()),
+        Rc::new(InjectPrint),
+    )
+    .unwrap_or_else(|error| panic!("{}: {:?}", error, diagnostics));
+
+    let unit = Arc::new(unit.build().unwrap());
+    let vm = Vm::new(Arc::new(context.runtime()), unit);
+    let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+
+    assert_eq!(i64::from_value(output).unwrap(), 3);
+    assert_eq!(
+        capture_output::drain_output().as_deref(),
+        Some("enter main;enter add;")
+    );
+}
@@ -120,3 +120,32 @@ fn test_reexport_fn() {
 
     assert_eq!(result, 8);
 }
+
+#[test]
+fn test_use_alias() {
+    let result = rune! { i64 =>
+        mod a { pub fn add(a, b) { a + b } }
+
+        use a::add as plus;
+
+        pub fn main() {
+            plus(1, 2)
+        }
+    };
+
+    assert_eq!(result, 3);
+}
+
+#[test]
+fn test_use_alias_conflict() {
+    assert_compile_error! {
+        r#"mod a { pub fn x() {} } mod b { pub fn y() {} } use a::x as z; use b::y as z; pub fn main() { z() }"#,
+        span, QueryError { error: AmbiguousItem { item, locations } } => {
+            assert_eq!(span, Span::new(94, 95));
+            assert_eq!(item.to_string(), "z");
+
+            let targets = locations.iter().map(|(_, item)| item.to_string()).collect::<Vec<_>>();
+            assert_eq!(targets, vec!["a::x", "b::y"]);
+        }
+    };
+}
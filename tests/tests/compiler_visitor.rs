@@ -0,0 +1,63 @@
+use rune::{CompileVisitor, Diagnostics, FileSourceLoader, Options, Sources};
+use runestick::{Source, SourceId, Span};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Default)]
+struct MacroVisitor {
+    expansions: RefCell<Vec<(SourceId, Span, Span)>>,
+}
+
+impl CompileVisitor for MacroVisitor {
+    fn visit_macro_expansion(&self, source_id: SourceId, call_span: Span, expanded_span: Span) {
+        self.expansions
+            .borrow_mut()
+            .push((source_id, call_span, expanded_span));
+    }
+}
+
+#[test]
+fn test_visit_macro_expansion() {
+    let context = rune_modules::default_context().unwrap();
+
+    let source = r#"pub fn main() { let a = stringify!(a + b); format!("{}", a) }"#;
+
+    let mut sources = Sources::new();
+    let source_id = sources.insert(Source::new("main", source));
+
+    let visitor = Rc::new(MacroVisitor::default());
+    let mut diagnostics = Diagnostics::new();
+
+    rune::load_sources_with_visitor(
+        &context,
+        &Options::default(),
+        &mut sources,
+        &mut diagnostics,
+        visitor.clone(),
+        Rc::new(FileSourceLoader::new()),
+    )
+    .unwrap();
+
+    let stringify = source.find("stringify!").unwrap();
+    let format = source.find("format!").unwrap();
+
+    let expansions = visitor.expansions.borrow();
+    let mut calls = expansions
+        .iter()
+        .map(|(source_id, call_span, _)| (*source_id, *call_span))
+        .collect::<Vec<_>>();
+
+    calls.sort_by_key(|(_, span)| span.start);
+
+    assert_eq!(
+        calls,
+        vec![
+            (source_id, Span::new(stringify, stringify + 17)),
+            (source_id, Span::new(format, format + 16)),
+        ]
+    );
+
+    for (_, call_span, expanded_span) in expansions.iter() {
+        assert!(call_span.start <= expanded_span.start && expanded_span.end <= call_span.end);
+    }
+}
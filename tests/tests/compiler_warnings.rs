@@ -29,3 +29,305 @@ fn test_remove_variant_parens() {
         }
     };
 }
+
+#[test]
+fn test_constant_true_condition() {
+    assert_warnings! {
+        r#"pub fn main() { if true { 1 } else { 2 } }"#,
+        ConstantCondition { span, value, .. } => {
+            assert_eq!(span, Span::new(19, 23));
+            assert!(value);
+        }
+    };
+}
+
+#[test]
+fn test_constant_false_condition() {
+    assert_warnings! {
+        r#"pub fn main() { while 1 == 2 { } }"#,
+        ConstantCondition { span, value, .. } => {
+            assert_eq!(span, Span::new(22, 28));
+            assert!(!value);
+        }
+    };
+}
+
+#[test]
+fn test_constant_condition_folding() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let mut options = rune::Options::default();
+    options.fold_constant_conditions(true);
+
+    let mut sources = rune::Sources::new();
+    sources.insert(runestick::Source::new(
+        "main",
+        r#"
+        pub fn main() {
+            let n = 0;
+            while false { n += 1; }
+            if (1 < 2) { n + 10 } else { n + 20 }
+        }
+        "#,
+    ));
+
+    let mut diagnostics = rune::Diagnostics::new();
+    let unit = rune::load_sources(&context, &options, &mut sources, &mut diagnostics).unwrap();
+
+    let unit = std::sync::Arc::new(unit);
+    let vm = runestick::Vm::new(std::sync::Arc::new(context.runtime()), unit.clone());
+    let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 10);
+
+    let jumps = unit
+        .iter_instructions()
+        .filter(|inst| {
+            matches!(
+                inst,
+                runestick::Inst::Jump { .. } | runestick::Inst::JumpIf { .. }
+            )
+        })
+        .count();
+
+    assert_eq!(jumps, 0);
+}
+
+#[test]
+fn test_mixed_number_literals() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let mut options = rune::Options::default();
+    options.warn_mixed_numbers(true);
+
+    let mut sources = rune::Sources::new();
+    sources.insert(runestick::Source::new(
+        "main",
+        r#"pub fn main() { (1 + 2.0, 1 + 2, 1.0 + 2.0) }"#,
+    ));
+
+    let mut diagnostics = rune::Diagnostics::new();
+    let _ = rune::load_sources(&context, &options, &mut sources, &mut diagnostics);
+
+    let warnings = diagnostics
+        .into_diagnostics()
+        .into_iter()
+        .filter_map(|d| match d {
+            rune::Diagnostic::Warning(warning) => Some(warning.into_kind()),
+            rune::Diagnostic::Error(..) => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(warnings.len(), 1);
+
+    match warnings[0] {
+        MixedNumberLiterals { span, integer, .. } => {
+            assert_eq!(span, Span::new(17, 24));
+            assert_eq!(integer, Span::new(17, 18));
+        }
+        actual => panic!("unexpected warning: {:?}", actual),
+    }
+}
+
+#[test]
+fn test_allow_dead_code() {
+    assert_warnings! {
+        r#"
+        #[allow(dead_code)]
+        fn allowed() { let a = 1; }
+        fn warned() {}
+        pub fn main() {}
+        "#,
+        NotUsed { span, .. } => {
+            assert_eq!(span, Span::new(73, 87));
+        }
+    };
+}
+
+#[test]
+fn test_allow_unknown_lint() {
+    assert_warnings! {
+        r#"#[allow(not_a_lint)] pub fn main() {}"#,
+        UnknownLint { span } => {
+            assert_eq!(span, Span::new(8, 18));
+        }
+    };
+}
+
+#[test]
+fn test_redundant_parens() {
+    let redundant_parens = |source: &str| {
+        let context = runestick::Context::with_default_modules().unwrap();
+
+        let mut options = rune::Options::default();
+        options.warn_redundant_parens(true);
+
+        let mut sources = rune::Sources::new();
+        sources.insert(runestick::Source::new("main", source));
+
+        let mut diagnostics = rune::Diagnostics::new();
+        let _ = rune::load_sources(&context, &options, &mut sources, &mut diagnostics).unwrap();
+
+        diagnostics
+            .into_diagnostics()
+            .into_iter()
+            .filter_map(|d| match d {
+                rune::Diagnostic::Warning(warning) => match warning.into_kind() {
+                    RedundantParens { span, .. } => Some(span),
+                    _ => None,
+                },
+                rune::Diagnostic::Error(..) => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        redundant_parens(r#"pub fn main() { let a = (1 + 2); a }"#),
+        vec![Span::new(24, 31)]
+    );
+
+    assert_eq!(
+        redundant_parens(r#"pub fn main() { 1 + (2 * 3) }"#),
+        vec![Span::new(20, 27)]
+    );
+
+    assert_eq!(
+        redundant_parens(r#"pub fn main() { let a = 1; (a) * 2 }"#),
+        vec![Span::new(27, 30)]
+    );
+
+    assert_eq!(
+        redundant_parens(r#"pub fn main() { ((1 + 2)) * 3 }"#),
+        vec![Span::new(17, 24)]
+    );
+
+    assert_eq!(
+        redundant_parens(r#"pub fn main() { (1 + 2) * 3 - (4 - 5) }"#),
+        vec![]
+    );
+
+    assert_eq!(
+        redundant_parens(
+            r#"struct Foo { a } pub fn main() { for n in (Foo { a: 1 }).a.iter() {} (!true) && false }"#
+        ),
+        vec![]
+    );
+
+    // NB: struct literals must be parenthesized in the heads of conditions.
+    assert_eq!(
+        redundant_parens(
+            r#"struct Foo { a } pub fn main() { let x = 1; if (Foo { a: 1 }) == x { 1 } else { 2 } }"#
+        ),
+        vec![]
+    );
+
+    assert_eq!(
+        redundant_parens(
+            r#"struct Foo { a } pub fn main() { while (Foo { a: 1 }).a == 2 {} match (Foo { a: 1 }).a { _ => () } }"#
+        ),
+        vec![]
+    );
+
+    assert_eq!(
+        redundant_parens(
+            r#"struct Foo { a } pub fn main() { let x = 1; let a = (Foo { a: 1 }) == x; a }"#
+        ),
+        vec![Span::new(52, 66)]
+    );
+}
+
+#[test]
+fn test_diverging_call_value() {
+    assert_warnings! {
+        r#"fn fail() { panic("always fails") } pub fn main() { let a = fail(); a }"#,
+        DivergingCallValue { span, .. } => {
+            assert_eq!(span, Span::new(60, 66));
+        }
+    };
+
+    assert_warnings! {
+        r#"fn spin() { loop {} } pub fn main() { let a = spin(); a }"#,
+        DivergingCallValue { .. } => {}
+    };
+
+    let diverging_calls = |source| {
+        let context = rune_modules::default_context().unwrap();
+        let (_, diagnostics) = compile_source(&context, source).expect("source should compile");
+
+        diagnostics
+            .into_diagnostics()
+            .into_iter()
+            .filter(|d| {
+                matches!(
+                    d,
+                    rune::Diagnostic::Warning(warning)
+                        if matches!(warning.kind(), DivergingCallValue { .. })
+                )
+            })
+            .count()
+    };
+
+    assert_eq!(
+        diverging_calls(r#"fn fail() { panic("always fails") } pub fn main() { fail(); }"#),
+        0
+    );
+
+    assert_eq!(
+        diverging_calls(
+            r#"fn fail(n) { if n { return 1; } panic("fails") } pub fn main() { let a = fail(true); a }"#
+        ),
+        0
+    );
+
+    assert_eq!(
+        diverging_calls(r#"fn spin() { loop { break; } } pub fn main() { let a = spin(); a }"#),
+        0
+    );
+
+    assert_eq!(
+        diverging_calls(r#"fn fail() { std::panic("fails") } pub fn main() { let a = fail(); a }"#),
+        1
+    );
+
+    // NB: the value of `fail()` is also used as the return value of `wrapper`.
+    assert_eq!(
+        diverging_calls(
+            r#"pub fn main() { let a = wrapper(); a } fn wrapper() { fail() } fn fail() { panic("fails") }"#
+        ),
+        2
+    );
+
+    assert_eq!(
+        diverging_calls(
+            r#"fn panic(x) { x } fn id(x) { panic(x) } pub fn main() { let a = id(1); a }"#
+        ),
+        0
+    );
+
+    assert_eq!(
+        diverging_calls(
+            r#"fn id(x) { let panic = |x| x; panic(x) } pub fn main() { let a = id(1); a }"#
+        ),
+        0
+    );
+}
+
+#[test]
+fn test_pattern_missing_fields() {
+    assert_warnings! {
+        r#"struct Foo { a, b } pub fn main() { let v = Foo { a: 1, b: 2 }; match v { Foo { a } => a, _ => 0 } }"#,
+        PatternMissingFields { span, missing, .. } => {
+            assert_eq!(span, Span::new(74, 83));
+            assert_eq!(missing, 1);
+        }
+    };
+
+    let context = rune_modules::default_context().unwrap();
+
+    let (_, diagnostics) = compile_source(
+        &context,
+        r#"struct Foo { a, b } pub fn main() { let v = Foo { a: 1, b: 2 }; match v { Foo { a, .. } => a, Foo { a, b } => a + b } }"#,
+    )
+    .expect("source should compile");
+
+    assert!(!diagnostics.has_warning());
+}
@@ -0,0 +1,86 @@
+use rune::{CompileErrorKind, Diagnostic, ErrorKind, Options};
+use runestick::{Context, ContextError, FromValue as _, Item, Module, Vm};
+use std::sync::Arc;
+
+fn restricted_context() -> Context {
+    Context::with_allowed_modules(true, vec![Item::with_crate_item("std", &["string"])]).unwrap()
+}
+
+#[test]
+fn test_allowed_module_is_available() {
+    let context = restricted_context();
+
+    let unit = rune_tests::compile_with_options(
+        &context,
+        &Options::default(),
+        r#"
+        pub fn main() {
+            let s = String::from_str("hello");
+            s.push_str(" world");
+            s
+        }
+        "#,
+    );
+
+    let vm = Vm::new(Arc::new(context.runtime()), unit);
+    let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+    assert_eq!(String::from_value(output).unwrap(), "hello world");
+}
+
+#[test]
+fn test_excluded_module_is_unavailable() {
+    let context = restricted_context();
+
+    assert!(context.contains_prefix(&Item::with_crate_item("std", &["string"])));
+    assert!(!context.contains_prefix(&Item::with_crate_item("std", &["io"])));
+    assert!(!context.contains_prefix(&Item::with_crate_item("std", &["env"])));
+
+    let diagnostics = rune_tests::compile_source(
+        &context,
+        r#"
+        pub fn main() {
+            std::io::println("hello")
+        }
+        "#,
+    )
+    .expect_err("std::io should be unavailable");
+
+    let errors = diagnostics
+        .into_diagnostics()
+        .into_iter()
+        .filter_map(|d| match d {
+            Diagnostic::Error(error) => Some(error),
+            Diagnostic::Warning(..) => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(errors.len(), 1);
+
+    match errors[0].kind() {
+        ErrorKind::CompileError(error) => match error.kind() {
+            CompileErrorKind::MissingItem { item } => {
+                assert_eq!(*item, Item::with_crate_item("std", &["io", "println"]));
+            }
+            kind => panic!("unexpected compile error: {:?}", kind),
+        },
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
+
+#[test]
+fn test_install_excluded_module() {
+    let mut context = restricted_context();
+
+    let mut module = Module::with_crate_item("std", &["io"]);
+    module.function(&["answer"], || 42i64).unwrap();
+
+    match context.install(&module) {
+        Err(ContextError::ModuleNotAllowed { item }) => {
+            assert_eq!(item, Item::with_crate_item("std", &["io"]));
+        }
+        actual => panic!("expected module to not be allowed, got {:?}", actual),
+    }
+
+    context.install_if_allowed(&module).unwrap();
+    assert!(!context.contains_prefix(&Item::with_crate_item("std", &["io"])));
+}
@@ -0,0 +1,70 @@
+use runestick::{Any, Context, ContextError, Module, Protocol};
+
+#[derive(Any)]
+struct Numbers {
+    values: Vec<i64>,
+}
+
+impl Numbers {
+    fn sum(&self) -> i64 {
+        self.values.iter().sum()
+    }
+
+    fn max(&self) -> Option<i64> {
+        self.values.iter().copied().max()
+    }
+
+    fn iter(&self) -> runestick::Iterator {
+        runestick::Iterator::from_double_ended("Numbers", self.values.clone().into_iter())
+    }
+}
+
+#[test]
+fn test_missing_required_protocol() {
+    let mut module = Module::default();
+    module.ty::<Numbers>().unwrap();
+    module
+        .inst_fn_requires("sum", Numbers::sum, &[Protocol::INTO_ITER])
+        .unwrap();
+    module
+        .inst_fn_requires("max", Numbers::max, &[Protocol::INTO_ITER])
+        .unwrap();
+
+    let mut context = Context::new();
+
+    match context.install(&module) {
+        Err(ContextError::MissingProtocols { functions }) => {
+            let mut names = functions
+                .iter()
+                .map(|f| f.name.as_str())
+                .collect::<Vec<_>>();
+            names.sort();
+            assert_eq!(names, vec!["max", "sum"]);
+
+            for f in &functions {
+                assert_eq!(f.protocols, vec![Protocol::INTO_ITER]);
+            }
+        }
+        actual => panic!("expected missing protocols, got {:?}", actual),
+    }
+
+    // NB: nothing from the failing module was installed, so the type can
+    // still be installed.
+    let mut module = Module::default();
+    module.ty::<Numbers>().unwrap();
+    module.inst_fn("sum", Numbers::sum).unwrap();
+    context.install(&module).unwrap();
+}
+
+#[test]
+fn test_present_required_protocol() {
+    let mut module = Module::default();
+    module.ty::<Numbers>().unwrap();
+    module
+        .inst_fn_requires("sum", Numbers::sum, &[Protocol::INTO_ITER])
+        .unwrap();
+    module.inst_fn(Protocol::INTO_ITER, Numbers::iter).unwrap();
+
+    let mut context = Context::new();
+    context.install(&module).unwrap();
+}
@@ -19,6 +19,29 @@ fn test_stringify() {
     assert_eq!("assert_eq ! ( 1 + 1 , 2 )", out);
 }
 
+#[test]
+fn test_matches() {
+    assert!(rune!(bool => pub fn main() { matches!(Some(5), Some(n) if n > 1) }));
+    assert!(!rune!(bool => pub fn main() { matches!(Some(1), Some(n) if n > 1) }));
+    assert!(!rune!(bool => pub fn main() { matches!(None, Some(_)) }));
+
+    let out: (bool, bool, bool) = rune!((bool, bool, bool) =>
+        enum Shape { Circle(r), Square(side) }
+
+        pub fn main() {
+            let shapes = [Shape::Circle(1), Shape::Square(2)];
+            let first = shapes[0];
+
+            (
+                matches!(first, Shape::Circle(_)),
+                matches!(shapes[1], Shape::Square(2),),
+                matches!([1, 2, 3], [1, ..]),
+            )
+        }
+    );
+    assert_eq!(out, (true, true, true));
+}
+
 #[test]
 fn test_unicode() {
     test_case!("{name:😊^10}", name = "😞😞😞😞😞");
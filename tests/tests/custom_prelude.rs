@@ -0,0 +1,43 @@
+use rune::{CompileErrorKind, Diagnostic, Diagnostics, ErrorKind, Spanned as _, UnitBuilder};
+use runestick::{Context, Item, Span};
+
+fn compile(source: &str) -> Diagnostics {
+    let context = Context::with_default_modules().unwrap();
+
+    let unit = UnitBuilder::with_prelude(vec![(
+        "Some",
+        Item::with_crate_item("std", &["option", "Option", "Some"]),
+    )]);
+
+    match rune_tests::compile_source_with_unit(&context, unit, source) {
+        Ok((_, diagnostics)) => diagnostics,
+        Err(diagnostics) => diagnostics,
+    }
+}
+
+#[test]
+fn test_custom_prelude() {
+    assert!(!compile(r#"pub fn main() { Some(1) }"#).has_error());
+    assert!(!compile(r#"pub fn main() { std::option::Option::None }"#).has_error());
+
+    let errors = compile(r#"pub fn main() { None }"#)
+        .into_diagnostics()
+        .into_iter()
+        .filter_map(|d| match d {
+            Diagnostic::Error(error) => Some(error),
+            Diagnostic::Warning(..) => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(errors.len(), 1);
+
+    match errors[0].kind() {
+        ErrorKind::CompileError(error) => match error.kind() {
+            CompileErrorKind::MissingItem { .. } => {
+                assert_eq!(error.span(), Span::new(16, 20));
+            }
+            kind => panic!("unexpected compile error: {:?}", kind),
+        },
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
@@ -0,0 +1,49 @@
+use rune::Disassemble as _;
+
+#[test]
+fn test_disassemble() {
+    let context = runestick::Context::with_default_modules().unwrap();
+    let options = rune::Options::default();
+
+    let mut sources = rune::Sources::new();
+    sources.insert(runestick::Source::new(
+        "main",
+        r#"
+        pub fn main() {
+            let n = 0;
+
+            for i in [1, 2] {
+                n += i;
+            }
+
+            n
+        }
+        "#,
+    ));
+
+    let mut diagnostics = rune::Diagnostics::new();
+
+    let unit = rune::load_sources(&context, &options, &mut sources, &mut diagnostics).unwrap();
+
+    let disassembly = unit.disassemble(&sources);
+    assert_eq!(disassembly.functions.len(), 1);
+
+    let function = &disassembly.functions[0];
+    assert_eq!(function.signature.as_deref(), Some("main()"));
+
+    assert!(function
+        .instructions
+        .iter()
+        .any(|i| i.comment.as_deref() == Some("copy next")));
+
+    assert!(function.instructions.iter().any(|i| matches!(
+        &i.source,
+        Some((_, line)) if line.trim() == "n += i;"
+    )));
+
+    let output = disassembly.to_string();
+    assert!(output.starts_with("fn main() ("));
+    assert!(output.contains("  3: let n = 0;\n"));
+    assert!(output.contains("= return"));
+    assert!(output.contains(" // copy next\n"));
+}
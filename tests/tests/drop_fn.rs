@@ -0,0 +1,66 @@
+use runestick::{Any, Context, Module};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[derive(Any, Default)]
+struct Handle {
+    closed: Arc<AtomicBool>,
+}
+
+impl Handle {
+    fn close(&mut self) {
+        self.closed.store(true, Ordering::SeqCst);
+    }
+}
+
+fn context() -> Context {
+    let mut module = Module::new();
+    module.ty::<Handle>().unwrap();
+    module.drop_fn(Handle::close).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    context
+}
+
+#[test]
+fn test_drop_fn() {
+    let context = Arc::new(context());
+    let handle = Handle::default();
+    let closed = handle.closed.clone();
+
+    rune_tests::run::<_, _, ()>(
+        &context,
+        r#"
+        pub fn main(handle) {
+            drop(handle);
+        }
+        "#,
+        ["main"],
+        (handle,),
+    )
+    .unwrap();
+
+    assert!(closed.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_drop_fn_not_called_without_drop() {
+    let context = Arc::new(context());
+    let handle = Handle::default();
+    let closed = handle.closed.clone();
+
+    rune_tests::run::<_, _, ()>(
+        &context,
+        r#"
+        pub fn main(handle) {
+            let handle = handle;
+        }
+        "#,
+        ["main"],
+        (handle,),
+    )
+    .unwrap();
+
+    assert!(!closed.load(Ordering::SeqCst));
+}
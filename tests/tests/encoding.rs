@@ -0,0 +1,50 @@
+//! Tests for the encoding module.
+
+use rune_tests::*;
+use runestick::Bytes;
+
+#[test]
+fn test_hex_roundtrip() {
+    let out: (String, Bytes, Bytes) = rune! { (String, Bytes, Bytes) =>
+        pub fn main() {
+            let hex = encoding::to_hex("hello");
+            let bytes = encoding::from_hex(hex).unwrap();
+            let raw = encoding::from_hex(encoding::to_hex(b"\x00\xff\x10")).unwrap();
+            (hex, bytes, raw)
+        }
+    };
+
+    assert_eq!(out.0, "68656c6c6f");
+    assert_eq!(&*out.1, b"hello");
+    assert_eq!(&*out.2, b"\x00\xff\x10");
+
+    assert!(rune! { bool =>
+        pub fn main() {
+            encoding::from_hex("abc").is_none()
+                && encoding::from_hex("zz").is_none()
+                && encoding::from_hex("ABcd").is_some()
+        }
+    });
+}
+
+#[test]
+fn test_base64_roundtrip() {
+    let out: (String, Bytes, Bytes) = rune! { (String, Bytes, Bytes) =>
+        pub fn main() {
+            let encoded = encoding::to_base64("hello world");
+            let bytes = encoding::from_base64(encoded).unwrap();
+            let raw = encoding::from_base64(encoding::to_base64(b"\x00\xff\x10")).unwrap();
+            (encoded, bytes, raw)
+        }
+    };
+
+    assert_eq!(out.0, "aGVsbG8gd29ybGQ=");
+    assert_eq!(&*out.1, b"hello world");
+    assert_eq!(&*out.2, b"\x00\xff\x10");
+
+    assert!(rune! { bool =>
+        pub fn main() {
+            encoding::from_base64("not base64!").is_none()
+        }
+    });
+}
@@ -53,3 +53,218 @@ fn test_ignore_binding() {
 
     assert_eq!(out, 4);
 }
+
+#[test]
+fn test_for_loop_unrolled() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let mut options = rune::Options::default();
+    options.unroll_loops(true);
+
+    let mut sources = rune::Sources::new();
+    sources.insert(runestick::Source::new(
+        "main",
+        r#"
+        pub fn main() {
+            let out = [];
+
+            for i in 0..4 {
+                out.push(i * 2);
+            }
+
+            for i in 10..=12 {
+                out.push(i);
+            }
+
+            out
+        }
+        "#,
+    ));
+
+    let mut diagnostics = rune::Diagnostics::new();
+    let unit = rune::load_sources(&context, &options, &mut sources, &mut diagnostics).unwrap();
+
+    let unit = std::sync::Arc::new(unit);
+    let vm = runestick::Vm::new(std::sync::Arc::new(context.runtime()), unit.clone());
+    let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+
+    assert_eq!(
+        Vec::<i64>::from_value(output).unwrap(),
+        vec![0, 2, 4, 6, 10, 11, 12]
+    );
+
+    let iter_next = unit
+        .iter_instructions()
+        .filter(|inst| matches!(inst, runestick::Inst::IterNext { .. }))
+        .count();
+
+    assert_eq!(iter_next, 0);
+}
+
+#[test]
+fn test_for_loop_not_unrolled_with_loop_control() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let mut options = rune::Options::default();
+    options.unroll_loops(true);
+
+    let mut sources = rune::Sources::new();
+    sources.insert(runestick::Source::new(
+        "main",
+        r#"
+        pub fn main() {
+            let out = [];
+
+            for i in 10..=16 {
+                if i == 12 { continue; }
+                if i == 15 { break; }
+                out.push(i);
+            }
+
+            'outer: for i in 0..4 {
+                for j in 0..2 {
+                    if i == 2 { break 'outer; }
+                    out.push(i * 10 + j);
+                }
+            }
+
+            for i in 0..2 {
+                for j in 0..4 {
+                    if j == 1 { break; }
+                    out.push(i * 100 + j);
+                }
+            }
+
+            out
+        }
+        "#,
+    ));
+
+    let mut diagnostics = rune::Diagnostics::new();
+    let unit = rune::load_sources(&context, &options, &mut sources, &mut diagnostics).unwrap();
+
+    let unit = std::sync::Arc::new(unit);
+    let vm = runestick::Vm::new(std::sync::Arc::new(context.runtime()), unit.clone());
+    let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+
+    assert_eq!(
+        Vec::<i64>::from_value(output).unwrap(),
+        vec![10, 11, 13, 14, 0, 1, 10, 11, 0, 100]
+    );
+
+    let iter_next = unit
+        .iter_instructions()
+        .filter(|inst| matches!(inst, runestick::Inst::IterNext { .. }))
+        .count();
+
+    // NB: the first loop, the labelled `'outer` loop and the inner loop of the
+    // last one are targeted by loop control and aren't unrolled. The latter is
+    // assembled once for each unrolled iteration of its parent.
+    assert_eq!(iter_next, 4);
+}
+
+#[test]
+fn test_for_loop_custom_iter() {
+    let out = rune! { Vec<i64> =>
+        struct Range { start, end }
+
+        impl Range {
+            fn iter(self) {
+                RangeIter { current: self.start, end: self.end }
+            }
+        }
+
+        struct RangeIter { current, end }
+
+        impl RangeIter {
+            fn next(self) {
+                if self.current >= self.end {
+                    return None;
+                }
+
+                let current = self.current;
+                self.current += 1;
+                Some(current)
+            }
+        }
+
+        pub fn main() {
+            let out = [];
+            let range = Range { start: 2, end: 5 };
+
+            for n in range {
+                out.push(n);
+            }
+
+            for n in (Range { start: 7, end: 9 }) {
+                out.push(n);
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(out, vec![2, 3, 4, 7, 8]);
+
+    assert_compile_error! {
+        r#"struct Foo { a } pub fn main() { for n in (Foo { a: 1 }) {} }"#,
+        span, NotIterable { ty } => {
+            assert_eq!(span, Span::new(43, 55));
+            assert_eq!(&*ty, "Foo");
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { for n in 42 {} }"#,
+        span, NotIterable { ty } => {
+            assert_eq!(span, Span::new(25, 27));
+            assert_eq!(&*ty, "integer");
+        }
+    };
+}
+
+#[test]
+fn test_for_else() {
+    let out = rune! { (i64, i64) =>
+        fn find(values, needle) {
+            for (i, v) in values.iter().enumerate() {
+                if v == needle {
+                    break i;
+                }
+            } else {
+                -1
+            }
+        }
+
+        pub fn main() {
+            (find([1, 2, 3], 2), find([1, 2, 3], 4))
+        }
+    };
+
+    assert_eq!(out, (1, -1));
+
+    let out = rune! { Vec<i64> =>
+        pub fn main() {
+            let out = [];
+
+            for i in 0..3 {
+                out.push(i);
+            } else {
+                let i = 10;
+                out.push(i);
+            }
+
+            for i in 0..3 {
+                if i == 1 {
+                    break;
+                }
+            } else {
+                out.push(20);
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(out, vec![0, 1, 2, 10]);
+}
@@ -0,0 +1,38 @@
+use rune_tests::capture_output;
+use runestick::{Context, FromValue as _};
+use std::sync::Arc;
+
+#[test]
+fn test_dbg_macro() {
+    let mut context = rune_modules::with_config(false).unwrap();
+    context
+        .install(&capture_output::output_redirect_module().unwrap())
+        .unwrap();
+    let context: Arc<Context> = Arc::new(context);
+
+    let vm = rune_tests::vm_with_source(
+        &context,
+        r#"
+        pub fn main() {
+            let x = 5;
+            let a = dbg!(x);
+            let b = dbg!(x + 1, "hi",);
+            let c = dbg!();
+            (a, b, c)
+        }
+        "#,
+    )
+    .unwrap();
+
+    let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+    let (a, b, c) = <(i64, (i64, String), ())>::from_value(output).unwrap();
+
+    assert_eq!(a, 5);
+    assert_eq!(b, (6, String::from("hi")));
+    assert_eq!(c, ());
+
+    assert_eq!(
+        capture_output::drain_output().as_deref(),
+        Some("[main:4] x = 5\n[main:5] x + 1 = 6\n[main:5] \"hi\" = \"hi\"\n[main:6]\n")
+    );
+}
@@ -0,0 +1,61 @@
+use rune::{
+    ast, macros, CompileErrorKind, Diagnostic, Diagnostics, ErrorKind, Options, Parser,
+    Spanned as _,
+};
+use rune::{Sources, TokenStream};
+use runestick::{Context, Module, Source, Span};
+
+/// A macro which only accepts a single string literal.
+fn only_strings(stream: &TokenStream) -> runestick::Result<TokenStream> {
+    let mut parser = Parser::from_token_stream(stream);
+    let lit = parser.parse::<ast::Lit>()?;
+    parser.eof()?;
+
+    match lit {
+        ast::Lit::Str(..) => Ok(rune::quote!(#lit).into_token_stream()),
+        _ => Err(macros::error(&lit, "expected a string literal").into()),
+    }
+}
+
+#[test]
+fn test_macro_error() {
+    let mut module = Module::new();
+    module.macro_(&["only_strings"], only_strings).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new(
+        "main",
+        r#"pub fn main() { only_strings!(42) }"#,
+    ));
+
+    let mut diagnostics = Diagnostics::new();
+    let result = rune::load_sources(
+        &context,
+        &Options::default(),
+        &mut sources,
+        &mut diagnostics,
+    );
+    assert!(result.is_err());
+
+    let error = match diagnostics.into_diagnostics().into_iter().next() {
+        Some(Diagnostic::Error(error)) => error,
+        other => panic!("expected an error, but got {:?}", other),
+    };
+
+    let error = match error.into_kind() {
+        ErrorKind::CompileError(error) => error,
+        kind => panic!("expected a compile error, but got {:?}", kind),
+    };
+
+    assert_eq!(error.span(), Span::new(30, 32));
+
+    match error.into_kind() {
+        CompileErrorKind::MacroError { message } => {
+            assert_eq!(&*message, "expected a string literal");
+        }
+        kind => panic!("expected a macro error, but got {:?}", kind),
+    }
+}
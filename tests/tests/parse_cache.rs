@@ -0,0 +1,72 @@
+use rune::{CompileOptions, Options, ParseCache, Sources};
+use runestick::{Context, FromValue as _, Source, Vm};
+use std::sync::Arc;
+
+fn compile(context: &Context, cache: &ParseCache, b: &str) -> Vm {
+    let mut sources = Sources::new();
+    sources.insert(Source::new("a", "pub fn a() { 1 }"));
+    sources.insert(Source::new("b", b));
+
+    let unit = rune_tests::compile_sources_with_options(
+        context,
+        &mut sources,
+        &Options::default(),
+        CompileOptions {
+            cache: Some(cache),
+            ..Default::default()
+        },
+    );
+
+    Vm::new(Arc::new(context.runtime()), unit)
+}
+
+#[test]
+fn test_parse_cache_reuses_unchanged_modules() {
+    let context = Context::with_default_modules().unwrap();
+    let cache = ParseCache::new();
+
+    let vm = compile(&context, &cache, "pub fn b() { a() + 1 }");
+    let output = vm.execute(["b"], ()).unwrap().complete().unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 2);
+    assert_eq!((cache.hits(), cache.misses()), (0, 2));
+
+    // Only the edited module is parsed again.
+    let vm = compile(&context, &cache, "pub fn b() { a() + 2 }");
+    let output = vm.execute(["b"], ()).unwrap().complete().unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 3);
+    assert_eq!((cache.hits(), cache.misses()), (1, 3));
+
+    let vm = compile(&context, &cache, "pub fn b() { a() + 2 }");
+    let output = vm.execute(["b"], ()).unwrap().complete().unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 3);
+    assert_eq!((cache.hits(), cache.misses()), (3, 3));
+
+    cache.clear();
+    compile(&context, &cache, "pub fn b() { a() + 2 }");
+    assert_eq!((cache.hits(), cache.misses()), (3, 5));
+}
+
+#[test]
+fn test_parse_cache_sources_with_same_name() {
+    let context = Context::with_default_modules().unwrap();
+    let cache = ParseCache::new();
+
+    for _ in 0..2 {
+        let mut sources = Sources::new();
+        sources.insert(Source::new("main", "pub fn a() { 1 }"));
+        sources.insert(Source::new("main", "pub fn b() { 2 }"));
+
+        rune_tests::compile_sources_with_options(
+            &context,
+            &mut sources,
+            &Options::default(),
+            CompileOptions {
+                cache: Some(&cache),
+                ..Default::default()
+            },
+        );
+    }
+
+    // NB: sources which share a name don't evict each other.
+    assert_eq!((cache.hits(), cache.misses()), (2, 2));
+}
@@ -0,0 +1,33 @@
+use rune::termcolor::Buffer;
+use rune::{Diagnostics, EmitDiagnostics as _, Options, Sources};
+use runestick::{Context, Source};
+
+#[test]
+fn test_diagnostics_with_base_line() {
+    let context = Context::with_default_modules().unwrap();
+    let options = Options::default();
+
+    let mut sources = Sources::new();
+    sources
+        .insert(Source::new("template.html", "pub fn main() {\n    missing()\n}").with_base(41, 2));
+
+    let mut diagnostics = Diagnostics::new();
+    let result = rune::load_sources(&context, &options, &mut sources, &mut diagnostics);
+    assert!(result.is_err());
+
+    let mut out = Buffer::no_color();
+    diagnostics.emit_diagnostics(&mut out, &sources).unwrap();
+    let out = String::from_utf8(out.into_inner()).unwrap();
+
+    assert!(out.contains("template.html:43:5"), "{}", out);
+    assert!(out.contains("43 │     missing()"), "{}", out);
+}
+
+#[test]
+fn test_position_with_base() {
+    let source = Source::new("template.html", "let a = 1;\nlet b = 2;").with_base(41, 2);
+
+    assert_eq!(source.position_to_unicode_line_char(4), (41, 6));
+    assert_eq!(source.position_to_unicode_line_char(15), (42, 4));
+    assert_eq!(source.position_to_utf16cu_line_char(15), Some((42, 4)));
+}
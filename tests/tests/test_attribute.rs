@@ -21,6 +21,68 @@ fn basic_use() {
     }
 }
 
+#[test]
+fn test_iter_tests() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        mod private {
+            #[test]
+            fn test_private() {
+                1
+            }
+        }
+
+        #[test]
+        fn test_root() {
+            2
+        }
+
+        pub fn main() {
+        }
+        "#,
+    )
+    .unwrap();
+
+    let tests = unit.iter_tests().cloned().collect::<Vec<_>>();
+
+    assert_eq!(
+        tests,
+        vec![
+            runestick::Item::with_item(&["private", "test_private"]),
+            runestick::Item::with_item(&["test_root"]),
+        ]
+    );
+
+    let context = std::sync::Arc::new(context.runtime());
+    let unit = std::sync::Arc::new(unit);
+    let mut outputs = Vec::new();
+
+    for item in &tests {
+        let vm = runestick::Vm::new(context.clone(), unit.clone());
+        let output = vm.call(runestick::Hash::type_hash(item), ()).unwrap();
+        outputs.push(output.into_integer().unwrap());
+    }
+
+    assert_eq!(outputs, vec![1, 2]);
+}
+
+#[test]
+fn deny_test_with_arguments() {
+    assert_compile_error! {
+        r#"
+        #[test]
+        fn test_fn(a) {
+        }
+        "#,
+        span, TestWithArguments => {
+            assert_eq!(span, Span::new(9, 38));
+        }
+    }
+}
+
 // We prevent tests from being declared inside of nested items at compile time.
 #[test]
 fn deny_nested_use() {
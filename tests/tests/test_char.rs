@@ -0,0 +1,103 @@
+use rune_tests::*;
+
+#[test]
+fn test_char_from_int() {
+    let out: (Option<char>, Option<char>, Option<char>) = rune! { (Option<char>, Option<char>, Option<char>) =>
+        pub fn main() {
+            (std::char::from_int(97), std::char::from_int(0x1F600), std::char::from_int(0x10FFFF))
+        }
+    };
+
+    assert_eq!(out, (Some('a'), Some('😀'), Some('\u{10FFFF}')));
+
+    let out = rune! { (Option<char>, Option<char>, Option<char>, Option<char>) =>
+        pub fn main() {
+            (
+                std::char::from_int(-1),
+                std::char::from_int(0xD800),
+                std::char::from_int(0x110000),
+                std::char::from_int(0x100000000),
+            )
+        }
+    };
+
+    assert_eq!(out, (None, None, None, None));
+}
+
+#[test]
+fn test_char_to_int() {
+    let out: (i64, i64) = rune! { (i64, i64) =>
+        pub fn main() {
+            ('a'.to_int(), '😀'.to_int())
+        }
+    };
+
+    assert_eq!(out, (97, 0x1F600));
+
+    let out: String = rune! { String =>
+        pub fn main() {
+            let out = String::new();
+
+            for c in "abz".chars() {
+                let n = (c.to_int() - 'a'.to_int() + 1) % 26 + 'a'.to_int();
+                out.push(std::char::from_int(n).unwrap());
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(out, "bca");
+}
+
+#[test]
+fn test_char_classification() {
+    let out = rune! { Vec<bool> =>
+        pub fn main() {
+            [
+                'a'.is_alphabetic(), '1'.is_alphabetic(),
+                'a'.is_alphanumeric(), '-'.is_alphanumeric(),
+                '7'.is_numeric(), 'x'.is_numeric(),
+                ' '.is_whitespace(), 'x'.is_whitespace(),
+            ]
+        }
+    };
+
+    assert_eq!(
+        out,
+        vec![true, false, true, false, true, false, true, false]
+    );
+
+    let out = rune! { (Option<char>, Option<char>, Option<char>, Option<char>) =>
+        pub fn main() {
+            ('a'.to_uppercase(), 'Ä'.to_uppercase(), '1'.to_uppercase(), 'ß'.to_uppercase())
+        }
+    };
+
+    assert_eq!(out, (Some('A'), Some('Ä'), Some('1'), None));
+}
+
+#[test]
+fn test_char_to_digit() {
+    let out = rune! { (Option<i64>, Option<i64>, Option<i64>, Option<i64>) =>
+        pub fn main() {
+            ('7'.to_digit(10), 'f'.to_digit(16), 'z'.to_digit(36), 'a'.to_digit(10))
+        }
+    };
+
+    assert_eq!(out, (Some(7), Some(15), Some(35), None));
+
+    for radix in &[0, 1, 37] {
+        let source = format!("pub fn main() {{ '1'.to_digit({}) }}", radix);
+
+        assert_vm_error!(
+            &source,
+            Panic { reason } => {
+                assert_eq!(
+                    reason.to_string(),
+                    format!("radix `{}` is out of range, expected a value between 2 and 36", radix)
+                );
+            }
+        );
+    }
+}
@@ -118,9 +118,22 @@ fn test_continue_not_in_loop() {
 fn test_continue_missing_label() {
     assert_compile_error! {
         r#"pub fn main() { 'existing: loop { loop { continue 'missing; } } }"#,
-        span, MissingLoopLabel { label } => {
+        span, MissingLoopLabel { label, existing } => {
             assert_eq!(span, Span::new(50, 58));
             assert_eq!(&*label, "missing");
+            assert_eq!(existing, vec![("existing".into(), Span::new(16, 25))]);
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { 'outer: loop { 'inner: loop { break 'iner; } } }"#,
+        span, MissingLoopLabel { label, existing } => {
+            assert_eq!(span, Span::new(52, 57));
+            assert_eq!(&*label, "iner");
+            assert_eq!(existing, vec![
+                ("inner".into(), Span::new(31, 37)),
+                ("outer".into(), Span::new(16, 22)),
+            ]);
         }
     };
 }
@@ -0,0 +1,144 @@
+//! Test for math functions
+
+use rune_tests::*;
+
+#[test]
+fn test_gcd_lcm() {
+    let out: Vec<i64> = rune! { Vec<i64> =>
+        pub fn main() {
+            [
+                std::math::gcd(12, 18),
+                std::math::gcd(-12, 18),
+                std::math::gcd(7, 0),
+                std::math::gcd(0, 0),
+                std::math::lcm(4, 6),
+                std::math::lcm(-4, 6),
+                std::math::lcm(0, 6),
+            ]
+        }
+    };
+
+    assert_eq!(out, vec![6, 6, 7, 0, 12, 12, 0]);
+
+    assert_vm_error!(
+        r#"pub fn main() { std::math::lcm(9223372036854775807, 2) }"#,
+        Overflow => {}
+    );
+}
+
+#[test]
+fn test_isqrt() {
+    let out: Vec<i64> = rune! { Vec<i64> =>
+        pub fn main() {
+            [
+                std::math::isqrt(0),
+                std::math::isqrt(1),
+                std::math::isqrt(15),
+                std::math::isqrt(16),
+                std::math::isqrt(17),
+                std::math::isqrt(9223372036854775807),
+            ]
+        }
+    };
+
+    assert_eq!(out, vec![0, 1, 3, 4, 4, 3037000499]);
+
+    assert_vm_error!(
+        r#"pub fn main() { std::math::isqrt(-4) }"#,
+        Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "cannot take the integer square root of negative number `-4`"
+            );
+        }
+    );
+}
+
+#[test]
+fn test_clamp_int() {
+    let out: Vec<i64> = rune! { Vec<i64> =>
+        pub fn main() {
+            [
+                std::math::clamp_int(5, 0, 10),
+                std::math::clamp_int(-5, 0, 10),
+                std::math::clamp_int(15, 0, 10),
+                std::math::clamp_int(3, 3, 3),
+            ]
+        }
+    };
+
+    assert_eq!(out, vec![5, 0, 10, 3]);
+
+    assert_vm_error!(
+        r#"pub fn main() { std::math::clamp_int(1, 10, 0) }"#,
+        Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "cannot clamp with a minimum `10` which is greater than the maximum `0`"
+            );
+        }
+    );
+}
+
+#[test]
+fn test_saturating() {
+    let out: Vec<i64> = rune! { Vec<i64> =>
+        pub fn main() {
+            let max = 9223372036854775807;
+            let min = -max - 1;
+
+            [
+                std::math::saturating_add(1, 2),
+                std::math::saturating_add(max, 1),
+                std::math::saturating_add(min, -1),
+                std::math::saturating_sub(5, 7),
+                std::math::saturating_sub(min, 1),
+                std::math::saturating_sub(max, -1),
+                std::math::saturating_mul(6, 7),
+                std::math::saturating_mul(max, 2),
+                std::math::saturating_mul(min, 2),
+            ]
+        }
+    };
+
+    assert_eq!(
+        out,
+        vec![
+            3,
+            i64::MAX,
+            i64::MIN,
+            -2,
+            i64::MIN,
+            i64::MAX,
+            42,
+            i64::MAX,
+            i64::MIN,
+        ]
+    );
+}
+
+#[test]
+fn test_float_bits() {
+    let out: (i64, f64, bool) = rune! { (i64, f64, bool) =>
+        pub fn main() {
+            let bits = std::math::to_bits(-1.5);
+            (bits, std::math::from_bits(bits), std::math::to_bits(0.0) == 0)
+        }
+    };
+
+    assert_eq!(out, ((-1.5f64).to_bits() as i64, -1.5, true));
+
+    // A NaN with a payload keeps its exact bit pattern.
+    let nan = 0x7ff8_0000_0000_0001u64 as i64;
+
+    let out: (f64, i64) = rune! { (f64, i64) =>
+        pub fn main() {
+            let nan = std::math::from_bits(0x7ff8000000000001);
+            (nan, std::math::to_bits(nan))
+        }
+    };
+
+    assert!(out.0.is_nan());
+    assert_eq!(out.0.to_bits() as i64, nan);
+    assert_eq!(out.1, nan);
+}
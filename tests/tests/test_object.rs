@@ -0,0 +1,84 @@
+//! Test for object functions
+
+use rune_tests::*;
+
+#[test]
+fn test_from_pairs() {
+    let out: (i64, i64, i64) = rune! { (i64, i64, i64) =>
+        pub fn main() {
+            let object = std::object::from_pairs([("a", 1), ("b", 2), ("a", 3)]);
+            (object.len(), object["a"], object["b"])
+        }
+    };
+
+    assert_eq!(out, (2, 3, 2));
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            std::object::from_pairs([(1, 2)])
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "object keys must be strings, but got `integer`");
+        }
+    );
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            std::object::from_pairs([["a", 2]])
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "expected a `(key, value)` tuple, but got `Vec`");
+        }
+    );
+}
+
+#[test]
+fn test_keys_values() {
+    let out: (Vec<String>, Vec<i64>, bool, bool) = rune! { (Vec<String>, Vec<i64>, bool, bool) =>
+        pub fn main() {
+            let object = #{};
+            object.insert("c", 3);
+            object.insert("a", 1);
+            object.insert("b", 2);
+
+            (
+                object.keys().collect_vec(),
+                object.values().collect_vec(),
+                object.contains_key("a"),
+                object.contains_key("d"),
+            )
+        }
+    };
+
+    // Objects are ordered by key, regardless of insertion order.
+    assert_eq!(out.0, vec!["a", "b", "c"]);
+    assert_eq!(out.1, vec![1, 2, 3]);
+    assert!(out.2);
+    assert!(!out.3);
+}
+
+#[test]
+fn test_keys_exclusively_borrowed() {
+    let object = runestick::Shared::new(runestick::Object::new());
+    let _guard = object.borrow_mut().unwrap();
+
+    let vm = rune_vm!(pub fn main(object) { object.keys() });
+    let error = vm
+        .call(["main"], (runestick::Value::Object(object.clone()),))
+        .unwrap_err();
+
+    let (kind, _) = error.as_unwound();
+    match kind {
+        runestick::VmErrorKind::BadArgument { error, arg: 0 } => {
+            assert!(matches!(
+                error.kind(),
+                runestick::VmErrorKind::AccessError { .. }
+            ));
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
@@ -182,3 +182,27 @@ fn test_object() {
         );
     });
 }
+
+#[test]
+fn test_debug_render() {
+    let ctx = MacroContext::empty();
+
+    with_context(ctx, || {
+        let stream = quote!(foo(1, "bar")).into_token_stream();
+        let output = stream.debug_render();
+
+        let lines = output.lines().collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            vec![
+                "0..0 ident `foo`",
+                "0..0 ( `(`",
+                "0..0 number `1`",
+                "0..0 , `,`",
+                "0..0 string `\"bar\"`",
+                "0..0 ) `)`",
+            ]
+        );
+    });
+}
@@ -93,3 +93,64 @@ fn test_unwrap() {
         }
     );
 }
+
+#[test]
+fn test_ok() {
+    assert_eq!(
+        rune! { (Option<u32>, Option<u32>) =>
+            pub fn main() {
+                (Ok(1).ok(), Err("Failed").ok())
+            }
+        },
+        (Some(1), None)
+    )
+}
+
+#[test]
+fn test_unwrap_or() {
+    assert_eq!(
+        rune! { (u32, u32) =>
+            pub fn main() {
+                (Ok(1).unwrap_or(2), Err("Failed").unwrap_or(2))
+            }
+        },
+        (1, 2)
+    )
+}
+
+#[test]
+fn test_unwrap_or_else() {
+    assert_eq!(
+        rune! { (u32, u32, Vec<String>) =>
+            pub fn main() {
+                let calls = [];
+                let a = Ok(1).unwrap_or_else(|e| { calls.push(e); 2 });
+                let b = Err("Failed").unwrap_or_else(|e| { calls.push(e); 2 });
+                (a, b, calls)
+            }
+        },
+        (1, 2, vec!["Failed".to_owned()])
+    )
+}
+
+#[test]
+fn test_try_combinators() {
+    assert_eq!(
+        rune! { Result<u32, String> =>
+            fn parse(v) {
+                if v > 0 { Ok(v) } else { Err("negative") }
+            }
+
+            fn inner() {
+                let a = parse(1).map(|v| v * 10)?;
+                let b = parse(-1).unwrap_or(2);
+                Ok(a + b + parse(-1).ok().unwrap_or(3))
+            }
+
+            pub fn main() {
+                inner()
+            }
+        },
+        Ok(15)
+    )
+}
@@ -0,0 +1,235 @@
+//! Test for string functions
+
+use rune_tests::*;
+
+#[test]
+fn test_parse_int() {
+    assert_eq!(
+        rune!(Option<i64> => pub fn main() { "42".parse_int(10) }),
+        Some(42)
+    );
+
+    assert_eq!(
+        rune!(Option<i64> => pub fn main() { "-ff".parse_int(16) }),
+        Some(-255)
+    );
+
+    assert_eq!(
+        rune!(Option<i64> => pub fn main() { "101".parse_int(2) }),
+        Some(5)
+    );
+
+    assert_eq!(
+        rune!(Option<i64> => pub fn main() { "z".parse_int(36) }),
+        Some(35)
+    );
+}
+
+#[test]
+fn test_parse_int_invalid() {
+    assert_eq!(
+        rune!(Option<i64> => pub fn main() { "102".parse_int(2) }),
+        None
+    );
+
+    assert_eq!(
+        rune!(Option<i64> => pub fn main() { "".parse_int(10) }),
+        None
+    );
+
+    assert_eq!(
+        rune!(Option<i64> => pub fn main() { "1.5".parse_int(10) }),
+        None
+    );
+}
+
+#[test]
+fn test_parse_int_bad_radix() {
+    assert_vm_error!(
+        r#"pub fn main() { "10".parse_int(1) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "radix `1` is not in the range 2 to 36");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { "10".parse_int(37) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "radix `37` is not in the range 2 to 36");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { "10".parse_int(-2) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "radix `-2` is not in the range 2 to 36");
+        }
+    );
+}
+
+#[test]
+fn test_parse_float() {
+    assert_eq!(
+        rune!(Option<f64> => pub fn main() { "4.5".parse_float() }),
+        Some(4.5)
+    );
+
+    assert_eq!(
+        rune!(Option<f64> => pub fn main() { "-1e3".parse_float() }),
+        Some(-1000.0)
+    );
+
+    assert_eq!(
+        rune!(Option<f64> => pub fn main() { "nope".parse_float() }),
+        None
+    );
+}
+
+#[test]
+fn test_byte_and_char_len() {
+    assert_eq!(
+        rune!((usize, usize) => pub fn main() { let s = "hello"; (s.byte_len(), s.char_len()) }),
+        (5, 5)
+    );
+
+    assert_eq!(
+        rune!((usize, usize) => pub fn main() { let s = "åäö 🦀"; (s.byte_len(), s.char_len()) }),
+        (11, 5)
+    );
+
+    assert_eq!(
+        rune!((bool, bool) => pub fn main() { ("".is_empty(), "å".is_empty()) }),
+        (true, false)
+    );
+}
+
+#[test]
+fn test_indent() {
+    assert_eq!(
+        rune!(String => pub fn main() { "a\n\n  b\r\nc".indent("> ") }),
+        "> a\n\n>   b\r\n> c"
+    );
+}
+
+#[test]
+fn test_dedent() {
+    assert_eq!(
+        rune!(String => pub fn main() { "    a\n\n      b\n    c\n".dedent() }),
+        "a\n\n  b\nc\n"
+    );
+
+    assert_eq!(
+        rune!(String => pub fn main() { "\t  a\n \n\t b\n".dedent() }),
+        " a\n\nb\n"
+    );
+
+    assert_eq!(
+        rune!(String => pub fn main() { "a\n  b".dedent() }),
+        "a\n  b"
+    );
+
+    assert_eq!(
+        rune!(String => pub fn main() { "    a\r\n  \r\n      b\r\n".dedent() }),
+        "a\r\n\r\n  b\r\n"
+    );
+}
+
+#[test]
+fn test_template() {
+    assert_eq!(
+        rune!(String => pub fn main() {
+            std::string::template("{name} is {age} years old", #{name: "Ada", age: 36})
+        }),
+        "Ada is 36 years old"
+    );
+
+    assert_eq!(
+        rune!(String => pub fn main() { std::string::template("{{a}} = {a}}}", #{a: 1}) }),
+        "{a} = 1}"
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { std::string::template("hello {name}", #{}) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "missing key `name` referenced by template");
+        }
+    );
+}
+
+#[test]
+fn test_split_trim_replace() {
+    assert_eq!(
+        rune!(Vec<String> => pub fn main() { "a→b→→c".split("→").collect_vec() }),
+        vec!["a", "b", "", "c"]
+    );
+
+    assert_eq!(
+        rune!(Vec<String> => pub fn main() { "ab".split("").collect_vec() }),
+        vec!["", "a", "b", ""]
+    );
+
+    assert_eq!(
+        rune!(Vec<String> => pub fn main() { "å,ä".split(',').collect_vec() }),
+        vec!["å", "ä"]
+    );
+
+    assert_eq!(
+        rune!(String => pub fn main() { " \t hello wörld \n".trim() }),
+        "hello wörld"
+    );
+
+    assert_eq!(
+        rune!(String => pub fn main() { "a→b→c".replace("→", "") }),
+        "abc"
+    );
+
+    assert_eq!(
+        rune!((bool, bool, bool) => pub fn main() {
+            ("ölkorv".starts_with("öl"), "ölkorv".starts_with(""), "ölkorv".starts_with("o"))
+        }),
+        (true, true, false)
+    );
+}
+
+#[test]
+fn test_char_indices() {
+    assert_eq!(
+        rune!(Vec<(usize, char)> => pub fn main() { "aåb€".char_indices() }),
+        vec![(0, 'a'), (1, 'å'), (3, 'b'), (4, '€')]
+    );
+
+    assert_eq!(
+        rune!(Vec<(usize, char)> => pub fn main() { "".char_indices() }),
+        Vec::<(usize, char)>::new()
+    );
+}
+
+#[test]
+fn test_matches() {
+    assert_eq!(
+        rune!(Vec<usize> => pub fn main() { "a,b,,c".matches(",") }),
+        vec![1, 3, 4]
+    );
+
+    assert_eq!(
+        rune!(Vec<usize> => pub fn main() { "aaaaa".matches("aa") }),
+        vec![0, 2]
+    );
+
+    assert_eq!(
+        rune!(Vec<usize> => pub fn main() { "åäö-åäö".matches("äö") }),
+        vec![1, 5]
+    );
+
+    assert_eq!(
+        rune!(Vec<usize> => pub fn main() { "hello".matches("x") }),
+        Vec::<usize>::new()
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { "hello".matches("") }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "cannot match an empty string");
+        }
+    );
+}
@@ -0,0 +1,526 @@
+//! Test for vec functions
+
+use rune_tests::*;
+
+#[test]
+fn test_sort_by() {
+    let out: Vec<(String, i64)> = rune! { Vec<(String, i64)> =>
+        struct Person { name, age }
+
+        pub fn main() {
+            let people = [
+                Person { name: "alice", age: 42 },
+                Person { name: "bob", age: 21 },
+                Person { name: "carol", age: 42 },
+                Person { name: "dave", age: 7 },
+            ];
+
+            people.sort_by(|a, b| a.age - b.age);
+
+            let out = [];
+
+            for p in people {
+                out.push((p.name, p.age));
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(
+        out,
+        vec![
+            (String::from("dave"), 7),
+            (String::from("bob"), 21),
+            (String::from("alice"), 42),
+            (String::from("carol"), 42),
+        ]
+    );
+}
+
+#[test]
+fn test_sort_by_error() {
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            let values = [3, 1, 2];
+            values.sort_by(|a, b| panic("bad comparison"));
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "bad comparison");
+        }
+    );
+}
+
+#[test]
+fn test_extend_from() {
+    let out: Vec<i64> = rune! { Vec<i64> =>
+        pub fn main() {
+            let out = [];
+            out.extend_from([1, 2]);
+            out.extend_from([]);
+            out.extend_from([3]);
+            out
+        }
+    };
+
+    assert_eq!(out, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_truncate() {
+    let out: (Vec<i64>, Vec<i64>, Vec<i64>) = rune! { (Vec<i64>, Vec<i64>, Vec<i64>) =>
+        pub fn main() {
+            let a = [1, 2, 3];
+            a.truncate(2);
+            let b = [1, 2, 3];
+            b.truncate(10);
+            let c = [1, 2, 3];
+            c.truncate(0);
+            (a, b, c)
+        }
+    };
+
+    assert_eq!(out, (vec![1, 2], vec![1, 2, 3], vec![]));
+}
+
+#[test]
+fn test_rotate() {
+    let out: Vec<Vec<i64>> = rune! { Vec<Vec<i64>> =>
+        pub fn main() {
+            let a = [1, 2, 3, 4];
+            a.rotate_left(1);
+            let b = [1, 2, 3, 4];
+            b.rotate_right(1);
+            let c = [1, 2, 3, 4];
+            c.rotate_left(6);
+            let d = [1, 2, 3, 4];
+            d.rotate_right(9);
+            let e = [1, 2, 3, 4];
+            e.rotate_left(8);
+            [a, b, c, d, e]
+        }
+    };
+
+    assert_eq!(
+        out,
+        vec![
+            vec![2, 3, 4, 1],
+            vec![4, 1, 2, 3],
+            vec![3, 4, 1, 2],
+            vec![4, 1, 2, 3],
+            vec![1, 2, 3, 4],
+        ]
+    );
+}
+
+#[test]
+fn test_rotate_empty() {
+    let out: Vec<i64> = rune! { Vec<i64> =>
+        pub fn main() {
+            let out = [];
+            out.rotate_left(3);
+            out.rotate_right(2);
+            out
+        }
+    };
+
+    assert!(out.is_empty());
+}
+
+#[test]
+fn test_group_by() {
+    let out: (Vec<i64>, Vec<i64>, usize) = rune! { (Vec<i64>, Vec<i64>, usize) =>
+        pub fn main() {
+            let groups = [1, 2, 3, 4, 5].group_by(|n| if n % 2 == 0 { "even" } else { "odd" });
+            (groups["even"], groups["odd"], groups.len())
+        }
+    };
+
+    assert_eq!(out, (vec![2, 4], vec![1, 3, 5], 2));
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            [1, 2, 3].group_by(|n| n % 2);
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "group_by key must be a string, but got `integer`");
+        }
+    );
+}
+
+#[test]
+fn test_min_max() {
+    let out: (Option<i64>, Option<i64>, Option<f64>, Option<i64>) = rune! { (Option<i64>, Option<i64>, Option<f64>, Option<i64>) =>
+        pub fn main() {
+            let values = [3, 1, 4, 1, 5];
+            (values.min(), values.max(), [2.5].max(), [].min())
+        }
+    };
+
+    assert_eq!(out, (Some(1), Some(5), Some(2.5), None));
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            [1, "a"].min();
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "cannot compare `String` and `integer`");
+        }
+    );
+}
+
+#[test]
+fn test_sum_product() {
+    let out: (i64, i64, i64, i64, f64) = rune! { (i64, i64, i64, i64, f64) =>
+        pub fn main() {
+            ([].sum(), [].product(), [7].sum(), [1, 2, 3, 4].product(), [1.5, 2.0].sum())
+        }
+    };
+
+    assert_eq!(out, (0, 1, 7, 24, 3.5));
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            [1, 2.0].sum();
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "cannot sum `integer` and `float`");
+        }
+    );
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            ["a"].product();
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "cannot multiply `String`");
+        }
+    );
+}
+
+#[test]
+fn test_dedup_by_eq() {
+    let out: Vec<Vec<i64>> = rune! { Vec<Vec<i64>> =>
+        pub fn main() {
+            let values = [[1], [1], [2], [1], [3, 4], [3, 4]];
+            values.dedup_by(std::eq);
+            values
+        }
+    };
+
+    assert_eq!(out, vec![vec![1], vec![2], vec![1], vec![3, 4]]);
+
+    let out: (bool, bool) = rune! { (bool, bool) =>
+        pub fn main() {
+            let eq = std::eq;
+            (eq(#{a: "b"}, #{a: "b"}), eq(Some(1), None))
+        }
+    };
+
+    assert_eq!(out, (true, false));
+}
+
+#[test]
+fn test_shuffle() {
+    let out: (Vec<i64>, Vec<i64>, Vec<i64>) = rune! { (Vec<i64>, Vec<i64>, Vec<i64>) =>
+        fn shuffled(seed) {
+            let values = [1, 2, 3, 4, 5, 6, 7, 8];
+            values.shuffle(rand::Pcg64::new_seed(seed));
+            values
+        }
+
+        pub fn main() {
+            let empty = [];
+            empty.shuffle(rand::WyRand::new_seed(1));
+            (shuffled(42), shuffled(42), empty)
+        }
+    };
+
+    let (a, b, empty) = out;
+    assert_eq!(a, b);
+    assert!(empty.is_empty());
+
+    let mut sorted = a;
+    sorted.sort();
+    assert_eq!(sorted, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn test_binary_search() {
+    let out: (Option<usize>, Option<usize>, Option<usize>, Option<usize>) = rune! { (Option<usize>, Option<usize>, Option<usize>, Option<usize>) =>
+        pub fn main() {
+            let values = [1, 3, 5, 7, 9];
+            (values.binary_search(7), values.binary_search(1), values.binary_search(4), [].binary_search(1))
+        }
+    };
+
+    assert_eq!(out, (Some(3), Some(0), None, None));
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            [1, 2, 3].binary_search("a")
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "cannot compare `integer` and `String`");
+        }
+    );
+}
+
+#[test]
+fn test_flat_map() {
+    let out = rune!(Vec<i64> => pub fn main() { [1, 2, 3].flat_map(|n| [n, n]) });
+    assert_eq!(out, vec![1, 1, 2, 2, 3, 3]);
+
+    let out = rune!(Vec<i64> => pub fn main() { [1, 2, 3].flat_map(|n| []) });
+    assert_eq!(out, Vec::<i64>::new());
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            [1, 2, 3].flat_map(|n| n);
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "flat_map function must return a vector, but got `integer`");
+        }
+    );
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            [1, 2, 3].flat_map(|n| panic("bad element"));
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "bad element");
+        }
+    );
+}
+
+#[test]
+fn test_take_skip() {
+    let out = rune!((Vec<i64>, Vec<i64>, Vec<i64>, Vec<i64>) => pub fn main() {
+        let v = [1, 2, 3];
+        (v.take(0), v.take(2), v.take(3), v.take(4))
+    });
+    assert_eq!(out, (vec![], vec![1, 2], vec![1, 2, 3], vec![1, 2, 3]));
+
+    let out = rune!((Vec<i64>, Vec<i64>, Vec<i64>, Vec<i64>) => pub fn main() {
+        let v = [1, 2, 3];
+        (v.skip(0), v.skip(2), v.skip(3), v.skip(4))
+    });
+    assert_eq!(out, (vec![1, 2, 3], vec![3], vec![], vec![]));
+
+    let out = rune!(Vec<i64> => pub fn main() {
+        let v = [1, 2, 3];
+        v.take(1);
+        v.skip(1);
+        v
+    });
+    assert_eq!(out, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_count() {
+    let out = rune!((usize, usize, usize, usize) => pub fn main() {
+        let numbers = [1, 2, 1, 3, 1];
+        let strings = ["a", "b", "a"];
+        let vectors = [[1, 2], [3], [1, 2]];
+        (numbers.count(1), strings.count("a"), vectors.count([1, 2]), [].count(1))
+    });
+    assert_eq!(out, (3, 2, 2, 0));
+
+    let out = rune!((usize, usize, usize) => pub fn main() {
+        let v = [1, 2, 3, 4, 5];
+        (v.count_by(|n| n % 2 == 0), v.count_by(|n| n > 10), [].count_by(|n| true))
+    });
+    assert_eq!(out, (2, 0, 0));
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2].count_by(|n| panic("boom")) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "boom");
+        }
+    );
+}
+
+#[test]
+fn test_iter_enumerate() {
+    let out = rune!((Vec<i64>, Vec<(usize, i64)>) => pub fn main() {
+        let v = [1, 2, 3];
+        (v.iter().map(|n| n * 2).collect_vec(), v.enumerate().collect_vec())
+    });
+    assert_eq!(out, (vec![2, 4, 6], vec![(0, 1), (1, 2), (2, 3)]));
+}
+
+#[test]
+fn test_position() {
+    let out = rune!((Option<usize>, Option<usize>, Option<usize>) => pub fn main() {
+        let v = [1, 2, 3, 2];
+        (v.position(|n| n == 2), v.position(|n| n > 10), [].position(|n| true))
+    });
+    assert_eq!(out, (Some(1), None, None));
+
+    // NB: the closure is not called past the first match.
+    let out = rune!((Option<usize>, i64) => pub fn main() {
+        let calls = [];
+        let index = [1, 2, 3, 4].position(|n| { calls.push(n); n == 2 });
+        (index, calls.len())
+    });
+    assert_eq!(out, (Some(1), 2));
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2].position(|n| panic("boom")) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "boom");
+        }
+    );
+}
+
+#[test]
+fn test_unique() {
+    let out = rune!((Vec<i64>, Vec<String>, Vec<f64>, Vec<i64>) => pub fn main() {
+        let numbers = [3, 1, 3, 2, 1, 4, 2];
+        let strings = ["b", "a", "b", "c", "a"];
+        let floats = [1.5, 2.0, 1.5, 3.0, 2.0];
+        let unique = numbers.unique();
+        (unique, strings.unique(), floats.unique(), numbers)
+    });
+
+    assert_eq!(
+        out,
+        (
+            vec![3, 1, 2, 4],
+            vec![String::from("b"), String::from("a"), String::from("c")],
+            vec![1.5, 2.0, 3.0],
+            vec![3, 1, 3, 2, 1, 4, 2],
+        )
+    );
+
+    let out = rune!(Vec<Vec<i64>> => pub fn main() { [[1, 2], [3], [1, 2], []].unique() });
+    assert_eq!(out, vec![vec![1, 2], vec![3], vec![]]);
+}
+
+#[test]
+fn test_swap() {
+    let out: Vec<i64> = rune! { Vec<i64> =>
+        pub fn main() {
+            let out = [1, 2, 3, 4];
+            out.swap(0, 3);
+            out.swap(1, 1);
+            out.swap(2, 1);
+            out
+        }
+    };
+
+    assert_eq!(out, vec![4, 3, 2, 1]);
+
+    assert_vm_error!(
+        r#"pub fn main() { let v = [1, 2]; v.swap(0, 2); }"#,
+        OutOfRange { index, len } => {
+            assert_eq!(index.to_string(), "2");
+            assert_eq!(len.to_string(), "2");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { let v = []; v.swap(0, 0); }"#,
+        OutOfRange { index, len } => {
+            assert_eq!(index.to_string(), "0");
+            assert_eq!(len.to_string(), "0");
+        }
+    );
+}
+
+#[test]
+fn test_fill() {
+    let out: (Vec<i64>, Vec<i64>) = rune! { (Vec<i64>, Vec<i64>) =>
+        pub fn main() {
+            let a = [1, 2, 3];
+            a.fill(7);
+            let b = [];
+            b.fill(7);
+            (a, b)
+        }
+    };
+
+    assert_eq!(out, (vec![7, 7, 7], vec![]));
+}
+
+#[test]
+fn test_reduce() {
+    let out: Option<i64> = rune!(Option<i64> => pub fn main() { [].reduce(|a, b| a + b) });
+    assert_eq!(out, None);
+
+    let out: Option<i64> = rune!(Option<i64> => pub fn main() { [7].reduce(|a, b| a + b) });
+    assert_eq!(out, Some(7));
+
+    let out: Option<i64> =
+        rune!(Option<i64> => pub fn main() { [1, 2, 3, 4].reduce(|a, b| a * 10 + b) });
+    assert_eq!(out, Some(1234));
+}
+
+#[test]
+fn test_reduce_error() {
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            [1, 2, 3].reduce(|a, b| panic("bad reduce"))
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "bad reduce");
+        }
+    );
+}
+
+#[test]
+fn test_const_index_bounds() {
+    assert_eq!(rune!(i64 => pub fn main() { [1, 2, 3][2] }), 3);
+    assert_eq!(rune!(i64 => pub fn main() { ([1, 2, 3])[1 + 1] }), 3);
+
+    assert_compile_error! {
+        r#"pub fn main() { [1, 2, 3][5] }"#,
+        span, ConstIndexOutOfBounds { len, index } => {
+            assert_eq!(span, Span::new(16, 28));
+            assert_eq!(len, 3);
+            assert_eq!(index, 5);
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { [][0] }"#,
+        span, ConstIndexOutOfBounds { len, index } => {
+            assert_eq!(span, Span::new(16, 21));
+            assert_eq!(len, 0);
+            assert_eq!(index, 0);
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { [1][0 - 1] }"#,
+        span, ConstIndexOutOfBounds { len, index } => {
+            assert_eq!(span, Span::new(16, 26));
+            assert_eq!(len, 1);
+            assert_eq!(index, -1);
+        }
+    };
+
+    // Non-constant indexes are still checked at runtime.
+    assert_vm_error!(
+        r#"fn index() { 5 } pub fn main() { [1, 2, 3][index()] }"#,
+        MissingIndex { .. } => {}
+    );
+}
@@ -0,0 +1,43 @@
+use runestick::{Context, Hash};
+
+fn content_hash(source: &str) -> Hash {
+    let context = Context::with_default_modules().unwrap();
+    rune_tests::compile_with_options(&context, &rune::Options::default(), source).content_hash()
+}
+
+const SOURCE: &str = r#"
+struct Point { x, y }
+enum Shape { Circle(r), Square(w) }
+
+fn area(shape) {
+    match shape {
+        Shape::Circle(r) => 3.14 * r * r,
+        Shape::Square(w) => w * w,
+    }
+}
+
+pub fn main() {
+    let point = Point { x: 1, y: 2 };
+    let values = #{"a": 1, "b": "two", "c": b"three"};
+
+    match point.x {
+        1 | 2 | 3 => area(Shape::Circle(1.0)),
+        _ => area(Shape::Square(2.0)),
+    }
+}
+"#;
+
+#[test]
+fn test_content_hash_stable() {
+    assert_eq!(content_hash(SOURCE), content_hash(SOURCE));
+}
+
+#[test]
+fn test_content_hash_changes() {
+    let changed = SOURCE.replace("\"two\"", "\"twO\"");
+    assert_ne!(changed, SOURCE);
+    assert_ne!(content_hash(SOURCE), content_hash(&changed));
+
+    let changed = SOURCE.replace("x: 1", "x: 2");
+    assert_ne!(content_hash(SOURCE), content_hash(&changed));
+}
@@ -0,0 +1,98 @@
+//! Test for round-tripping values through serde.
+
+use rune_tests::*;
+use runestick::Value;
+use std::sync::Arc;
+
+#[test]
+fn test_serialize_value() {
+    let value: Value = rune! { Value =>
+        pub fn main() {
+            #{
+                "unit": (),
+                "bool": true,
+                "integer": 42,
+                "float": 1.5,
+                "string": "hello",
+                "vec": [1, 2, 3],
+                "tuple": (1, "two"),
+                "option": Some(1),
+                "none": None,
+                "nested": #{"a": [#{"b": false}]},
+            }
+        }
+    };
+
+    let json = serde_json::to_value(&value).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "unit": null,
+            "bool": true,
+            "integer": 42,
+            "float": 1.5,
+            "string": "hello",
+            "vec": [1, 2, 3],
+            "tuple": [1, "two"],
+            "option": 1,
+            "none": null,
+            "nested": {"a": [{"b": false}]},
+        })
+    );
+}
+
+#[test]
+fn test_deserialize_value() {
+    let value: Value = serde_json::from_str(
+        r#"{"integer": 42, "float": 1.5, "string": "hello", "vec": [1, null, true], "object": {"a": 1}}"#,
+    )
+    .unwrap();
+
+    let context = Arc::new(rune_modules::default_context().unwrap());
+
+    let out: (i64, f64, String, Vec<Value>, i64) = run(
+        &context,
+        r#"
+        pub fn main(value) {
+            (value.integer, value.float, value.string, value.vec, value.object.a)
+        }
+        "#,
+        &["main"],
+        (value,),
+    )
+    .unwrap();
+
+    assert_eq!(out.0, 42);
+    assert_eq!(out.1, 1.5);
+    assert_eq!(out.2, "hello");
+    assert!(matches!(
+        out.3.as_slice(),
+        [Value::Integer(1), Value::Unit, Value::Bool(true)]
+    ));
+    assert_eq!(out.4, 1);
+}
+
+#[test]
+fn test_roundtrip_value() {
+    let value: Value = rune! { Value =>
+        pub fn main() {
+            #{"a": [1, 2.5, "three"], "b": #{"c": (true, ())}}
+        }
+    };
+
+    let json = serde_json::to_value(&value).unwrap();
+    let value: Value = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&value).unwrap(), json);
+}
+
+#[test]
+fn test_serialize_unsupported() {
+    let value: Value = rune! { Value =>
+        fn foo() {}
+        pub fn main() { [foo] }
+    };
+
+    let error = serde_json::to_string(&value).unwrap_err();
+    assert_eq!(error.to_string(), "cannot serialize function pointers");
+}
@@ -166,3 +166,43 @@ fn test_bitwise_not() {
         !0b10100,
     };
 }
+
+#[test]
+fn test_operator_overloads() {
+    assert_eq! {
+        rune! { (i64, i64) =>
+            struct Vector { x, y }
+
+            impl Vector {
+                fn add(self, other) {
+                    Vector { x: self.x + other.x, y: self.y + other.y }
+                }
+
+                fn mul(self, n) {
+                    Vector { x: self.x * n, y: self.y * n }
+                }
+            }
+
+            pub fn main() {
+                let a = Vector { x: 1, y: 2 };
+                let b = Vector { x: 3, y: 4 };
+                let c = (a + b) * 2;
+                (c.x, c.y)
+            }
+        },
+        (8, 12),
+    };
+
+    assert_vm_error!(
+        r#"
+        struct Vector { x, y }
+
+        pub fn main() {
+            Vector { x: 1, y: 2 } - Vector { x: 3, y: 4 }
+        }
+        "#,
+        UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(op, "-");
+        }
+    );
+}
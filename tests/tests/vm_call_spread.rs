@@ -0,0 +1,63 @@
+use rune_tests::*;
+
+#[test]
+fn test_call_spread() {
+    assert_eq! {
+        rune!(i64 => fn f(a, b, c) { a * 100 + b * 10 + c } pub fn main() { let v = [1, 2, 3]; f(..v) }),
+        123,
+    };
+
+    assert_eq! {
+        rune!(i64 => fn f(a, b, c) { a * 100 + b * 10 + c } pub fn main() { let rest = [2, 3]; f(1, ..rest) }),
+        123,
+    };
+
+    assert_eq! {
+        rune!(i64 => fn f() { 42 } pub fn main() { f(..[]) }),
+        42,
+    };
+
+    assert_eq! {
+        rune!(i64 => pub fn main() { let f = |a, b| a - b; f(..[3, 1]) }),
+        2,
+    };
+
+    assert_eq! {
+        rune!(i64 => pub fn main() { let v = [1]; v.push(..[2]); v.len() }),
+        2,
+    };
+
+    assert_eq! {
+        rune!((i64, i64) => struct Foo(a, b); pub fn main() { let Foo(a, b) = Foo(..[1, 2]); (a, b) }),
+        (1, 2),
+    };
+
+    // NB: a parenthesized range is passed as-is.
+    assert_eq! {
+        rune!(i64 => fn f(range) { range.end.unwrap() } pub fn main() { let v = 4; f((..v)) }),
+        4,
+    };
+}
+
+#[test]
+fn test_call_spread_errors() {
+    assert_vm_error!(
+        r#"fn f(a, b) { a + b } pub fn main() { f(..[1, 2, 3]) }"#,
+        BadArgumentCount { actual, expected } => {
+            assert_eq!(actual, 3);
+            assert_eq!(expected, 2);
+        }
+    );
+
+    assert_vm_error!(
+        r#"fn f(a) { a } pub fn main() { f(..42) }"#,
+        runestick::VmErrorKind::Expected { .. } => {}
+    );
+
+    assert_compile_error! {
+        r#"fn f(a, b) { a + b } pub fn main() { f(..[1], 2) }"#,
+        span, SpreadNotLast => {
+            assert_eq!(span, Span::new(39, 44));
+        }
+    };
+}
@@ -137,6 +137,29 @@ fn test_more_complexity() {
     assert_eq!(result, 1280);
 }
 
+#[test]
+fn test_const_labeled_break() {
+    let result = rune! { i64 =>
+        const VALUE = {
+            let n = 0;
+
+            'outer: loop {
+                loop {
+                    n += 1;
+
+                    if n == 5 {
+                        break 'outer n * 2;
+                    }
+                }
+            }
+        };
+
+        pub fn main() { VALUE }
+    };
+
+    assert_eq!(result, 10);
+}
+
 #[test]
 fn test_if_else() {
     let result = rune! { i64 =>
@@ -266,3 +289,189 @@ fn test_const_block() {
 
     assert_eq!(result, "Hello World");
 }
+
+#[test]
+fn test_const_len() {
+    // NB: Rune has no array-repeat syntax like `[0; VALUES.len()]`, so
+    // constant items are used as the positions which require `len()` to be
+    // folded at compile time instead.
+    let result = rune! { (i64, i64, i64, i64) =>
+        const VALUES = [1, 2, 3];
+        const LEN = VALUES.len();
+        const STRING = "hello";
+        const TUPLE = (1, 2);
+
+        pub fn main() {
+            (LEN, STRING.len(), const { TUPLE.len() }, const { #{a: 1}.len() })
+        }
+    };
+
+    assert_eq!(result, (3, 5, 2, 1));
+
+    let result = rune! { i64 =>
+        const VALUES = [1, 2, 3, 4];
+        const COUNT = count();
+
+        const fn count() {
+            let n = 0;
+
+            while n < VALUES.len() {
+                n += 1;
+            }
+
+            n
+        }
+
+        pub fn main() {
+            COUNT
+        }
+    };
+
+    assert_eq!(result, 4);
+
+    assert_compile_error! {
+        r#"const VALUE = 1.len(); pub fn main() { VALUE }"#,
+        span, QueryError { .. } => {
+            assert_eq!(span, Span::new(14, 21));
+        }
+    };
+}
+
+#[test]
+fn test_propagate_constants() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let source = r#"
+    pub fn main() {
+        let a = 1 + 2;
+        let b = 10;
+        b += a;
+        let c = 5;
+        let f = || c;
+        let v = [];
+        v.push(a);
+        (a * 2, b, f(), #{a}.a, v.len())
+    }
+    "#;
+
+    let compile = |propagate: bool| {
+        let mut options = rune::Options::default();
+        options.propagate_constants(propagate);
+        rune_tests::compile_with_options(&context, &options, source)
+    };
+
+    let run = |unit: &std::sync::Arc<runestick::Unit>| {
+        let vm = runestick::Vm::new(std::sync::Arc::new(context.runtime()), unit.clone());
+        let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+        <(i64, i64, i64, i64, i64)>::from_value(output).unwrap()
+    };
+
+    let folded = |unit: &std::sync::Arc<runestick::Unit>| {
+        unit.iter_instructions()
+            .filter(|inst| {
+                matches!(
+                    inst,
+                    runestick::Inst::Push {
+                        value: runestick::InstValue::Integer(3)
+                    }
+                )
+            })
+            .count()
+    };
+
+    let plain = compile(false);
+    let propagated = compile(true);
+
+    let expected = (6, 13, 5, 3, 1);
+    assert_eq!(run(&plain), expected);
+    assert_eq!(run(&propagated), expected);
+
+    // NB: `a` is computed at runtime without propagation, and folded into
+    // each of its four uses with propagation.
+    assert_eq!(folded(&plain), 0);
+    assert_eq!(folded(&propagated), 4);
+    assert!(propagated.iter_instructions().count() < plain.iter_instructions().count());
+}
+
+#[test]
+fn test_module_constants() {
+    let mut module = runestick::Module::with_crate("host");
+    module.constant(&["LIMIT"], 40i64).unwrap();
+    module.constant(&["VERSION"], String::from("1.0")).unwrap();
+
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let unit = rune_tests::compile_with_options(
+        &context,
+        &rune::Options::default(),
+        r#"
+        const DOUBLE = host::LIMIT * 2;
+
+        pub fn main() {
+            (host::LIMIT + 2, DOUBLE, `v${host::VERSION}`)
+        }
+        "#,
+    );
+
+    let vm = runestick::Vm::new(std::sync::Arc::new(context.runtime()), unit);
+    let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+
+    assert_eq!(
+        <(i64, i64, String)>::from_value(output).unwrap(),
+        (42, 80, String::from("v1.0"))
+    );
+}
+
+#[test]
+fn test_const_string_concat() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let unit = rune_tests::compile_with_options(
+        &context,
+        &rune::Options::default(),
+        r#"
+        const GREETING = "hello" + " " + "world";
+        pub fn main() { GREETING }
+        "#,
+    );
+
+    let strings = unit
+        .iter_static_strings()
+        .map(|s| s.as_str().to_owned())
+        .collect::<Vec<_>>();
+
+    assert_eq!(strings, vec![String::from("hello world")]);
+    assert!(!unit
+        .iter_instructions()
+        .any(|inst| matches!(inst, runestick::Inst::Op { .. })));
+
+    let vm = runestick::Vm::new(std::sync::Arc::new(context.runtime()), unit);
+    let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+    assert_eq!(String::from_value(output).unwrap(), "hello world");
+
+    assert_compile_error! {
+        r#"const VALUE = "hello" + 1; pub fn main() { VALUE }"#,
+        span, QueryError { .. } => {
+            assert_eq!(span, Span::new(14, 25));
+        }
+    };
+}
+
+#[test]
+fn test_const_fn_errors() {
+    assert_compile_error! {
+        r#"const fn rec(n) { rec(n + 1) } const VALUE = rec(0); pub fn main() { VALUE }"#,
+        span, QueryError { error: QueryErrorKind::IrError { error: rune::IrErrorKind::CallDepthExceeded { max } } } => {
+            assert_eq!(span, Span::new(18, 28));
+            assert_eq!(max, 64);
+        }
+    };
+
+    assert_compile_error! {
+        r#"const fn foo(n) { n + bar() } fn bar() { 1 } const VALUE = foo(1); pub fn main() { VALUE }"#,
+        span, QueryError { .. } => {
+            assert_eq!(span, Span::new(22, 27));
+        }
+    };
+}
@@ -1,4 +1,5 @@
 use rune_tests::*;
+use runestick::Inst;
 use std::sync::Arc;
 
 #[test]
@@ -63,3 +64,33 @@ fn test_function() {
     let value: Value = function.call(()).unwrap();
     assert!(matches!(value, Value::Integer(3)));
 }
+
+#[test]
+fn test_direct_call() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let source = r#"
+    fn foo(a, b) { a + b }
+    struct Tuple(a, b);
+
+    pub fn main() {
+        let f = foo;
+        let t = (Tuple)(1, 2);
+        (foo)(1, 2) + f(3, 4) + ((foo))(5, 6) + t.0
+    }
+    "#;
+
+    let unit = rune_tests::compile_with_options(&context, &rune::Options::default(), source);
+
+    let count = |f: fn(&Inst) -> bool| unit.iter_instructions().filter(&f).count();
+
+    // NB: only the function stored in a variable is loaded as a value and
+    // called dynamically.
+    assert_eq!(count(|inst| matches!(inst, Inst::LoadFn { .. })), 1);
+    assert_eq!(count(|inst| matches!(inst, Inst::CallFn { .. })), 1);
+    assert_eq!(count(|inst| matches!(inst, Inst::Call { args: 2, .. })), 3);
+
+    let vm = runestick::Vm::new(Arc::new(context.runtime()), unit);
+    let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 22);
+}
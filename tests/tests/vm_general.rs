@@ -156,23 +156,66 @@ fn test_while() {
         10,
     };
 
+    assert_compile_error! {
+        r#"pub fn main() { let a = 0; while a >= 0 { break a; } }"#,
+        span, BreakValueUnsupported => {
+            assert_eq!(span, Span::new(48, 49));
+        }
+    };
+}
+
+#[test]
+fn test_while_let() {
     assert_eq! {
-        rune! { i64 =>
+        rune! { Vec<i64> =>
             pub fn main() {
-                let a = 0;
+                let it = [1, 2, 3, 4, 5, 6].iter();
+                let out = [];
 
-                let a = while a >= 0 {
-                    if a >= 10 {
-                        break a;
+                while let Some(n) = it.next() {
+                    let n = n * 10;
+
+                    if n == 20 {
+                        continue;
                     }
 
-                    a = a + 1;
-                };
+                    if n == 50 {
+                        break;
+                    }
 
-                a
+                    out.push(n);
+                }
+
+                out
             }
         },
-        10,
+        vec![10, 30, 40],
+    };
+
+    assert_eq! {
+        rune! { (i64, i64) =>
+            pub fn main() {
+                let values = [(1, 2), (3, 4)].iter();
+                let sum = 0;
+                let last = 0;
+
+                'outer: while let Some((a, b)) = values.next() {
+                    let inner = [a, b].iter();
+
+                    while let Some(n) = inner.next() {
+                        if n == 4 {
+                            last = n;
+                            break 'outer;
+                        }
+
+                        sum += n;
+                    }
+                }
+
+                (sum, last)
+            }
+        },
+        (6, 4),
     };
 }
 
@@ -219,40 +262,70 @@ fn test_loop() {
 }
 
 #[test]
-fn test_for() {
+fn test_labeled_break() {
     assert_eq! {
-        rune! { i64 =>
-            use std::iter::range;
-
+        rune! { (i64, i64) =>
             pub fn main() {
-                let a = 0;
-                let it = range(0, 10);
+                let n = 0;
 
-                for v in it {
-                    a = a + 1;
-                }
+                let value = 'outer: loop {
+                    loop {
+                        n += 1;
 
-                a
+                        if n == 3 {
+                            break 'outer n * 10;
+                        }
+                    }
+                };
+
+                let other = 'outer: loop {
+                    for i in 0..10 {
+                        while true {
+                            if i == 2 {
+                                break 'outer i;
+                            }
+
+                            break;
+                        }
+                    }
+                };
+
+                (value, other)
             }
         },
-        10,
+        (30, 2),
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { let n = 0; 'outer: while n < 1 { loop { break 'outer 1; } } }"#,
+        span, BreakValueUnsupported => {
+            assert_eq!(span, Span::new(69, 70));
+        }
     };
 
+    assert_compile_error! {
+        r#"pub fn main() { loop { break 'missing; } }"#,
+        span, MissingLoopLabel { label, existing } => {
+            assert_eq!(span, Span::new(29, 37));
+            assert_eq!(&*label, "missing");
+            assert!(existing.is_empty());
+        }
+    };
+}
+
+#[test]
+fn test_for() {
     assert_eq! {
         rune! { i64 =>
             use std::iter::range;
 
             pub fn main() {
                 let a = 0;
-                let it = range(0, 100);
-
-                let a = for v in it {
-                    if a >= 10 {
-                        break a;
-                    }
+                let it = range(0, 10);
 
+                for v in it {
                     a = a + 1;
-                };
+                }
 
                 a
             }
@@ -260,6 +333,13 @@ fn test_for() {
         10,
     };
 
+    assert_compile_error! {
+        r#"pub fn main() { let a = 0; for v in 0..100 { break a; } }"#,
+        span, BreakValueUnsupported => {
+            assert_eq!(span, Span::new(51, 52));
+        }
+    };
+
     assert_eq! {
         rune! { bool =>
             use std::iter::range;
@@ -22,3 +22,53 @@ fn test_lazy_and_or() {
         true,
     };
 }
+
+#[test]
+fn test_fold_constant_and_or() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let source = r#"
+    pub fn main() {
+        let calls = [];
+        let a = true || { calls.push(1); false };
+        let b = false && { calls.push(2); true };
+        let c = false || { calls.push(3); true };
+        let d = true && { calls.push(4); false };
+        (a, b, c, d, calls)
+    }
+    "#;
+
+    let compile = |fold: bool| {
+        let mut options = rune::Options::default();
+        options.fold_constant_conditions(fold);
+        rune_tests::compile_with_options(&context, &options, source)
+    };
+
+    let run = |unit: &std::sync::Arc<runestick::Unit>| {
+        let vm = runestick::Vm::new(std::sync::Arc::new(context.runtime()), unit.clone());
+        let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+        <(bool, bool, bool, bool, Vec<i64>)>::from_value(output).unwrap()
+    };
+
+    let jumps = |unit: &std::sync::Arc<runestick::Unit>| {
+        unit.iter_instructions()
+            .filter(|inst| {
+                matches!(
+                    inst,
+                    runestick::Inst::JumpIfOrPop { .. } | runestick::Inst::JumpIfNotOrPop { .. }
+                )
+            })
+            .count()
+    };
+
+    let plain = compile(false);
+    let folded = compile(true);
+
+    let expected = (true, false, true, false, vec![3, 4]);
+    assert_eq!(run(&plain), expected);
+    assert_eq!(run(&folded), expected);
+
+    assert_eq!(jumps(&plain), 4);
+    assert_eq!(jumps(&folded), 0);
+    assert!(folded.iter_instructions().count() < plain.iter_instructions().count());
+}
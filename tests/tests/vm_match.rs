@@ -97,3 +97,207 @@ fn test_struct_matching() {
         3,
     };
 }
+
+#[test]
+fn test_match_in_set() {
+    assert_eq! {
+        rune! { Vec<bool> =>
+            fn check(value) {
+                match value {
+                    in [1, -2, 3] => true,
+                    in ["foo", "bar"] => true,
+                    in ['a', b'b', true] => true,
+                    _ => false,
+                }
+            }
+
+            pub fn main() {
+                [
+                    check(1), check(-2), check(2),
+                    check("foo"), check(String::from_str("bar")), check("baz"),
+                    check('a'), check(b'b'), check(true), check(false),
+                ]
+            }
+        },
+        vec![true, true, false, true, true, false, true, true, true, false],
+    };
+
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                match (2, "b") { (in [1, 2], in ["a", "b"]) => 1, _ => 2 }
+            }
+        },
+        1,
+    };
+}
+
+#[test]
+fn test_match_in_set_instructions() {
+    use std::sync::Arc;
+
+    let literals = (0..50).map(|n| n * 3).collect::<Vec<i64>>();
+
+    let set_source = format!(
+        "pub fn main(n) {{ match n {{ in [{}] => true, _ => false }} }}",
+        literals
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let arms_source = format!(
+        "pub fn main(n) {{ match n {{ {} _ => false }} }}",
+        literals
+            .iter()
+            .map(|n| format!("{} => true,", n))
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+
+    let context = runestick::Context::with_default_modules().unwrap();
+    let runtime = Arc::new(context.runtime());
+
+    let options = rune::Options::default();
+    let set_unit = rune_tests::compile_with_options(&context, &options, &set_source);
+    let arms_unit = rune_tests::compile_with_options(&context, &options, &arms_source);
+
+    for n in 0..160 {
+        let run = |unit: &Arc<runestick::Unit>| {
+            let vm = runestick::Vm::new(runtime.clone(), unit.clone());
+            let output = vm.execute(["main"], (n,)).unwrap().complete().unwrap();
+            bool::from_value(output).unwrap()
+        };
+
+        assert_eq!(run(&set_unit), literals.contains(&n));
+        assert_eq!(run(&arms_unit), literals.contains(&n));
+    }
+
+    let set_count = set_unit.iter_instructions().count();
+    let arms_count = arms_unit.iter_instructions().count();
+    assert!(
+        set_count * 10 < arms_count,
+        "expected set pattern ({} instructions) to be smaller than arms ({} instructions)",
+        set_count,
+        arms_count
+    );
+}
+
+#[test]
+fn test_match_or_pattern() {
+    assert_eq! {
+        rune! { i64 => pub fn main() { match 2 { 1 | 2 => 10, _ => 0 } } },
+        10,
+    };
+
+    assert_eq! {
+        rune! { Vec<i64> =>
+            fn check(value) {
+                match value {
+                    (a, 1) | (1, a) => a,
+                    [_, b, a] | [a, b] => a + b,
+                    _ => 0,
+                }
+            }
+
+            pub fn main() {
+                let outer = 100;
+                [check((5, 1)), check((1, 6)), check((2, 2)), check([1, 2, 3]), check([4, 5]), outer]
+            }
+        },
+        vec![5, 6, 0, 5, 9, 100],
+    };
+}
+
+#[test]
+fn test_or_pattern_bindings() {
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                match [1, 2] { [a, b] | (b, a) | #{a, b} => a * 10 + b, _ => 0 }
+            }
+        },
+        12,
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { match (1, 2) { (a, 1) | (1, b) => a, _ => 0 } }"#,
+        span, InconsistentOrPatternBindings { name, alternative_span } => {
+            assert_eq!(span, Span::new(44, 45));
+            assert_eq!(name, "b");
+            assert_eq!(alternative_span, Span::new(31, 37));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { match (1, 2) { (a, b) | (1, b) => a, _ => 0 } }"#,
+        span, InconsistentOrPatternBindings { name, alternative_span } => {
+            assert_eq!(span, Span::new(32, 33));
+            assert_eq!(name, "a");
+            assert_eq!(alternative_span, Span::new(40, 46));
+        }
+    };
+}
+
+#[test]
+fn test_match_range_pattern() {
+    assert_eq! {
+        rune! { Vec<bool> =>
+            fn check(c) {
+                match c { 'a'..='z' => true, _ => false }
+            }
+
+            pub fn main() {
+                [check('a'), check('m'), check('z'), check('A'), check(1)]
+            }
+        },
+        vec![true, true, true, false, false],
+    };
+
+    assert_eq! {
+        rune! { Vec<i64> =>
+            fn check(n) {
+                match n {
+                    -10..0 => 1,
+                    0..=5 => 2,
+                    b'0'..=b'9' => 3,
+                    _ => 0,
+                }
+            }
+
+            pub fn main() {
+                [check(-10), check(-1), check(0), check(5), check(6), check(b'5'), check(b'a')]
+            }
+        },
+        vec![1, 1, 2, 2, 0, 3, 0],
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { match 1.0 { 0.0..=1.0 => true, _ => false } }"#,
+        span, MatchFloatInPattern => {
+            assert_eq!(span, Span::new(28, 31));
+        }
+    };
+}
+
+#[test]
+fn test_or_pattern_swap_bindings() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let unit = rune_tests::compile_with_options(
+        &context,
+        &rune::Options::default(),
+        r#"pub fn main() { match (1, 2) { [a, b] | (a, b) => a * 10 + b, _ => 0 } }"#,
+    );
+
+    // NB: both bindings are declared in order on top of the loaded value, so
+    // they're moved into place with a swap.
+    assert!(unit
+        .iter_instructions()
+        .any(|inst| matches!(inst, runestick::Inst::Swap)));
+
+    let vm = runestick::Vm::new(std::sync::Arc::new(context.runtime()), unit);
+    let output = vm.execute(["main"], ()).unwrap().complete().unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 12);
+}
@@ -0,0 +1,81 @@
+use rune_tests::*;
+use runestick::Location;
+
+fn panic_location(source: &str) -> Option<(usize, Span)> {
+    let (location, _) = panic_location_with_text(source)?;
+    Some((location.source_id, location.span))
+}
+
+/// Run the given source, returning the location it panicked at along with the
+/// source text at that location.
+fn panic_location_with_text(source: &str) -> Option<(Location, String)> {
+    let context = rune_modules::default_context().unwrap();
+
+    let mut sources = rune::Sources::new();
+    sources.insert(runestick::Source::new("main", source));
+
+    let vm = vm(&context, &mut sources).unwrap();
+
+    let error = match vm.execute(["main"], ()).and_then(|mut e| e.complete()) {
+        Err(error) => error,
+        Ok(..) => panic!("expected program to error"),
+    };
+
+    let location = error.first_location()?;
+    let text = sources.source_for_span(location)?.to_owned();
+    Some((location, text))
+}
+
+#[test]
+fn test_panic_location() {
+    let source = r#"pub fn main() { let a = 1; panic("boom"); }"#;
+    let start = source.find("panic").unwrap();
+
+    assert_eq!(
+        panic_location(source),
+        Some((0, Span::new(start, start + 13)))
+    );
+
+    let source = r#"fn fail() { panic("boom") } pub fn main() { fail(); }"#;
+    let start = source.find("panic").unwrap();
+
+    assert_eq!(
+        panic_location(source),
+        Some((0, Span::new(start, start + 13)))
+    );
+}
+
+#[test]
+fn test_track_caller() {
+    let source = r#"
+    #[track_caller]
+    fn check(n) { if n > 1 { panic("too large") } }
+    pub fn main() { check(1); check(2); }
+    "#;
+
+    let start = source.find("check(2)").unwrap();
+
+    assert_eq!(
+        panic_location(source),
+        Some((0, Span::new(start, start + 8)))
+    );
+
+    let (_, text) = panic_location_with_text(source).unwrap();
+    assert_eq!(text, "check(2)");
+
+    let source = r#"
+    #[track_caller]
+    fn inner() { panic("boom") }
+    #[track_caller]
+    fn outer() { inner() }
+    pub fn main() { outer() }
+    "#;
+
+    let start = source.find("outer()").unwrap();
+    let start = source[start + 1..].find("outer()").unwrap() + start + 1;
+
+    assert_eq!(
+        panic_location(source),
+        Some((0, Span::new(start, start + 7)))
+    );
+}
@@ -0,0 +1,63 @@
+use rune_tests::*;
+
+#[test]
+fn test_struct_update() {
+    assert_eq! {
+        rune! { (i64, i64, i64) =>
+            struct Foo { a, b, c }
+
+            pub fn main() {
+                let base = Foo { a: 1, b: 2, c: 3 };
+                let foo = Foo { b: 20, ..base };
+                (foo.a, foo.b, foo.c)
+            }
+        },
+        (1, 20, 3),
+    };
+
+    assert_eq! {
+        rune! { (i64, i64) =>
+            enum Shape { Rect { w, h } }
+
+            pub fn main() {
+                let base = Shape::Rect { w: 4, h: 5 };
+                let shape = Shape::Rect { h: 10, ..base };
+
+                match shape {
+                    Shape::Rect { w, h } => (w, h),
+                }
+            }
+        },
+        (4, 10),
+    };
+
+    assert_vm_error!(
+        r#"
+        struct Foo { a, b }
+        struct Bar { a, b }
+
+        pub fn main() {
+            let base = Bar { a: 1, b: 2 };
+            Foo { a: 3, ..base }
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "base of struct update does not match the type being constructed");
+        }
+    );
+
+    assert_compile_error! {
+        r#"pub fn main() { let base = #{a: 1}; #{b: 2, ..base} }"#,
+        span, UnsupportedObjectBase => {
+            assert_eq!(span, Span::new(44, 50));
+        }
+    };
+
+    assert_compile_error! {
+        r#"struct Foo { a } pub fn main() { let base = Foo { a: 1 }; Foo { b: 2, ..base } }"#,
+        span, LitObjectNotField { field, .. } => {
+            assert_eq!(span, Span::new(64, 65));
+            assert_eq!(field.as_ref(), "b");
+        }
+    };
+}
@@ -39,3 +39,81 @@ fn test_instance_kinds() {
         (4, 5, 6, 7),
     };
 }
+
+#[test]
+fn test_associated_fns() {
+    assert_eq! {
+        rune! { (i64, i64, i64) =>
+            struct Point { x, y }
+
+            impl Point {
+                fn new(x, y) {
+                    Point { x, y }
+                }
+
+                fn origin() {
+                    Point::new(0, 0)
+                }
+
+                fn sum(self) {
+                    self.x + self.y
+                }
+            }
+
+            pub fn main() {
+                let p = Point::new(1, 2);
+                let f = Point::new;
+                (p.sum(), Point::origin().sum(), f(3, 4).sum())
+            }
+        },
+        (3, 0, 7),
+    };
+
+    assert_vm_error!(
+        r#"
+        struct Point { x, y }
+        impl Point { fn origin() { Point { x: 0, y: 0 } } }
+        pub fn main() { Point::origin().origin() }
+        "#,
+        MissingInstanceFunction { .. } => {}
+    );
+}
+
+#[test]
+fn test_call_instance() {
+    let vm = rune_vm! {
+        struct Counter { count }
+
+        impl Counter {
+            fn add(self, n) {
+                self.count += n;
+                self.count
+            }
+        }
+
+        pub fn main() {
+            Counter { count: 10 }
+        }
+    };
+
+    let counter = vm.clone().call(["main"], ()).unwrap();
+
+    let count: i64 = vm.call_instance(counter.clone(), "add", (5i64,)).unwrap();
+    assert_eq!(count, 15);
+    let count: i64 = vm.call_instance(counter.clone(), "add", (1i64,)).unwrap();
+    assert_eq!(count, 16);
+
+    // NB: instance functions provided by the context.
+    let len: usize = vm
+        .call_instance(Value::vec(vec![Value::from(1i64)]), "len", ())
+        .unwrap();
+    assert_eq!(len, 1);
+
+    let error = vm
+        .call_instance::<_, Value>(counter, "missing", ())
+        .unwrap_err();
+    assert!(matches!(
+        error.into_kind(),
+        runestick::VmErrorKind::MissingInstanceFunction { .. }
+    ));
+}
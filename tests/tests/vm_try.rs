@@ -48,3 +48,45 @@ fn test_unwrap() {
         Err(3),
     };
 }
+
+#[test]
+fn test_try_option() {
+    assert_eq! {
+        rune! { (Option<i64>, Option<i64>) =>
+            fn first_plus_one(values) {
+                let first = values.iter().next()?;
+                Some(first + 1)
+            }
+
+            pub fn main() {
+                (first_plus_one([41]), first_plus_one([]))
+            }
+        },
+        (Some(42), None),
+    };
+
+    // Both carriers can be used in the same function, the early return value
+    // is the operand which short-circuited.
+    assert_eq! {
+        rune! { (Option<i64>, Option<i64>, Result<i64, i64>) =>
+            fn mixed(option, result) {
+                let a = option?;
+                let b = result?;
+                Some(a + b)
+            }
+
+            pub fn main() {
+                let a = mixed(Some(1), Ok(2));
+                let b = mixed(None, Ok(2));
+
+                let c = match mixed(Some(1), Err(3)) {
+                    Err(e) => Err(e),
+                    _ => Ok(0),
+                };
+
+                (a, b, c)
+            }
+        },
+        (Some(3), None, Err(3)),
+    };
+}
@@ -0,0 +1,107 @@
+use rune_tests::*;
+
+#[test]
+fn test_try_catch_no_error() {
+    assert_eq! {
+        rune!(i64 => pub fn main() { try { 1 + 2 } catch (e) { 0 } }),
+        3,
+    };
+}
+
+#[test]
+fn test_try_catch_panic() {
+    assert_eq! {
+        rune!((String, String) => pub fn main() { try { panic("boom") } catch (e) { (e.kind(), e.message()) } }),
+        (String::from("Panic"), String::from("boom")),
+    };
+
+    assert_eq! {
+        rune!((String, String) => pub fn main() { try { 1 / 0 } catch (e) { (e.kind(), e.message()) } }),
+        (String::from("DivideByZero"), String::from("division by zero")),
+    };
+
+    assert_eq! {
+        rune_s!(String => r#"pub fn main() { try { panic("boom") } catch (e) { `caught: ${e}` } }"#),
+        "caught: boom",
+    };
+}
+
+#[test]
+fn test_try_catch_error_value() {
+    let context = std::sync::Arc::new(rune_modules::default_context().unwrap());
+
+    let error: runestick::CaughtError = rune_tests::run(
+        &context,
+        r#"pub fn main() { try { 1 / 0 } catch (e) { e } }"#,
+        ["main"],
+        (),
+    )
+    .unwrap();
+
+    assert!(matches!(error.kind(), runestick::VmErrorKind::DivideByZero));
+
+    let location = error
+        .first_location()
+        .expect("unwound error to have a location");
+    assert_eq!(location.span, Span::new(22, 27));
+}
+
+#[test]
+fn test_try_catch_nested_call() {
+    let out: (i64, String) = rune_s! { (i64, String) => r#"
+        fn fail(n) {
+            let a = 1;
+            let b = 2;
+            panic(`failed at ${n}`)
+        }
+
+        pub fn main() {
+            let n = 10;
+
+            let e = try {
+                let x = 20;
+                fail(x + n)
+            } catch (e) {
+                e.message()
+            };
+
+            (n, e)
+        }
+    "#};
+
+    assert_eq!(out, (10, String::from("failed at 30")));
+}
+
+#[test]
+fn test_try_catch_left_by_break() {
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            loop {
+                try { break; } catch (e) { }
+            }
+
+            panic("uncaught");
+        }
+        "#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "uncaught");
+        }
+    );
+}
+
+#[test]
+fn test_try_catch_nested() {
+    assert_eq! {
+        rune_s!(String => r#"
+        pub fn main() {
+            try {
+                try { panic("inner") } catch (e) { panic(`${e} outer`) }
+            } catch (e) {
+                e.message()
+            }
+        }
+        "#),
+        "inner outer",
+    };
+}
@@ -240,6 +240,8 @@ fn main() -> Result<()> {
                 Eof,
                 #("/// En error marker.")
                 Error,
+                #("/// A comment, only produced by the lexer when comments are preserved.")
+                Comment,
                 #("/// A close delimiter: `)`, `}`, or `]`.")
                 Close(#delimiter),
                 #("/// An open delimiter: `(`, `{`, or `[`.")
@@ -284,6 +286,7 @@ fn main() -> Result<()> {
                     match self {
                         Self::Eof => "eof",
                         Self::Error => "error",
+                        Self::Comment => "comment",
                         Self::Close(delimiter) => delimiter.close(),
                         Self::Open(delimiter) => delimiter.open(),
                         Self::Ident(..) => "ident",